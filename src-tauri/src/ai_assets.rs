@@ -0,0 +1,122 @@
+// Behavior Trees and Blackboards: the nodes a tree is built from and the keys a blackboard
+// declares, for generated AI documentation.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::{ObjectExport, Package};
+use crate::properties::{self, PropertyValue};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BehaviorTreeNode {
+    pub name: String,
+    pub class: String,
+    /// Display name set in the tree editor, when it differs from the class default.
+    pub node_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlackboardKey {
+    pub name: String,
+    /// `Bool`, `Float`, `Object`, ... (the `BlackboardKeyType_` suffix).
+    pub key_type: String,
+    pub instance_synced: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AiAssetInfo {
+    pub class: String,
+    pub blackboard: Option<String>,
+    pub composites: Vec<BehaviorTreeNode>,
+    pub tasks: Vec<BehaviorTreeNode>,
+    pub decorators: Vec<BehaviorTreeNode>,
+    pub services: Vec<BehaviorTreeNode>,
+    /// Keys declared by this blackboard; inherited ones live on `parent`.
+    pub keys: Vec<BlackboardKey>,
+    pub parent: Option<String>,
+}
+
+// Native nodes are recognised by class prefix; Blueprint nodes by the usual BTT_/BTD_/BTS_ names.
+fn node_kind(class: &str) -> Option<&'static str> {
+    let kinds: [(&[&str], &str); 4] = [
+        (&["BTComposite_"], "composite"),
+        (&["BTTask_", "BTT_"], "task"),
+        (&["BTDecorator_", "BTD_"], "decorator"),
+        (&["BTService_", "BTS_"], "service"),
+    ];
+    kinds.iter().find(|(prefixes, _)| prefixes.iter().any(|p| class.starts_with(p))).map(|(_, kind)| *kind)
+}
+
+fn tree_node(file_path: &Path, package: &Package, export: &ObjectExport, class: String) -> BehaviorTreeNode {
+    let props = properties::read_export_properties(file_path, package, export).unwrap_or_default();
+    BehaviorTreeNode {
+        name: export.object_name.clone(),
+        class: class.trim_end_matches("_C").to_string(),
+        node_name: properties::find(&props, "NodeName").and_then(|v| v.as_str()).map(str::to_string),
+    }
+}
+
+fn blackboard_keys(package: &Package, props: &[properties::Property]) -> Vec<BlackboardKey> {
+    let Some(PropertyValue::Array(entries)) = properties::find(props, "Keys") else {
+        return Vec::new();
+    };
+    entries
+        .iter()
+        .map(|entry| {
+            // KeyType points at an instanced subobject whose class names the type.
+            let key_type = entry
+                .field("KeyType")
+                .and_then(|v| v.as_str())
+                .and_then(|path| package.export_by_path(path))
+                .map(|export| package.export_class(export))
+                .unwrap_or_default();
+            BlackboardKey {
+                name: entry.field("EntryName").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                key_type: key_type.trim_start_matches("BlackboardKeyType_").to_string(),
+                instance_synced: entry.field("bInstanceSynced").and_then(|v| v.as_bool()).unwrap_or(false),
+            }
+        })
+        .collect()
+}
+
+fn object_package(value: Option<&PropertyValue>) -> Option<String> {
+    value?.as_str().map(|p| crate::package::package_of_object_path(p).to_string())
+}
+
+pub fn inspect(file_path: &Path) -> CodexResult<AiAssetInfo> {
+    let package = Package::read_file(file_path)?;
+    let class = package.main_class().unwrap_or_default();
+    let main = package.main_export().ok_or_else(|| CodexError::message("no_main_export", &[]))?;
+    let props = properties::read_export_properties(file_path, &package, main)?;
+    let mut info = AiAssetInfo { class: class.clone(), ..Default::default() };
+
+    match class.as_str() {
+        "BehaviorTree" => {
+            info.blackboard = object_package(properties::find(&props, "BlackboardAsset"));
+            for export in &package.exports {
+                let node_class = package.export_class(export);
+                let Some(kind) = node_kind(&node_class) else {
+                    continue;
+                };
+                let node = tree_node(file_path, &package, export, node_class);
+                match kind {
+                    "composite" => info.composites.push(node),
+                    "task" => info.tasks.push(node),
+                    "decorator" => info.decorators.push(node),
+                    _ => info.services.push(node),
+                }
+            }
+        }
+        "BlackboardData" => {
+            info.parent = object_package(properties::find(&props, "Parent"));
+            info.keys = blackboard_keys(&package, &props);
+        }
+        _ => return Err(CodexError::message("not_a_behavior_tree", &[("class", &class)])),
+    }
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn get_ai_asset_info(file_path: String) -> CodexResult<AiAssetInfo> {
+    inspect(Path::new(&file_path))
+}
@@ -0,0 +1,253 @@
+// Structural diff of a package between two revisions: header, exports, tagged
+// properties, dependencies and registry tags, since the raw binary diff says nothing.
+
+use crate::error::CodexResult;
+use crate::history::AssetRepo;
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Cursor;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportDiff {
+    pub path: String,
+    pub class: String,
+    pub serial_size_before: i64,
+    pub serial_size_after: i64,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetDiff {
+    pub file_path: String,
+    pub rev_a: String,
+    pub rev_b: String,
+    /// One line per notable change, ready to show as-is.
+    pub summary: Vec<String>,
+    pub header: Vec<FieldChange>,
+    pub added_exports: Vec<String>,
+    pub removed_exports: Vec<String>,
+    pub changed_exports: Vec<ExportDiff>,
+    pub added_dependencies: Vec<String>,
+    pub removed_dependencies: Vec<String>,
+    pub tags: Vec<FieldChange>,
+}
+
+struct Snapshot {
+    package: Package,
+    bytes: Vec<u8>,
+}
+
+impl Snapshot {
+    fn read(bytes: Vec<u8>) -> CodexResult<Snapshot> {
+        let package = Package::read(&mut Cursor::new(&bytes))?;
+        Ok(Snapshot { package, bytes })
+    }
+
+    fn exports(&self) -> BTreeMap<String, usize> {
+        (0..self.package.exports.len())
+            .filter_map(|i| Some((self.package.object_path(i as i32 + 1)?, i)))
+            .collect()
+    }
+
+    fn properties(&self, index: usize) -> BTreeMap<String, String> {
+        let export = &self.package.exports[index];
+        let props = properties::read_properties(&mut Cursor::new(&self.bytes), &self.package, export).unwrap_or_default();
+        let mut flat = BTreeMap::new();
+        flatten_properties("", &props, &mut flat);
+        flat
+    }
+}
+
+fn flatten_properties(prefix: &str, props: &[Property], out: &mut BTreeMap<String, String>) {
+    for prop in props {
+        let key = if prop.array_index > 0 {
+            format!("{}{}[{}]", prefix, prop.name, prop.array_index)
+        } else {
+            format!("{}{}", prefix, prop.name)
+        };
+        flatten_value(&key, &prop.value, out);
+    }
+}
+
+fn flatten_value(key: &str, value: &PropertyValue, out: &mut BTreeMap<String, String>) {
+    match value {
+        PropertyValue::Struct { properties, .. } if !properties.is_empty() => {
+            flatten_properties(&format!("{}.", key), properties, out);
+        }
+        PropertyValue::Array(items) => {
+            out.insert(format!("{}.Num", key), items.len().to_string());
+            for (i, item) in items.iter().enumerate() {
+                flatten_value(&format!("{}[{}]", key, i), item, out);
+            }
+        }
+        PropertyValue::Map(entries) => {
+            out.insert(format!("{}.Num", key), entries.len().to_string());
+            for (k, v) in entries {
+                flatten_value(&format!("{}[{}]", key, k.display()), v, out);
+            }
+        }
+        _ => {
+            out.insert(key.to_string(), value.display());
+        }
+    }
+}
+
+fn diff_maps(before: &BTreeMap<String, String>, after: &BTreeMap<String, String>) -> Vec<FieldChange> {
+    let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+    keys.into_iter()
+        .filter(|k| before.get(*k) != after.get(*k))
+        .map(|k| FieldChange {
+            field: k.clone(),
+            before: before.get(k).cloned(),
+            after: after.get(k).cloned(),
+        })
+        .collect()
+}
+
+fn header_fields(package: &Package) -> BTreeMap<String, String> {
+    let s = &package.summary;
+    BTreeMap::from([
+        ("SavedByEngineVersion".to_string(), s.saved_by_engine_version.to_string()),
+        ("FileVersionUE4".to_string(), s.file_version_ue4.to_string()),
+        ("FileVersionUE5".to_string(), s.file_version_ue5.to_string()),
+        ("PackageFlags".to_string(), format!("0x{:08X}", s.package_flags)),
+        ("Names".to_string(), package.names.len().to_string()),
+        ("Imports".to_string(), package.imports.len().to_string()),
+        ("Exports".to_string(), package.exports.len().to_string()),
+    ])
+}
+
+fn compare(a: &Snapshot, b: &Snapshot) -> AssetDiff {
+    let mut diff = AssetDiff {
+        file_path: String::new(),
+        rev_a: String::new(),
+        rev_b: String::new(),
+        summary: Vec::new(),
+        header: diff_maps(&header_fields(&a.package), &header_fields(&b.package)),
+        added_exports: Vec::new(),
+        removed_exports: Vec::new(),
+        changed_exports: Vec::new(),
+        added_dependencies: Vec::new(),
+        removed_dependencies: Vec::new(),
+        tags: Vec::new(),
+    };
+
+    let exports_a = a.exports();
+    let exports_b = b.exports();
+    for (path, &ib) in &exports_b {
+        let Some(&ia) = exports_a.get(path) else {
+            diff.added_exports.push(path.clone());
+            continue;
+        };
+        let (ea, eb) = (&a.package.exports[ia], &b.package.exports[ib]);
+        let mut changes = Vec::new();
+        let (class_a, class_b) = (a.package.export_class(ea), b.package.export_class(eb));
+        if class_a != class_b {
+            changes.push(FieldChange {
+                field: "Class".to_string(),
+                before: Some(class_a),
+                after: Some(class_b.clone()),
+            });
+        }
+        changes.extend(diff_maps(&a.properties(ia), &b.properties(ib)));
+        if !changes.is_empty() || ea.serial_size != eb.serial_size {
+            diff.changed_exports.push(ExportDiff {
+                path: path.clone(),
+                class: class_b,
+                serial_size_before: ea.serial_size,
+                serial_size_after: eb.serial_size,
+                changes,
+            });
+        }
+    }
+    diff.removed_exports = exports_a.keys().filter(|p| !exports_b.contains_key(*p)).cloned().collect();
+
+    let deps_a: BTreeSet<String> = a.package.dependencies().into_iter().collect();
+    let deps_b: BTreeSet<String> = b.package.dependencies().into_iter().collect();
+    diff.added_dependencies = deps_b.difference(&deps_a).cloned().collect();
+    diff.removed_dependencies = deps_a.difference(&deps_b).cloned().collect();
+
+    let tags = |s: &Snapshot| s.package.main_registry_object().map(|o| o.tags.clone()).unwrap_or_default();
+    diff.tags = diff_maps(&tags(a), &tags(b));
+
+    diff.summary = summarize(&diff);
+    diff
+}
+
+fn summarize(diff: &AssetDiff) -> Vec<String> {
+    let mut lines = Vec::new();
+    for change in &diff.header {
+        lines.push(format!(
+            "{}: {} -> {}",
+            change.field,
+            change.before.as_deref().unwrap_or("-"),
+            change.after.as_deref().unwrap_or("-")
+        ));
+    }
+    for path in &diff.added_exports {
+        lines.push(format!("Added {}", path));
+    }
+    for path in &diff.removed_exports {
+        lines.push(format!("Removed {}", path));
+    }
+    for export in &diff.changed_exports {
+        let name = export.path.rsplit(['.', ':']).next().unwrap_or(&export.path);
+        if export.changes.is_empty() {
+            lines.push(format!(
+                "{} ({}) data changed, {} -> {} bytes",
+                name, export.class, export.serial_size_before, export.serial_size_after
+            ));
+            continue;
+        }
+        for change in &export.changes {
+            let line = match (&change.before, &change.after) {
+                (Some(before), Some(after)) => format!("{}.{}: {} -> {}", name, change.field, before, after),
+                (None, Some(after)) => format!("{}.{} set to {}", name, change.field, after),
+                (Some(before), None) => format!("{}.{} reset (was {})", name, change.field, before),
+                (None, None) => continue,
+            };
+            lines.push(line);
+        }
+    }
+    for dep in &diff.added_dependencies {
+        lines.push(format!("Now references {}", dep));
+    }
+    for dep in &diff.removed_dependencies {
+        lines.push(format!("No longer references {}", dep));
+    }
+    for change in &diff.tags {
+        lines.push(format!(
+            "Tag {}: {} -> {}",
+            change.field,
+            change.before.as_deref().unwrap_or("-"),
+            change.after.as_deref().unwrap_or("-")
+        ));
+    }
+    lines
+}
+
+pub fn diff_revisions(file_path: &Path, rev_a: &str, rev_b: &str) -> CodexResult<AssetDiff> {
+    let repo = AssetRepo::open(file_path)?;
+    let a = Snapshot::read(repo.read_revision(rev_a)?)?;
+    let b = Snapshot::read(repo.read_revision(rev_b)?)?;
+    let mut diff = compare(&a, &b);
+    diff.file_path = file_path.to_string_lossy().to_string();
+    diff.rev_a = rev_a.to_string();
+    diff.rev_b = rev_b.to_string();
+    Ok(diff)
+}
+
+#[tauri::command]
+pub fn diff_asset_versions(file_path: String, rev_a: String, rev_b: String) -> CodexResult<AssetDiff> {
+    diff_revisions(Path::new(&file_path), &rev_a, &rev_b)
+}
@@ -0,0 +1,142 @@
+// Text dumps of packages for version control: every export's tagged properties as JSON with
+// sorted keys and none of the offsets or sizes that move on every save, so committing the
+// dump next to the binary gives a reviewable diff.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{Cursor, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetDump {
+    pub file_path: String,
+    pub exports: usize,
+    pub bytes: usize,
+}
+
+// Keys are inserted in sorted order, so the output is the same whether or not serde_json
+// keeps insertion order.
+fn object(entries: BTreeMap<String, Value>) -> Value {
+    Value::Object(entries.into_iter().collect::<Map<String, Value>>())
+}
+
+fn property_key(property: &Property) -> String {
+    if property.array_index == 0 {
+        property.name.clone()
+    } else {
+        format!("{}[{}]", property.name, property.array_index)
+    }
+}
+
+fn properties_value(props: &[Property]) -> Value {
+    object(props.iter().map(|p| (property_key(p), value(&p.value))).collect())
+}
+
+fn value(value: &PropertyValue) -> Value {
+    match value {
+        PropertyValue::Bool(v) => json!(v),
+        PropertyValue::Int(v) => json!(v),
+        PropertyValue::Float(v) => json!(v),
+        PropertyValue::Name(v) | PropertyValue::Str(v) | PropertyValue::Enum(v) => json!(v),
+        PropertyValue::SoftObject(v) | PropertyValue::Guid(v) => json!(v),
+        PropertyValue::Text(v) | PropertyValue::Object(v) => json!(v),
+        PropertyValue::Numbers(v) => json!(v),
+        PropertyValue::Struct { struct_type, properties } => {
+            let mut fields = match properties_value(properties) {
+                Value::Object(map) => map.into_iter().collect::<BTreeMap<_, _>>(),
+                _ => BTreeMap::new(),
+            };
+            fields.insert("$type".to_string(), json!(struct_type));
+            object(fields)
+        }
+        PropertyValue::Array(items) => Value::Array(items.iter().map(self::value).collect()),
+        // Map order is the order the editor saved it in, which is stable for unchanged maps.
+        PropertyValue::Map(entries) => {
+            Value::Array(entries.iter().map(|(k, v)| json!([self::value(k), self::value(v)])).collect())
+        }
+        PropertyValue::Raw { size } => json!({ "$raw": size }),
+    }
+}
+
+pub fn dump(file_path: &Path) -> CodexResult<Value> {
+    let mut bytes = Vec::new();
+    crate::file_lock::open(file_path)
+        .and_then(|mut f| f.read_to_end(&mut bytes))
+        .map_err(|e| CodexError::io(file_path, &e))?;
+    let package = Package::read(&mut Cursor::new(&bytes)).map_err(|e| CodexError::parse(file_path, e))?;
+
+    let mut exports = BTreeMap::new();
+    for (i, export) in package.exports.iter().enumerate() {
+        let Some(path) = package.object_path(i as i32 + 1) else {
+            continue;
+        };
+        let mut entry = BTreeMap::new();
+        entry.insert("class".to_string(), json!(package.export_class(export)));
+        if let Some(outer) = package.object_path(export.outer_index).filter(|_| export.outer_index != 0) {
+            entry.insert("outer".to_string(), json!(outer));
+        }
+        if let Some(parent) = package.object_path(export.super_index).filter(|_| export.super_index != 0) {
+            entry.insert("super".to_string(), json!(parent));
+        }
+        entry.insert("object_flags".to_string(), json!(export.object_flags));
+        let props = match properties::read_properties(&mut Cursor::new(&bytes), &package, export) {
+            Ok(props) => properties_value(&props),
+            Err(e) => json!({ "$error": e }),
+        };
+        entry.insert("properties".to_string(), props);
+        exports.insert(path, object(entry));
+    }
+
+    let imports: BTreeSet<String> =
+        (0..package.imports.len()).filter_map(|i| package.object_path(-(i as i32) - 1)).collect();
+    let soft: BTreeSet<&String> = package.soft_package_references.iter().collect();
+    let tags: BTreeMap<String, Value> = package
+        .asset_registry
+        .iter()
+        .map(|o| (o.object_path.clone(), object(o.tags.iter().map(|(k, v)| (k.clone(), json!(v))).collect())))
+        .collect();
+    let summary = &package.summary;
+
+    let mut root = BTreeMap::new();
+    root.insert("package".to_string(), json!(summary.package_name));
+    root.insert("class".to_string(), json!(package.main_class()));
+    root.insert("saved_by".to_string(), json!(summary.saved_by_engine_version.to_string()));
+    root.insert(
+        "file_version".to_string(),
+        json!({ "licensee": summary.file_version_licensee, "ue4": summary.file_version_ue4, "ue5": summary.file_version_ue5 }),
+    );
+    root.insert("package_flags".to_string(), json!(summary.package_flags));
+    root.insert("imports".to_string(), json!(imports));
+    root.insert("soft_package_references".to_string(), json!(soft));
+    root.insert("registry_tags".to_string(), object(tags));
+    root.insert("exports".to_string(), object(exports));
+    Ok(object(root))
+}
+
+/// `dest` may be a file or a folder; by default the dump sits next to the asset (`X.uasset.json`).
+pub fn write_dump(file_path: &Path, dest: Option<&Path>) -> CodexResult<AssetDump> {
+    let dump = dump(file_path)?;
+    let file_name = format!("{}.json", file_path.file_name().unwrap_or_default().to_string_lossy());
+    let target: PathBuf = match dest {
+        Some(dest) if dest.is_dir() => dest.join(file_name),
+        Some(dest) => dest.to_path_buf(),
+        None => file_path.with_file_name(file_name),
+    };
+    let mut text = serde_json::to_string_pretty(&dump).map_err(|e| e.to_string())?;
+    text.push('\n');
+    std::fs::write(&target, &text).map_err(|e| CodexError::io(&target, &e))?;
+    Ok(AssetDump {
+        file_path: target.to_string_lossy().to_string(),
+        exports: dump["exports"].as_object().map(|e| e.len()).unwrap_or(0),
+        bytes: text.len(),
+    })
+}
+
+#[tauri::command]
+pub fn dump_asset_json(file_path: String, dest: Option<String>) -> CodexResult<AssetDump> {
+    write_dump(Path::new(&file_path), dest.as_deref().map(Path::new))
+}
@@ -0,0 +1,453 @@
+// Asset Manager awareness: primary asset types from DefaultGame.ini, PrimaryAssetLabel
+// assets, the chunk each asset is directly assigned to, and which assets nothing manages.
+
+use crate::config::{ConfigValue, IniFile};
+use crate::error::CodexResult;
+use crate::index::{is_in_folder, AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+use tauri::State;
+
+const SETTINGS_SECTION: &str = "/Script/Engine.AssetManagerSettings";
+
+const BASE_ASSET_MANAGER_SETTINGS: &str = r#"[/Script/Engine.AssetManagerSettings]
++PrimaryAssetTypesToScan=(PrimaryAssetType="Map",AssetBaseClass=/Script/Engine.World,bHasBlueprintClasses=False,bIsEditorOnly=True,Directories=((Path="/Game/Maps")),SpecificAssets=,Rules=(Priority=-1,ChunkId=-1,bApplyRecursively=True,CookRule=Unknown))
++PrimaryAssetTypesToScan=(PrimaryAssetType="PrimaryAssetLabel",AssetBaseClass=/Script/Engine.PrimaryAssetLabel,bHasBlueprintClasses=False,bIsEditorOnly=True,Directories=((Path="/Game")),SpecificAssets=,Rules=(Priority=-1,ChunkId=-1,bApplyRecursively=True,CookRule=Unknown))
+"#;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetRules {
+    pub priority: i64,
+    pub chunk_id: i64,
+    pub apply_recursively: bool,
+    pub cook_rule: String,
+}
+
+impl Default for AssetRules {
+    fn default() -> Self {
+        AssetRules {
+            priority: -1,
+            chunk_id: -1,
+            apply_recursively: true,
+            cook_rule: "Unknown".to_string(),
+        }
+    }
+}
+
+impl AssetRules {
+    fn from_config(value: Option<&ConfigValue>) -> AssetRules {
+        let mut rules = AssetRules::default();
+        if let Some(value) = value {
+            rules.priority = value.get_i64("Priority").unwrap_or(rules.priority);
+            rules.chunk_id = value.get_i64("ChunkId").unwrap_or(rules.chunk_id);
+            rules.apply_recursively = value.get_bool("bApplyRecursively").unwrap_or(rules.apply_recursively);
+            if let Some(cook_rule) = value.get_str("CookRule") {
+                rules.cook_rule = cook_rule.to_string();
+            }
+        }
+        rules
+    }
+
+    fn from_property(value: Option<&properties::PropertyValue>) -> AssetRules {
+        let mut rules = AssetRules::default();
+        if let Some(value) = value {
+            if let Some(v) = value.field("Priority").and_then(|v| v.as_i64()) {
+                rules.priority = v;
+            }
+            if let Some(v) = value.field("ChunkId").and_then(|v| v.as_i64()) {
+                rules.chunk_id = v;
+            }
+            if let Some(v) = value.field("bApplyRecursively").and_then(|v| v.as_bool()) {
+                rules.apply_recursively = v;
+            }
+            if let Some(v) = value.field("CookRule").and_then(|v| v.as_str()) {
+                rules.cook_rule = v.rsplit("::").next().unwrap_or(v).to_string();
+            }
+        }
+        rules
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimaryAssetType {
+    pub primary_asset_type: String,
+    pub asset_base_class: String,
+    pub has_blueprint_classes: bool,
+    pub is_editor_only: bool,
+    pub directories: Vec<String>,
+    pub specific_assets: Vec<String>,
+    pub rules: AssetRules,
+    pub assets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PrimaryAssetLabel {
+    pub path: String,
+    pub rules: AssetRules,
+    pub label_assets_in_my_directory: bool,
+    pub is_runtime_label: bool,
+    /// Name of the collection whose assets the label also manages.
+    pub asset_collection: Option<String>,
+    pub explicit_assets: Vec<String>,
+    pub assets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkAssignment {
+    pub chunk_id: i64,
+    pub assets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetManagerReport {
+    pub primary_asset_types: Vec<PrimaryAssetType>,
+    pub labels: Vec<PrimaryAssetLabel>,
+    pub chunks: Vec<ChunkAssignment>,
+    /// Assets no primary asset type or label manages, directly or as a dependency.
+    pub uncovered_assets: Vec<String>,
+}
+
+/// Rules that apply to a specific asset, as collected from types, overrides and labels.
+#[derive(Debug, Clone)]
+pub struct Manager {
+    pub rules: AssetRules,
+}
+
+pub struct AssetManagerSettings {
+    pub types: Vec<PrimaryAssetType>,
+    explicit_rules: Vec<(String, AssetRules)>,
+    custom_rules: Vec<(String, Option<String>, AssetRules)>,
+}
+
+impl AssetManagerSettings {
+    pub fn load(project: &Path) -> AssetManagerSettings {
+        // BaseGame.ini ships these two types; the project file layers its +/- operators on top.
+        let project_text = std::fs::read_to_string(project.join("Config").join("DefaultGame.ini")).unwrap_or_default();
+        let ini = IniFile::parse(&format!("{}\n{}", BASE_ASSET_MANAGER_SETTINGS, project_text));
+
+        let types = ini
+            .get_all(SETTINGS_SECTION, "PrimaryAssetTypesToScan")
+            .into_iter()
+            .map(ConfigValue::parse)
+            .map(|v| PrimaryAssetType {
+                primary_asset_type: v.get_str("PrimaryAssetType").unwrap_or_default().to_string(),
+                asset_base_class: v.get_str("AssetBaseClass").unwrap_or_default().to_string(),
+                has_blueprint_classes: v.get_bool("bHasBlueprintClasses").unwrap_or(false),
+                is_editor_only: v.get_bool("bIsEditorOnly").unwrap_or(false),
+                directories: directory_paths(v.get("Directories")),
+                specific_assets: v
+                    .get("SpecificAssets")
+                    .map(|s| s.items().into_iter().filter_map(|i| i.as_str()).map(str::to_string).collect())
+                    .unwrap_or_default(),
+                rules: AssetRules::from_config(v.get("Rules")),
+                assets: Vec::new(),
+            })
+            .collect();
+
+        let explicit_rules = ini
+            .get_all(SETTINGS_SECTION, "PrimaryAssetRules")
+            .into_iter()
+            .map(ConfigValue::parse)
+            .filter_map(|v| {
+                let id = v.get_str("PrimaryAssetId")?.to_string();
+                Some((id, AssetRules::from_config(v.get("Rules"))))
+            })
+            .collect();
+
+        let custom_rules = ini
+            .get_all(SETTINGS_SECTION, "CustomPrimaryAssetRules")
+            .into_iter()
+            .map(ConfigValue::parse)
+            .filter_map(|v| {
+                let ty = v.get_str("PrimaryAssetType")?.to_string();
+                let dir = v.get("FilterDirectory").and_then(|d| d.get_str("Path")).map(str::to_string);
+                Some((ty, dir, AssetRules::from_config(v.get("Rules"))))
+            })
+            .collect();
+
+        AssetManagerSettings {
+            types,
+            explicit_rules,
+            custom_rules,
+        }
+    }
+
+    /// Primary asset id (`Type:Name`) for an asset, using registry tags when the editor wrote them.
+    pub fn primary_asset_id(&self, index: &AssetIndex, asset: &IndexedAsset) -> Option<(String, String)> {
+        if let (Some(ty), Some(name)) = (asset.tag("PrimaryAssetType"), asset.tag("PrimaryAssetName")) {
+            return Some((ty.to_string(), name.to_string()));
+        }
+        self.types
+            .iter()
+            .find(|t| type_matches(index, t, asset))
+            .map(|t| {
+                let name = if asset.is_class("World") {
+                    asset.asset.path.clone()
+                } else {
+                    asset.asset.name.clone()
+                };
+                (t.primary_asset_type.clone(), name)
+            })
+    }
+
+    /// Effective rules for a primary asset: explicit overrides, then custom directory rules, then type defaults.
+    pub fn rules_for(&self, ty: &str, name: &str, path: &str) -> AssetRules {
+        let id = format!("{}:{}", ty, name);
+        if let Some((_, rules)) = self.explicit_rules.iter().find(|(i, _)| i.eq_ignore_ascii_case(&id)) {
+            return rules.clone();
+        }
+        if let Some((_, _, rules)) = self
+            .custom_rules
+            .iter()
+            .find(|(t, dir, _)| t == ty && dir.as_deref().is_none_or(|d| is_in_folder(path, d)))
+        {
+            return rules.clone();
+        }
+        self.types
+            .iter()
+            .find(|t| t.primary_asset_type == ty)
+            .map(|t| t.rules.clone())
+            .unwrap_or_default()
+    }
+}
+
+fn directory_paths(value: Option<&ConfigValue>) -> Vec<String> {
+    value
+        .map(|v| {
+            v.items()
+                .into_iter()
+                .filter_map(|d| d.get_str("Path").or_else(|| d.as_str()))
+                .filter(|p| !p.is_empty())
+                .map(|p| p.trim_end_matches('/').to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn short_class(path: &str) -> &str {
+    let path = crate::package::strip_object_reference(path);
+    path.rsplit(['.', '/']).next().unwrap_or(path)
+}
+
+/// Walks Blueprint parents inside the project until a native class is reached.
+pub fn native_parent<'a>(index: &'a AssetIndex, asset: &'a IndexedAsset) -> Option<&'a str> {
+    let mut current = asset;
+    for _ in 0..16 {
+        if let Some(native) = current.tag("NativeParentClass") {
+            return Some(short_class(native));
+        }
+        let parent = current.tag("ParentClass")?;
+        let parent_path = crate::package::package_of_object_path(parent);
+        match index.get(parent_path) {
+            Some(next) => current = next,
+            None => return Some(short_class(parent).trim_end_matches("_C")),
+        }
+    }
+    None
+}
+
+fn type_matches(index: &AssetIndex, ty: &PrimaryAssetType, asset: &IndexedAsset) -> bool {
+    let in_scope = ty.directories.iter().any(|d| is_in_folder(&asset.asset.path, d))
+        || ty.specific_assets.iter().any(|s| {
+            crate::package::package_of_object_path(s).eq_ignore_ascii_case(&asset.asset.path)
+        });
+    if !in_scope {
+        return false;
+    }
+    let base = short_class(&ty.asset_base_class);
+    if ty.has_blueprint_classes {
+        let direct_parent = asset.tag("ParentClass").map(short_class).map(|p| p.trim_end_matches("_C"));
+        direct_parent == Some(base) || native_parent(index, asset) == Some(base)
+    } else {
+        asset.class.as_deref() == Some(base)
+    }
+}
+
+pub fn read_labels(index: &AssetIndex) -> Vec<PrimaryAssetLabel> {
+    let mut labels = Vec::new();
+    for asset in index.of_class("PrimaryAssetLabel") {
+        let file_path = Path::new(&asset.asset.file_path);
+        let Ok(package) = Package::read_file(file_path) else {
+            continue;
+        };
+        let props = package
+            .main_export()
+            .and_then(|e| properties::read_export_properties(file_path, &package, e).ok())
+            .unwrap_or_default();
+        let rules = AssetRules::from_property(properties::find(&props, "Rules"));
+        // Defaults of UPrimaryAssetLabel: label the folder, editor-only.
+        let label_directory = properties::find(&props, "bLabelAssetsInMyDirectory")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+        let is_runtime_label = properties::find(&props, "bIsRuntimeLabel")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        let asset_collection = properties::find(&props, "AssetCollection")
+            .and_then(|v| v.field("CollectionName"))
+            .and_then(|v| v.as_str())
+            .filter(|name| !name.is_empty() && *name != "None")
+            .map(str::to_string);
+        let explicit_assets: Vec<String> = package
+            .soft_package_references
+            .iter()
+            .filter(|p| index.contains(p))
+            .cloned()
+            .collect();
+
+        let mut members: BTreeSet<String> = explicit_assets.iter().cloned().collect();
+        if let Some(name) = &asset_collection {
+            let collected = collection_assets(&index.project, name);
+            members.extend(collected.iter().filter_map(|p| index.get(p)).map(|a| a.asset.path.clone()));
+        }
+        if label_directory {
+            let folder = asset.folder();
+            members.extend(
+                index
+                    .assets
+                    .iter()
+                    .filter(|a| a.asset.path != asset.asset.path && is_in_folder(&a.asset.path, folder))
+                    .map(|a| a.asset.path.clone()),
+            );
+        }
+
+        labels.push(PrimaryAssetLabel {
+            path: asset.asset.path.clone(),
+            rules,
+            label_assets_in_my_directory: label_directory,
+            is_runtime_label,
+            asset_collection,
+            explicit_assets,
+            assets: members.into_iter().collect(),
+        });
+    }
+    labels
+}
+
+/// Object paths in a static collection, shared (`Content/Collections`) or local
+/// (`Saved/Collections`). The file is a `Key:Value` header, a blank line, then one path per line.
+fn collection_assets(project: &Path, name: &str) -> Vec<String> {
+    let file_name = format!("{}.collection", name);
+    let candidates = [
+        project.join("Content/Collections").join(&file_name),
+        project.join("Saved/Collections").join(&file_name),
+    ];
+    let Some(text) = candidates.iter().find_map(|p| std::fs::read_to_string(p).ok()) else {
+        return Vec::new();
+    };
+    text.lines()
+        .skip_while(|line| !line.trim().is_empty())
+        .map(str::trim)
+        .filter(|line| line.starts_with('/'))
+        .map(|line| crate::package::package_of_object_path(line).to_string())
+        .collect()
+}
+
+/// Every asset's direct managers (primary asset rules and labels), keyed by package path.
+pub fn collect_managers(
+    index: &AssetIndex,
+    settings: &mut AssetManagerSettings,
+    labels: &[PrimaryAssetLabel],
+) -> BTreeMap<String, Vec<Manager>> {
+    let mut managers: BTreeMap<String, Vec<Manager>> = BTreeMap::new();
+    for asset in &index.assets {
+        if let Some((ty, name)) = settings.primary_asset_id(index, asset) {
+            let rules = settings.rules_for(&ty, &name, &asset.asset.path);
+            if let Some(t) = settings.types.iter_mut().find(|t| t.primary_asset_type == ty) {
+                t.assets.push(asset.asset.path.clone());
+            }
+            managers
+                .entry(asset.asset.path.clone())
+                .or_default()
+                .push(Manager { rules });
+        }
+    }
+    for label in labels {
+        for member in &label.assets {
+            managers.entry(member.clone()).or_default().push(Manager {
+                rules: label.rules.clone(),
+            });
+        }
+    }
+    managers
+}
+
+/// Chunks from the highest-priority managers; lower priorities only fill in when nobody outranks them.
+pub fn effective_chunks(managers: &[Manager]) -> Vec<i64> {
+    let assigned: Vec<&Manager> = managers.iter().filter(|m| m.rules.chunk_id >= 0).collect();
+    let Some(top) = assigned.iter().map(|m| m.rules.priority).max() else {
+        return Vec::new();
+    };
+    let mut chunks: Vec<i64> = assigned
+        .iter()
+        .filter(|m| m.rules.priority == top)
+        .map(|m| m.rules.chunk_id)
+        .collect();
+    chunks.sort();
+    chunks.dedup();
+    chunks
+}
+
+/// Managed assets plus everything they pull in: a rule that applies recursively manages the
+/// dependencies too, whatever its chunk; chunk -1 only means no chunk of its own, not uncooked.
+fn covered_assets<'a>(index: &'a AssetIndex, managers: &BTreeMap<String, Vec<Manager>>) -> HashSet<&'a str> {
+    let mut covered = HashSet::new();
+    let mut queue = Vec::new();
+    for (path, asset_managers) in managers {
+        let Some(asset) = index.get(path) else {
+            continue;
+        };
+        covered.insert(asset.asset.path.as_str());
+        if asset_managers.iter().any(|m| m.rules.apply_recursively) {
+            queue.push(asset);
+        }
+    }
+    while let Some(asset) = queue.pop() {
+        for dependency in asset.dependencies.iter().filter_map(|d| index.get(d)) {
+            if covered.insert(dependency.asset.path.as_str()) {
+                queue.push(dependency);
+            }
+        }
+    }
+    covered
+}
+
+pub fn build_report(index: &AssetIndex) -> AssetManagerReport {
+    let mut settings = AssetManagerSettings::load(&index.project);
+    let labels = read_labels(index);
+    let managers = collect_managers(index, &mut settings, &labels);
+
+    let mut chunks: BTreeMap<i64, Vec<String>> = BTreeMap::new();
+    for (path, asset_managers) in &managers {
+        for chunk in effective_chunks(asset_managers) {
+            chunks.entry(chunk).or_default().push(path.clone());
+        }
+    }
+
+    let covered = covered_assets(index, &managers);
+    let uncovered_assets = index
+        .assets
+        .iter()
+        .filter(|a| !covered.contains(a.asset.path.as_str()))
+        .map(|a| a.asset.path.clone())
+        .collect();
+
+    AssetManagerReport {
+        primary_asset_types: settings.types,
+        labels,
+        chunks: chunks
+            .into_iter()
+            .map(|(chunk_id, assets)| ChunkAssignment { chunk_id, assets })
+            .collect(),
+        uncovered_assets,
+    }
+}
+
+#[tauri::command]
+pub fn get_asset_manager_report(manager: State<ScanManager>, project: String) -> CodexResult<AssetManagerReport> {
+    let index = manager.index(&project)?;
+    Ok(build_report(&index))
+}
@@ -0,0 +1,141 @@
+// Sound class tree with the sounds routed to each class, plus the classes every sound mix
+// adjusts. Nothing here is in the registry tags, so it comes from the assets' properties.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SoundClassNode {
+    pub path: String,
+    /// False for engine or plugin classes the project only refers to.
+    pub in_project: bool,
+    pub sounds: Vec<String>,
+    pub children: Vec<SoundClassNode>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SoundMixInfo {
+    pub path: String,
+    pub adjusted_classes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioHierarchy {
+    pub classes: Vec<SoundClassNode>,
+    pub mixes: Vec<SoundMixInfo>,
+    /// Sounds without a sound class (they fall back to the project default).
+    pub unassigned: Vec<String>,
+}
+
+const SOUND_CLASSES: &[&str] = &["SoundWave", "SoundCue", "MetaSoundSource", "SoundWaveProcedural"];
+
+fn main_properties(asset: &IndexedAsset) -> Vec<Property> {
+    let file_path = Path::new(&asset.asset.file_path);
+    let Ok(package) = Package::read_file(file_path) else {
+        return Vec::new();
+    };
+    package
+        .main_export()
+        .and_then(|main| properties::read_export_properties(file_path, &package, main).ok())
+        .unwrap_or_default()
+}
+
+fn object_package(value: Option<&PropertyValue>) -> Option<String> {
+    value?.as_str().map(|p| crate::package::package_of_object_path(p).to_string())
+}
+
+pub fn build(index: &AssetIndex) -> AudioHierarchy {
+    // Child -> parent; ChildClasses on the parent covers classes saved before ParentClass existed.
+    let mut parents: BTreeMap<String, Option<String>> = BTreeMap::new();
+    for class in index.of_class("SoundClass") {
+        let props = main_properties(class);
+        let path = class.asset.path.clone();
+        let parent = object_package(properties::find(&props, "ParentClass"));
+        if let Some(parent) = &parent {
+            parents.entry(parent.clone()).or_insert(None);
+        }
+        let entry = parents.entry(path.clone()).or_insert(None);
+        if parent.is_some() {
+            *entry = parent;
+        }
+        if let Some(PropertyValue::Array(children)) = properties::find(&props, "ChildClasses") {
+            for child in children.iter().filter_map(|c| object_package(Some(c))) {
+                let parent = parents.entry(child).or_insert(None);
+                if parent.is_none() {
+                    *parent = Some(path.clone());
+                }
+            }
+        }
+    }
+
+    let mut sounds: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut unassigned = Vec::new();
+    for sound in index.assets.iter().filter(|a| SOUND_CLASSES.iter().any(|c| a.is_class(c))) {
+        match object_package(properties::find(&main_properties(sound), "SoundClassObject")) {
+            Some(class) => {
+                parents.entry(class.clone()).or_insert(None);
+                sounds.entry(class).or_default().push(sound.asset.path.clone());
+            }
+            None => unassigned.push(sound.asset.path.clone()),
+        }
+    }
+
+    let mixes = index
+        .of_class("SoundMix")
+        .map(|mix| {
+            let adjusted: BTreeSet<String> = match properties::find(&main_properties(mix), "SoundClassEffects") {
+                Some(PropertyValue::Array(effects)) => {
+                    effects.iter().filter_map(|e| object_package(e.field("SoundClassObject"))).collect()
+                }
+                _ => BTreeSet::new(),
+            };
+            SoundMixInfo { path: mix.asset.path.clone(), adjusted_classes: adjusted.into_iter().collect() }
+        })
+        .collect();
+
+    let mut children: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+    for (class, parent) in &parents {
+        children.entry(parent.clone()).or_default().push(class.clone());
+    }
+    let roots = children.get(&None).cloned().unwrap_or_default();
+    let mut visited = BTreeSet::new();
+    let classes = roots.iter().map(|r| node(index, r, &children, &mut sounds, &mut visited)).collect();
+
+    AudioHierarchy { classes, mixes, unassigned }
+}
+
+fn node(
+    index: &AssetIndex,
+    path: &str,
+    children: &BTreeMap<Option<String>, Vec<String>>,
+    sounds: &mut BTreeMap<String, Vec<String>>,
+    visited: &mut BTreeSet<String>,
+) -> SoundClassNode {
+    visited.insert(path.to_string());
+    let mut nodes = Vec::new();
+    for child in children.get(&Some(path.to_string())).into_iter().flatten() {
+        // Parent loops can only come from broken data; each class is listed once.
+        if !visited.contains(child) {
+            nodes.push(node(index, child, children, sounds, visited));
+        }
+    }
+    SoundClassNode {
+        path: path.to_string(),
+        in_project: index.contains(path),
+        sounds: sounds.remove(path).unwrap_or_default(),
+        children: nodes,
+    }
+}
+
+#[tauri::command]
+pub fn get_audio_hierarchy(manager: State<ScanManager>, project: String) -> CodexResult<AudioHierarchy> {
+    let index = manager.index(&project)?;
+    Ok(build(&index))
+}
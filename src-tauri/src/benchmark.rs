@@ -0,0 +1,114 @@
+// Timed run of the scan pipeline, phase by phase, for performance reports.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use crate::scan::{ContentRoot, ScanOptions};
+use serde::Serialize;
+use std::path::Path;
+use std::time::Instant;
+use tauri::State;
+use walkdir::WalkDir;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseTiming {
+    pub phase: String,
+    pub millis: f64,
+    pub items: usize,
+    pub items_per_sec: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanBenchmark {
+    pub path: String,
+    pub files_walked: usize,
+    pub assets: usize,
+    pub header_errors: usize,
+    pub phases: Vec<PhaseTiming>,
+    /// Size of the scan reply in each IPC encoding.
+    pub json_bytes: usize,
+    pub msgpack_bytes: usize,
+    pub total_millis: f64,
+    pub files_per_sec: f64,
+}
+
+fn timing(phase: &str, started: Instant, items: usize) -> PhaseTiming {
+    let millis = started.elapsed().as_secs_f64() * 1000.0;
+    PhaseTiming {
+        phase: phase.to_string(),
+        millis,
+        items,
+        items_per_sec: per_sec(items, millis),
+    }
+}
+
+fn per_sec(items: usize, millis: f64) -> f64 {
+    if millis > 0.0 {
+        items as f64 / (millis / 1000.0)
+    } else {
+        0.0
+    }
+}
+
+pub fn run(path: &Path, roots: &[ContentRoot]) -> CodexResult<ScanBenchmark> {
+    if roots.is_empty() {
+        return Err(CodexError::missing_content(path));
+    }
+    let options = ScanOptions::default();
+    let mut phases = Vec::new();
+    let total = Instant::now();
+
+    let started = Instant::now();
+    let entries: Vec<_> = roots
+        .iter()
+        .flat_map(|root| {
+            WalkDir::new(&root.path)
+                .into_iter()
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .map(move |e| (root, e.into_path()))
+        })
+        .collect();
+    phases.push(timing("walk", started, entries.len()));
+
+    // Path and extension only, so the header cost all lands in the next phase.
+    let started = Instant::now();
+    let assets: Vec<_> = entries
+        .iter()
+        .filter_map(|(root, p)| crate::scan::asset_from_name(root, p, &options))
+        .collect();
+    phases.push(timing("classify", started, entries.len()));
+
+    let asset_count = assets.len();
+    let started = Instant::now();
+    let index = AssetIndex::from_assets(path, assets);
+    phases.push(timing("parse_headers", started, asset_count));
+    let header_errors = index.assets.iter().filter(|a| a.error.is_some()).count();
+
+    let assets: Vec<_> = index.assets.iter().map(|a| a.asset.clone()).collect();
+    let started = Instant::now();
+    let json_bytes = crate::scan_output::encode(&assets, "json")?.len();
+    phases.push(timing("encode_json", started, assets.len()));
+    let started = Instant::now();
+    let msgpack_bytes = crate::scan_output::encode(&assets, "msgpack")?.len();
+    phases.push(timing("encode_msgpack", started, assets.len()));
+
+    let total_millis = total.elapsed().as_secs_f64() * 1000.0;
+    Ok(ScanBenchmark {
+        path: path.to_string_lossy().to_string(),
+        files_walked: entries.len(),
+        assets: asset_count,
+        header_errors,
+        phases,
+        json_bytes,
+        msgpack_bytes,
+        total_millis,
+        files_per_sec: per_sec(entries.len(), total_millis),
+    })
+}
+
+#[tauri::command]
+pub fn benchmark_scan(manager: State<ScanManager>, path: String) -> CodexResult<ScanBenchmark> {
+    let path = Path::new(&path);
+    run(path, &manager.content_roots(path))
+}
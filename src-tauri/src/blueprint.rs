@@ -0,0 +1,123 @@
+// Blueprint graph summary from the editor-only subobjects saved with a Blueprint: its
+// graphs (EdGraph exports) and the K2 nodes inside them. Cooked packages have neither.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use crate::properties::{self, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphSummary {
+    pub name: String,
+    /// `EventGraph`, `Function` or `Macro`.
+    pub kind: String,
+    pub node_count: usize,
+    /// Functions called from this graph, by name.
+    pub calls: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlueprintSummary {
+    pub name: String,
+    pub parent_class: Option<String>,
+    /// Event, custom event and input handlers, across all event graphs.
+    pub events: Vec<String>,
+    pub functions: Vec<String>,
+    pub variables: Vec<String>,
+    pub graphs: Vec<GraphSummary>,
+}
+
+/// Summarises the Blueprint at `blueprint` (an export index, 0-based).
+pub fn summarize(file_path: &Path, package: &Package, blueprint: usize) -> CodexResult<BlueprintSummary> {
+    let export =
+        package.exports.get(blueprint).ok_or_else(|| CodexError::message("blueprint_export_out_of_range", &[]))?;
+    let props = properties::read_export_properties(file_path, package, export)?;
+
+    // Which list a graph is in decides its kind.
+    let mut kinds: HashMap<String, &str> = HashMap::new();
+    for (list, kind) in [("UbergraphPages", "EventGraph"), ("FunctionGraphs", "Function"), ("MacroGraphs", "Macro")] {
+        if let Some(PropertyValue::Array(graphs)) = properties::find(&props, list) {
+            for graph in graphs.iter().filter_map(|g| g.as_str()) {
+                kinds.insert(graph.to_string(), kind);
+            }
+        }
+    }
+
+    let variables = match properties::find(&props, "NewVariables") {
+        Some(PropertyValue::Array(vars)) => vars
+            .iter()
+            .filter_map(|v| v.field("VarName").and_then(|n| n.as_str()))
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    let blueprint_index = blueprint as i32 + 1;
+    let mut graphs = Vec::new();
+    let mut events = BTreeSet::new();
+    for (i, graph) in package.exports.iter().enumerate() {
+        if graph.outer_index != blueprint_index || package.export_class(graph) != "EdGraph" {
+            continue;
+        }
+        let graph_path = package.object_path(i as i32 + 1).unwrap_or_default();
+        let kind = kinds.get(&graph_path).copied().unwrap_or("Function");
+        let mut calls = BTreeSet::new();
+        let mut node_count = 0;
+        for node in package.exports.iter().filter(|e| e.outer_index == i as i32 + 1) {
+            let class = package.export_class(node);
+            if !class.starts_with("K2Node") {
+                continue;
+            }
+            node_count += 1;
+            let read = || properties::read_export_properties(file_path, package, node).unwrap_or_default();
+            match class.as_str() {
+                "K2Node_Event" | "K2Node_ComponentBoundEvent" | "K2Node_ActorBoundEvent" => {
+                    if let Some(name) = member_name(&read(), "EventReference") {
+                        events.insert(name);
+                    }
+                }
+                "K2Node_CustomEvent" => {
+                    if let Some(name) = properties::find(&read(), "CustomFunctionName").and_then(|v| v.as_str()) {
+                        events.insert(name.to_string());
+                    }
+                }
+                "K2Node_InputAction" | "K2Node_InputKey" | "K2Node_EnhancedInputAction" => {
+                    events.insert(node.object_name.clone());
+                }
+                "K2Node_CallFunction" | "K2Node_CallParentFunction" | "K2Node_CallArrayFunction" => {
+                    if let Some(name) = member_name(&read(), "FunctionReference") {
+                        calls.insert(name);
+                    }
+                }
+                _ => {}
+            }
+        }
+        graphs.push(GraphSummary {
+            name: graph.object_name.clone(),
+            kind: kind.to_string(),
+            node_count,
+            calls: calls.into_iter().collect(),
+        });
+    }
+
+    let functions = graphs.iter().filter(|g| g.kind == "Function").map(|g| g.name.clone()).collect();
+    Ok(BlueprintSummary {
+        name: export.object_name.clone(),
+        parent_class: properties::find(&props, "ParentClass")
+            .and_then(|v| v.as_str())
+            .map(|p| p.rsplit(['.', '/']).next().unwrap_or(p).to_string()),
+        events: events.into_iter().collect(),
+        functions,
+        variables,
+        graphs,
+    })
+}
+
+fn member_name(props: &[properties::Property], reference: &str) -> Option<String> {
+    properties::find(props, reference)?
+        .field("MemberName")?
+        .as_str()
+        .map(str::to_string)
+}
@@ -0,0 +1,74 @@
+// Reference integrity: dependencies recorded in package headers that no longer resolve to a
+// package on disk, usually left behind by a delete or a move without fixing up redirectors.
+// Only mounts the index covers can be checked; references into anything else (engine
+// content that isn't mounted, plugins outside the project) are counted, not flagged.
+
+use crate::error::CodexResult;
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use crate::package::Package;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BrokenReference {
+    pub referencer: String,
+    pub file_path: String,
+    pub missing: String,
+    /// False for import-table (hard) references, true for soft object paths.
+    pub soft: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BrokenReferenceReport {
+    pub broken: Vec<BrokenReference>,
+    /// Mount points that were referenced but not indexed, with how many references went unchecked.
+    pub unchecked_mounts: BTreeMap<String, usize>,
+}
+
+fn mount_of(path: &str) -> &str {
+    let end = path.get(1..).and_then(|rest| rest.find('/')).map(|i| i + 1).unwrap_or(path.len());
+    &path[..end]
+}
+
+pub fn find(index: &AssetIndex) -> BrokenReferenceReport {
+    let mut mounts: HashSet<String> = index.assets.iter().map(|a| a.asset.mount.to_lowercase()).collect();
+    mounts.insert("/game".to_string());
+
+    let mut broken = Vec::new();
+    let mut unchecked_mounts: BTreeMap<String, usize> = BTreeMap::new();
+    for asset in &index.assets {
+        let mut missing: BTreeSet<&str> = BTreeSet::new();
+        for dep in &asset.dependencies {
+            let package = crate::package::package_of_object_path(dep);
+            let mount = mount_of(package);
+            if !mounts.contains(&mount.to_lowercase()) {
+                *unchecked_mounts.entry(mount.to_string()).or_default() += 1;
+            } else if !index.contains(package) {
+                missing.insert(package);
+            }
+        }
+        if missing.is_empty() {
+            continue;
+        }
+        // The index merges hard and soft references; the header tells them apart again.
+        let hard: HashSet<String> = Package::read_file(Path::new(&asset.asset.file_path))
+            .map(|p| p.import_packages().iter().map(|i| i.to_lowercase()).collect())
+            .unwrap_or_default();
+        broken.extend(missing.into_iter().map(|package| BrokenReference {
+            referencer: asset.asset.path.clone(),
+            file_path: asset.asset.file_path.clone(),
+            missing: package.to_string(),
+            soft: !hard.contains(&package.to_lowercase()),
+        }));
+    }
+    BrokenReferenceReport { broken, unchecked_mounts }
+}
+
+#[tauri::command(async)]
+pub fn find_broken_references(manager: State<ScanManager>, project: String) -> CodexResult<BrokenReferenceReport> {
+    let index = manager.index(&project)?;
+    Ok(find(&index))
+}
@@ -0,0 +1,98 @@
+// Whether each level's lighting and HLODs have been built, and whether the build predates
+// the map. Lighting lives in a `<Map>_BuiltData` package next to the map. HLODs are
+// `<Map>_*` packages in the map folder's `HLOD` subfolder (UE4), or WorldPartitionHLOD
+// external actors for World Partition maps.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use serde::Serialize;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BuildArtifact {
+    pub packages: Vec<String>,
+    /// Oldest write time among the packages, seconds since the Unix epoch.
+    pub modified: u64,
+    /// Built before the last change to the level.
+    pub stale: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LevelBuildState {
+    pub level: String,
+    pub file_path: String,
+    /// Last change to the map, including its external actors for World Partition maps.
+    pub modified: u64,
+    pub world_partition: bool,
+    pub lighting: Option<BuildArtifact>,
+    pub hlod: Option<BuildArtifact>,
+}
+
+fn artifact(assets: &[&IndexedAsset], level_modified: u64) -> Option<BuildArtifact> {
+    let modified = assets.iter().map(|a| a.asset.modified).min()?;
+    let mut packages: Vec<String> = assets.iter().map(|a| a.asset.path.clone()).collect();
+    packages.sort();
+    Some(BuildArtifact { packages, modified, stale: modified < level_modified })
+}
+
+/// `/Game/__ExternalActors__/Maps/Level` for `/Game/Maps/Level`.
+pub fn external_actors_folder(level: &IndexedAsset) -> String {
+    let mount = &level.asset.mount;
+    let relative = level.asset.path.get(mount.len()..).unwrap_or_default();
+    format!("{}/__ExternalActors__{}", mount, relative)
+}
+
+fn level_state(index: &AssetIndex, level: &IndexedAsset) -> LevelBuildState {
+    let path = &level.asset.path;
+    let built_data = index.get(&format!("{}_BuiltData", path));
+
+    let external_folder = external_actors_folder(level);
+    let (hlod_actors, actors): (Vec<&IndexedAsset>, Vec<&IndexedAsset>) = index
+        .assets
+        .iter()
+        .filter(|a| crate::index::is_in_folder(&a.asset.path, &external_folder))
+        .partition(|a| a.class.as_deref().is_some_and(|c| c.starts_with("WorldPartitionHLOD")));
+    let world_partition = !actors.is_empty() || !hlod_actors.is_empty();
+    let modified = actors.iter().map(|a| a.asset.modified).chain([level.asset.modified]).max().unwrap_or(0);
+
+    let hlods: Vec<&IndexedAsset> = if world_partition {
+        hlod_actors
+    } else {
+        let hlod_folder = format!("{}/HLOD", level.folder());
+        let prefix = format!("{}_", level.asset.name.to_lowercase());
+        index
+            .assets
+            .iter()
+            .filter(|a| {
+                a.folder().eq_ignore_ascii_case(&hlod_folder) && a.asset.name.to_lowercase().starts_with(&prefix)
+            })
+            .collect()
+    };
+
+    LevelBuildState {
+        level: path.clone(),
+        file_path: level.asset.file_path.clone(),
+        modified,
+        world_partition,
+        lighting: built_data.and_then(|b| artifact(&[b], modified)),
+        hlod: artifact(&hlods, modified),
+    }
+}
+
+pub fn build_state(index: &AssetIndex) -> Vec<LevelBuildState> {
+    let mut states: Vec<LevelBuildState> = index
+        .assets
+        .iter()
+        .filter(|a| a.asset.asset_type == "Level" && !a.folder().contains("/__External"))
+        .map(|level| level_state(index, level))
+        .collect();
+    states.sort_by(|a, b| a.level.cmp(&b.level));
+    states
+}
+
+#[tauri::command(async)]
+pub fn get_build_state(manager: State<ScanManager>, project: String) -> CodexResult<Vec<LevelBuildState>> {
+    let index = manager.index(&project)?;
+    Ok(build_state(&index))
+}
@@ -0,0 +1,164 @@
+// Pre-cook estimate of pak chunk contents: direct Asset Manager/label assignments
+// pushed down through dependency closures, with unclaimed assets landing in chunk 0.
+
+use crate::asset_manager::{self, AssetManagerSettings, Manager};
+use crate::error::CodexResult;
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChunkReason {
+    /// Assigned by a primary asset rule or label.
+    Direct,
+    /// Pulled in by a recursively applied rule on something that references it.
+    Dependency,
+    /// Nothing claims it, so the cooker falls back to chunk 0.
+    Default,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkAsset {
+    pub path: String,
+    pub size: u64,
+    pub reason: ChunkReason,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkEstimate {
+    pub chunk_id: i64,
+    pub asset_count: usize,
+    pub total_size: u64,
+    pub assets: Vec<ChunkAsset>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicatedAsset {
+    pub path: String,
+    pub size: u64,
+    pub chunks: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChunkSizeReport {
+    pub chunks: Vec<ChunkEstimate>,
+    /// Assets that end up in more than one chunk and are therefore shipped several times.
+    pub duplicated_assets: Vec<DuplicatedAsset>,
+    pub duplicated_size: u64,
+    /// Assets excluded by a NeverCook rule.
+    pub never_cooked: Vec<String>,
+    pub total_size: u64,
+    /// Part of `total_size` that is Starter Content or template boilerplate.
+    pub starter_content_size: u64,
+}
+
+pub fn estimate(index: &AssetIndex) -> ChunkSizeReport {
+    let mut settings = AssetManagerSettings::load(&index.project);
+    let labels = asset_manager::read_labels(index);
+    let direct = asset_manager::collect_managers(index, &mut settings, &labels);
+
+    // Secondary assets inherit the rules of every manager that reaches them.
+    let mut inherited: BTreeMap<String, Vec<Manager>> = BTreeMap::new();
+    for (path, managers) in &direct {
+        for manager in managers.iter().filter(|m| m.rules.apply_recursively) {
+            let mut queue: VecDeque<&str> = VecDeque::from([path.as_str()]);
+            let mut seen = HashSet::new();
+            while let Some(current) = queue.pop_front() {
+                let Some(asset) = index.get(current) else {
+                    continue;
+                };
+                for dep in &asset.dependencies {
+                    let Some(target) = index.get(dep) else {
+                        continue;
+                    };
+                    let target = target.asset.path.as_str();
+                    if direct.contains_key(target) || !seen.insert(target) {
+                        continue;
+                    }
+                    inherited.entry(target.to_string()).or_default().push(manager.clone());
+                    queue.push_back(target);
+                }
+            }
+        }
+    }
+
+    let mut chunks: BTreeMap<i64, Vec<ChunkAsset>> = BTreeMap::new();
+    let mut duplicated_assets = Vec::new();
+    let mut never_cooked = Vec::new();
+    let mut starter_content_size = 0;
+    for asset in index.assets.iter().filter(|a| a.asset.is_package()) {
+        let path = &asset.asset.path;
+        let (managers, reason) = match (direct.get(path), inherited.get(path)) {
+            (Some(m), _) => (m.as_slice(), ChunkReason::Direct),
+            (None, Some(m)) => (m.as_slice(), ChunkReason::Dependency),
+            (None, None) => (&[][..], ChunkReason::Default),
+        };
+        if is_never_cook(managers) {
+            never_cooked.push(path.clone());
+            continue;
+        }
+        let mut assigned = asset_manager::effective_chunks(managers);
+        if assigned.is_empty() {
+            assigned.push(0);
+        }
+        if assigned.len() > 1 {
+            duplicated_assets.push(DuplicatedAsset {
+                path: path.clone(),
+                size: asset.size,
+                chunks: assigned.clone(),
+            });
+        }
+        if asset.asset.is_starter_content {
+            starter_content_size += asset.size * assigned.len() as u64;
+        }
+        for chunk_id in assigned {
+            chunks.entry(chunk_id).or_default().push(ChunkAsset {
+                path: path.clone(),
+                size: asset.size,
+                reason: reason.clone(),
+            });
+        }
+    }
+
+    let chunks: Vec<ChunkEstimate> = chunks
+        .into_iter()
+        .map(|(chunk_id, assets)| ChunkEstimate {
+            chunk_id,
+            asset_count: assets.len(),
+            total_size: assets.iter().map(|a| a.size).sum(),
+            assets,
+        })
+        .collect();
+    let duplicated_size = duplicated_assets
+        .iter()
+        .map(|d| d.size * (d.chunks.len() as u64 - 1))
+        .sum();
+
+    ChunkSizeReport {
+        total_size: chunks.iter().map(|c| c.total_size).sum(),
+        chunks,
+        duplicated_assets,
+        duplicated_size,
+        never_cooked,
+        starter_content_size,
+    }
+}
+
+fn is_never_cook(managers: &[Manager]) -> bool {
+    let Some(top) = managers.iter().map(|m| m.rules.priority).max() else {
+        return false;
+    };
+    managers
+        .iter()
+        .filter(|m| m.rules.priority == top)
+        .any(|m| m.rules.cook_rule == "NeverCook")
+}
+
+#[tauri::command]
+pub fn estimate_chunk_sizes(manager: State<ScanManager>, project: String) -> CodexResult<ChunkSizeReport> {
+    let index = manager.index(&project)?;
+    Ok(estimate(&index))
+}
@@ -0,0 +1,110 @@
+// Soft locks for teams without Perforce: before editing a binary asset, claim it so nobody
+// else starts on it and ends up with a merge git can't do. Claims live in the codex database
+// and travel through team sync; with sync configured, every claim and release syncs first so
+// it's checked against the team's latest state, and a read-only sync refuses them since they
+// would never leave this machine. Nothing stops the editor from saving a claimed asset; this
+// is coordination, not enforcement.
+
+use crate::codex_db::{AssetClaim, CodexDb};
+use crate::error::{CodexError, CodexResult};
+use serde::Serialize;
+use std::path::Path;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClaimedAsset {
+    pub path: String,
+    #[serde(flatten)]
+    pub claim: AssetClaim,
+}
+
+/// A claim or release saved to the local database.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClaimChange {
+    #[serde(flatten)]
+    pub claim: AssetClaim,
+    /// Why pushing it to the team failed; the next sync pushes it instead.
+    pub sync_error: Option<String>,
+}
+
+// Syncs with the team, if configured. A read-only sync is refused: claims made here would
+// never leave this machine.
+fn sync_claims(project: &Path) -> CodexResult<()> {
+    match CodexDb::read(project)?.team_sync {
+        Some(sync) if sync.read_only => Err(CodexError::message("team_sync_read_only", &[])),
+        Some(_) => crate::team_sync::sync(&project.to_string_lossy()).map(|_| ()),
+        None => Ok(()),
+    }
+}
+
+// Pushes a change that's already saved, so a failure is reported rather than returned.
+fn sync_after_change(project: &Path, claim: AssetClaim) -> ClaimChange {
+    let sync_error = sync_claims(project).err().map(|e| e.to_string());
+    ClaimChange { claim, sync_error }
+}
+
+pub fn claim(project: &Path, ue_path: &str, user: &str, note: Option<String>) -> CodexResult<ClaimChange> {
+    let path = crate::package::package_of_object_path(ue_path).to_string();
+    sync_claims(project)?;
+    let claim = CodexDb::update(project, |db| {
+        if let Some(held) = db.claims.get(&path).filter(|c| !c.released && c.user != user) {
+            return Err(CodexError::message("asset_claimed", &[("path", &path), ("user", &held.user)]));
+        }
+        let claim = AssetClaim {
+            user: user.to_string(),
+            timestamp: crate::scan::unix_seconds(SystemTime::now()),
+            note: note.filter(|n| !n.trim().is_empty()),
+            released: false,
+        };
+        db.claims.insert(path, claim.clone());
+        Ok(claim)
+    })?;
+    Ok(sync_after_change(project, claim))
+}
+
+/// `force` releases someone else's claim, for when they've left for the day holding it.
+pub fn release(project: &Path, ue_path: &str, user: &str, force: bool) -> CodexResult<ClaimChange> {
+    let path = crate::package::package_of_object_path(ue_path).to_string();
+    sync_claims(project)?;
+    let claim = CodexDb::update(project, |db| {
+        let held = db.claims.get_mut(&path).filter(|c| !c.released && (force || c.user == user));
+        let Some(claim) = held else {
+            return Err(CodexError::message("claim_not_held", &[("path", &path), ("user", &user)]));
+        };
+        claim.released = true;
+        claim.timestamp = crate::scan::unix_seconds(SystemTime::now());
+        Ok(claim.clone())
+    })?;
+    Ok(sync_after_change(project, claim))
+}
+
+/// Assets currently claimed, by path.
+pub fn active(project: &Path) -> CodexResult<Vec<ClaimedAsset>> {
+    let db = CodexDb::read(project)?;
+    Ok(db.claims.into_iter().filter(|(_, c)| !c.released).map(|(path, claim)| ClaimedAsset { path, claim }).collect())
+}
+
+#[tauri::command(async)]
+pub fn claim_asset(project: String, path: String, user: String, note: Option<String>) -> CodexResult<ClaimChange> {
+    if user.trim().is_empty() {
+        return Err(CodexError::message("user_required", &[]));
+    }
+    claim(Path::new(&project), &path, user.trim(), note)
+}
+
+#[tauri::command(async)]
+pub fn release_asset(project: String, path: String, user: String, force: Option<bool>) -> CodexResult<ClaimChange> {
+    release(Path::new(&project), &path, user.trim(), force.unwrap_or(false))
+}
+
+/// Claims from the local database, as of the last sync; the indicators in the asset list.
+#[tauri::command]
+pub fn list_asset_claims(project: String) -> CodexResult<Vec<ClaimedAsset>> {
+    active(Path::new(&project))
+}
+
+#[tauri::command]
+pub fn get_asset_claim(project: String, path: String) -> CodexResult<Option<AssetClaim>> {
+    let db = CodexDb::read(Path::new(&project))?;
+    Ok(db.claims.get(crate::package::package_of_object_path(&path)).filter(|c| !c.released).cloned())
+}
@@ -0,0 +1,253 @@
+// The codex database: what the codex knows about a project beyond the files themselves
+// (reviews, links, notes, the UI workspace to resume). One JSON document per project, kept in
+// the project's Saved folder so it stays on this machine and out of source control. Team sync
+// (team_sync.rs) can share the reviews, issue links, asset claims and audit snapshot through a
+// common location.
+
+use crate::error::{CodexError, CodexResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// Serialises read-modify-write cycles from concurrent commands.
+static DB_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReviewState {
+    NeedsReview,
+    Approved,
+    Rejected,
+}
+
+impl ReviewState {
+    pub fn parse(text: &str) -> CodexResult<ReviewState> {
+        match text {
+            "needs-review" => Ok(ReviewState::NeedsReview),
+            "approved" => Ok(ReviewState::Approved),
+            "rejected" => Ok(ReviewState::Rejected),
+            _ => Err(CodexError::message("unknown_review_state", &[("state", &text)])),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReviewState::NeedsReview => "needs-review",
+            ReviewState::Approved => "approved",
+            ReviewState::Rejected => "rejected",
+        }
+    }
+
+    /// Approval and rejection are verdicts on a pending review; any verdict can be reopened.
+    pub fn can_move_to(self, next: ReviewState) -> bool {
+        match next {
+            ReviewState::NeedsReview => true,
+            ReviewState::Approved | ReviewState::Rejected => self == ReviewState::NeedsReview,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewEvent {
+    pub state: ReviewState,
+    pub reviewer: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    pub state: ReviewState,
+    pub reviewer: String,
+    pub timestamp: u64,
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Every transition, oldest first.
+    #[serde(default)]
+    pub history: Vec<ReviewEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLink {
+    /// Tracker key, e.g. `GAME-1234`.
+    pub key: String,
+    pub linked_by: String,
+    pub timestamp: u64,
+}
+
+/// A soft lock: someone said they're editing the asset, so others should leave it alone.
+/// Released claims are kept with the release time so the release wins when synced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetClaim {
+    pub user: String,
+    /// When it was claimed, or released.
+    pub timestamp: u64,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub released: bool,
+}
+
+/// Jira connection used to look up issue status. The token stays in this file, which never
+/// leaves the machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTracker {
+    /// e.g. `https://studio.atlassian.net`
+    pub base_url: String,
+    pub user: String,
+    #[serde(default)]
+    pub token: Option<String>,
+}
+
+/// Slack or Discord incoming webhook that audit regressions are posted to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notifier {
+    pub webhook_url: String,
+    #[serde(default = "enabled")]
+    pub enabled: bool,
+}
+
+fn enabled() -> bool {
+    true
+}
+
+/// What the audits found after the last notified rescan; the next one reports only what's new.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditSnapshot {
+    pub timestamp: u64,
+    /// `referencer -> missing`
+    #[serde(default)]
+    pub broken_references: BTreeSet<String>,
+    #[serde(default)]
+    pub large_assets: BTreeSet<String>,
+    #[serde(default)]
+    pub corrupt_assets: BTreeSet<String>,
+}
+
+/// When and where the nightly audit report is written.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportSchedule {
+    /// Local `HH:MM`.
+    pub time: String,
+    pub folder: String,
+    /// `json`, `html` or both.
+    pub formats: Vec<String>,
+    /// Local date (`YYYY-MM-DD`) of the last scheduled run.
+    #[serde(default)]
+    pub last_run: Option<String>,
+}
+
+/// Shared location the team's reviews, issue links, claims and audit snapshot are synced through.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamSync {
+    /// A folder (network share) or an `https://` S3-compatible bucket URL, path-style.
+    pub location: String,
+    /// Pull only; this machine never uploads.
+    #[serde(default)]
+    pub read_only: bool,
+    #[serde(default)]
+    pub region: Option<String>,
+    #[serde(default)]
+    pub access_key: Option<String>,
+    #[serde(default)]
+    pub secret_key: Option<String>,
+    #[serde(default)]
+    pub last_sync: Option<u64>,
+}
+
+/// UI state saved on exit and restored when the project is opened again.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkspaceState {
+    /// Active view, e.g. `assets` or `graph`.
+    #[serde(default)]
+    pub view: Option<String>,
+    #[serde(default)]
+    pub search: Option<String>,
+    /// Filter name -> value, as the UI encodes them.
+    #[serde(default)]
+    pub filters: BTreeMap<String, String>,
+    /// Package paths.
+    #[serde(default)]
+    pub selected_assets: Vec<String>,
+    /// Detail panels left open, e.g. `dependencies:/Game/Maps/Arena`.
+    #[serde(default)]
+    pub open_panels: Vec<String>,
+    /// Content roots mounted from outside the project at save time.
+    #[serde(default)]
+    pub mounted_roots: Vec<crate::workspace::MountedRoot>,
+    #[serde(default)]
+    pub saved_at: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CodexDb {
+    /// Package path -> review.
+    #[serde(default)]
+    pub reviews: BTreeMap<String, ReviewRecord>,
+    /// Package path -> linked issues, oldest first.
+    #[serde(default)]
+    pub issues: BTreeMap<String, Vec<IssueLink>>,
+    /// Package path -> when its issue links last changed, so sync can tell an unlink from a
+    /// link the other side hasn't seen yet.
+    #[serde(default)]
+    pub issue_changes: BTreeMap<String, u64>,
+    /// Package path -> latest claim.
+    #[serde(default)]
+    pub claims: BTreeMap<String, AssetClaim>,
+    #[serde(default)]
+    pub issue_tracker: Option<IssueTracker>,
+    #[serde(default)]
+    pub notifier: Option<Notifier>,
+    #[serde(default)]
+    pub audit_snapshot: Option<AuditSnapshot>,
+    #[serde(default)]
+    pub report_schedule: Option<ReportSchedule>,
+    #[serde(default)]
+    pub team_sync: Option<TeamSync>,
+    #[serde(default)]
+    pub workspace: Option<WorkspaceState>,
+}
+
+pub fn db_path(project: &Path) -> PathBuf {
+    project.join("Saved").join("BlueprintCodex").join("codex.json")
+}
+
+impl CodexDb {
+    pub fn load(project: &Path) -> CodexResult<CodexDb> {
+        let path = db_path(project);
+        match std::fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(|e| CodexError::parse(&path, e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(CodexDb::default()),
+            Err(e) => Err(CodexError::io(&path, &e)),
+        }
+    }
+
+    pub fn save(&self, project: &Path) -> CodexResult<()> {
+        let path = db_path(project);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| CodexError::io(dir, &e))?;
+        }
+        // Write then rename, so a crash mid-write never leaves a truncated database.
+        let json = serde_json::to_vec_pretty(self).map_err(|e| e.to_string())?;
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, json).map_err(|e| CodexError::io(&tmp, &e))?;
+        std::fs::rename(&tmp, &path).map_err(|e| CodexError::io(&path, &e))
+    }
+
+    /// Loads, applies `change` and saves, holding the database lock throughout.
+    pub fn update<T>(project: &Path, change: impl FnOnce(&mut CodexDb) -> CodexResult<T>) -> CodexResult<T> {
+        let _guard = DB_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut db = CodexDb::load(project)?;
+        let result = change(&mut db)?;
+        db.save(project)?;
+        Ok(result)
+    }
+
+    pub fn read(project: &Path) -> CodexResult<CodexDb> {
+        let _guard = DB_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        CodexDb::load(project)
+    }
+}
@@ -0,0 +1,166 @@
+// Engine version checks: assets saved by a newer engine than the project uses won't load.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::package::{Package, VER_UE5_NEWEST_SUPPORTED};
+use crate::project::{EngineRelease, UProject};
+use crate::scan::UnrealAsset;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NewerAsset {
+    pub path: String,
+    pub file_path: String,
+    /// The saving engine, or the package version when the engine wasn't recorded.
+    pub saved_by: String,
+    pub compatible_with: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineCompatReport {
+    pub engine_association: String,
+    /// `None` when the association is a source build we can't resolve.
+    pub project_engine: Option<String>,
+    pub newer_assets: Vec<NewerAsset>,
+    /// Asset count per saving engine release, e.g. `{"5.3": 120, "5.4": 2}`.
+    pub saved_by_versions: BTreeMap<String, usize>,
+    /// Packages without a recorded engine version (licensee or unversioned saves).
+    pub unknown_version: Vec<String>,
+    pub unreadable: Vec<String>,
+}
+
+pub fn check(project: &Path, assets: Vec<UnrealAsset>) -> CodexResult<EngineCompatReport> {
+    let uproject = UProject::load(project)?;
+    let project_engine = uproject.engine_release();
+    // A source-build GUID that isn't registered here would make every asset look compatible.
+    if project_engine.is_none() && !uproject.engine_association.is_empty() {
+        return Err(CodexError::EngineNotFound { association: uproject.engine_association });
+    }
+
+    let mut report = EngineCompatReport {
+        engine_association: uproject.engine_association.clone(),
+        project_engine: project_engine.map(|r| r.to_string()),
+        newer_assets: Vec::new(),
+        saved_by_versions: BTreeMap::new(),
+        unknown_version: Vec::new(),
+        unreadable: Vec::new(),
+    };
+    for asset in assets.into_iter().filter(|a| a.is_package()) {
+        // Only the summary: a full read would refuse the newer packages this report is after.
+        let Ok(summary) = Package::read_saved_versions(Path::new(&asset.file_path)) else {
+            report.unreadable.push(asset.path);
+            continue;
+        };
+        // A package version past the newest this reader knows can only come from a newer engine.
+        let too_new = summary.file_version_ue5 > VER_UE5_NEWEST_SUPPORTED;
+        let saved_by = &summary.saved_by_engine_version;
+        let newer = if saved_by.major == 0 {
+            if !too_new {
+                report.unknown_version.push(asset.path);
+                continue;
+            }
+            true
+        } else {
+            let release = EngineRelease {
+                major: saved_by.major,
+                minor: saved_by.minor,
+            };
+            *report.saved_by_versions.entry(release.to_string()).or_default() += 1;
+            project_engine.is_some_and(|p| release > p)
+        };
+        if newer {
+            report.newer_assets.push(NewerAsset {
+                path: asset.path,
+                file_path: asset.file_path,
+                saved_by: if saved_by.major == 0 {
+                    format!("package version {}", summary.file_version_ue5)
+                } else {
+                    saved_by.to_string()
+                },
+                compatible_with: summary.compatible_with_engine_version.to_string(),
+            });
+        }
+    }
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn check_engine_compat(manager: State<ScanManager>, project: String) -> CodexResult<EngineCompatReport> {
+    check(Path::new(&project), manager.assets(&project)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::package::PACKAGE_FILE_TAG;
+    use std::path::PathBuf;
+
+    /// A summary through the engine versions, laid out for UE4 version 522 and UE5 versions from
+    /// 1016 on, saved by `saved_by` (`(0, 0)` when the engine wasn't recorded).
+    fn summary(ue5: i32, saved_by: (u16, u16)) -> Vec<u8> {
+        let mut bytes = PACKAGE_FILE_TAG.to_le_bytes().to_vec();
+        // Legacy, UE3, UE4, UE5 and licensee versions, then no custom versions.
+        for value in [-8i32, 864, 522, ue5, 0, 0] {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes.extend([0u8; 20]); // SavedHash
+
+        // Header size, empty package name, flags and the table counts and offsets up to the
+        // generations, all zero; the GUID and cell fields are counted as four ints each.
+        bytes.extend([0u8; 29 * 4]);
+        for _ in 0..2 {
+            bytes.extend(saved_by.0.to_le_bytes());
+            bytes.extend(saved_by.1.to_le_bytes());
+            bytes.extend(0u16.to_le_bytes()); // patch
+            bytes.extend(0u32.to_le_bytes()); // changelist
+            bytes.extend(0i32.to_le_bytes()); // empty branch
+        }
+        bytes.resize(bytes.len() + 64, 0);
+        bytes
+    }
+
+    fn project_with(packages: &[(&str, Vec<u8>)]) -> (PathBuf, Vec<UnrealAsset>) {
+        let project = std::env::temp_dir().join(format!("codex-compat-{}", std::process::id()));
+        std::fs::create_dir_all(project.join("Content")).unwrap();
+        std::fs::write(project.join("Test.uproject"), r#"{"EngineAssociation": "5.4"}"#).unwrap();
+        let assets = packages
+            .iter()
+            .map(|(name, bytes)| {
+                let file_path = project.join("Content").join(format!("{}.uasset", name));
+                std::fs::write(&file_path, bytes).unwrap();
+                UnrealAsset {
+                    name: name.to_string(),
+                    path: format!("/Game/{}", name),
+                    file_path: file_path.to_string_lossy().to_string(),
+                    asset_type: "Asset".to_string(),
+                    size: bytes.len() as u64,
+                    modified: 0,
+                    is_large: false,
+                    mount: "/Game".to_string(),
+                    owner: None,
+                    locked: false,
+                    is_starter_content: false,
+                }
+            })
+            .collect();
+        (project, assets)
+    }
+
+    #[test]
+    fn packages_newer_than_the_reader_count_as_newer_assets() {
+        let (project, assets) = project_with(&[
+            ("Current", summary(1017, (5, 4))),
+            ("Newer", summary(1018, (5, 8))),
+            ("Unrecorded", summary(1018, (0, 0))),
+        ]);
+        let report = check(&project, assets).unwrap();
+        assert!(report.unreadable.is_empty());
+        let newer: Vec<&str> = report.newer_assets.iter().map(|a| a.path.as_str()).collect();
+        assert_eq!(newer, ["/Game/Newer", "/Game/Unrecorded"]);
+        assert_eq!(report.newer_assets[0].saved_by, "5.8.0");
+        assert_eq!(report.saved_by_versions.get("5.4"), Some(&1));
+    }
+}
@@ -0,0 +1,204 @@
+// Unreal-flavoured ini parsing: sections, array operators (+ - . !) and the
+// parenthesised struct syntax used for values like `(Path="/Game/Maps")`.
+
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Default)]
+pub struct IniFile {
+    sections: BTreeMap<String, Vec<(String, String)>>,
+}
+
+impl IniFile {
+    pub fn parse(text: &str) -> IniFile {
+        let mut ini = IniFile::default();
+        let mut section = String::new();
+        for raw in text.lines() {
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') && line.ends_with(']') {
+                section = line[1..line.len() - 1].trim().to_string();
+                ini.sections.entry(section.clone()).or_default();
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = unquote(value.trim()).to_string();
+            let entries = ini.sections.entry(section.clone()).or_default();
+            let (op, key) = match key.trim().chars().next() {
+                Some(c @ ('+' | '-' | '.' | '!')) => (c, key.trim()[1..].trim().to_string()),
+                _ => (' ', key.trim().to_string()),
+            };
+            match op {
+                '+' => {
+                    if !entries.iter().any(|(k, v)| k == &key && v == &value) {
+                        entries.push((key, value));
+                    }
+                }
+                '.' => entries.push((key, value)),
+                '-' => entries.retain(|(k, v)| !(k == &key && v == &value)),
+                '!' => entries.retain(|(k, _)| k != &key),
+                _ => {
+                    entries.retain(|(k, _)| k != &key);
+                    entries.push((key, value));
+                }
+            }
+        }
+        ini
+    }
+
+    pub fn entries(&self, section: &str) -> &[(String, String)] {
+        self.sections.get(section).map(|e| e.as_slice()).unwrap_or(&[])
+    }
+
+    pub fn get_all(&self, section: &str, key: &str) -> Vec<&str> {
+        self.entries(section)
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+            .collect()
+    }
+}
+
+pub fn parse_bool(value: &str) -> bool {
+    matches!(value.trim().to_ascii_lowercase().as_str(), "true" | "1" | "yes" | "on")
+}
+
+fn unquote(value: &str) -> &str {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        &value[1..value.len() - 1]
+    } else {
+        value
+    }
+}
+
+/// A value in exported-text form: either plain text or a parenthesised group.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    Text(String),
+    Group(Vec<(Option<String>, ConfigValue)>),
+}
+
+impl ConfigValue {
+    pub fn parse(text: &str) -> ConfigValue {
+        let chars: Vec<char> = text.trim().chars().collect();
+        let mut pos = 0;
+        parse_value(&chars, &mut pos)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&ConfigValue> {
+        match self {
+            ConfigValue::Group(items) => items
+                .iter()
+                .find(|(k, _)| k.as_deref().is_some_and(|k| k.eq_ignore_ascii_case(key)))
+                .map(|(_, v)| v),
+            ConfigValue::Text(_) => None,
+        }
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        match self.get(key) {
+            Some(ConfigValue::Text(s)) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get_str(key).and_then(|s| s.trim().parse().ok())
+    }
+
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get_str(key).map(parse_bool)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            ConfigValue::Text(s) => Some(s.as_str()),
+            ConfigValue::Group(_) => None,
+        }
+    }
+
+    /// Unnamed members of a group, e.g. the entries of `((Path="/Game"),(Path="/Game/UI"))`.
+    pub fn items(&self) -> Vec<&ConfigValue> {
+        match self {
+            ConfigValue::Group(items) => items.iter().map(|(_, v)| v).collect(),
+            ConfigValue::Text(s) if s.is_empty() => Vec::new(),
+            ConfigValue::Text(_) => vec![self],
+        }
+    }
+}
+
+fn parse_value(chars: &[char], pos: &mut usize) -> ConfigValue {
+    skip_ws(chars, pos);
+    if *pos < chars.len() && chars[*pos] == '(' {
+        *pos += 1;
+        let mut items = Vec::new();
+        loop {
+            skip_ws(chars, pos);
+            if *pos >= chars.len() {
+                break;
+            }
+            if chars[*pos] == ')' {
+                *pos += 1;
+                break;
+            }
+            if chars[*pos] == ',' {
+                *pos += 1;
+                continue;
+            }
+            // Either `Key=Value` or a bare value.
+            let start = *pos;
+            let mut key = None;
+            if chars[*pos] != '(' && chars[*pos] != '"' {
+                let mut end = *pos;
+                while end < chars.len() && !matches!(chars[end], '=' | ',' | ')' | '(') {
+                    end += 1;
+                }
+                if end < chars.len() && chars[end] == '=' {
+                    key = Some(chars[start..end].iter().collect::<String>().trim().to_string());
+                    *pos = end + 1;
+                }
+            }
+            let value = parse_value(chars, pos);
+            items.push((key, value));
+        }
+        return ConfigValue::Group(items);
+    }
+
+    if *pos < chars.len() && chars[*pos] == '"' {
+        *pos += 1;
+        let mut text = String::new();
+        while *pos < chars.len() && chars[*pos] != '"' {
+            if chars[*pos] == '\\' && *pos + 1 < chars.len() {
+                *pos += 1;
+            }
+            text.push(chars[*pos]);
+            *pos += 1;
+        }
+        *pos += 1;
+        return ConfigValue::Text(text);
+    }
+
+    // Bare text runs to the next separator, keeping nested quotes (e.g. Class'"/Game/X"').
+    let mut text = String::new();
+    let mut in_quote = false;
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        if c == '"' {
+            in_quote = !in_quote;
+        } else if !in_quote && (c == ',' || c == ')') {
+            break;
+        }
+        text.push(c);
+        *pos += 1;
+    }
+    ConfigValue::Text(text.trim().to_string())
+}
+
+fn skip_ws(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos].is_whitespace() {
+        *pos += 1;
+    }
+}
@@ -0,0 +1,103 @@
+// Curve assets (and the curves Blueprint timelines keep as subobjects): key counts and the
+// time/value range of each channel.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CurveChannel {
+    /// `Value` for float curves, `X`/`Y`/`Z` or `R`/`G`/`B`/`A` otherwise.
+    pub channel: String,
+    pub key_count: usize,
+    pub time_range: Option<(f64, f64)>,
+    pub value_range: Option<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CurveSummary {
+    pub name: String,
+    pub class: String,
+    pub channels: Vec<CurveChannel>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CurveInfo {
+    pub class: String,
+    pub curves: Vec<CurveSummary>,
+}
+
+fn channel_names(class: &str) -> Option<&'static [&'static str]> {
+    match class {
+        "CurveFloat" => Some(&["Value"]),
+        "CurveVector" => Some(&["X", "Y", "Z"]),
+        "CurveLinearColor" => Some(&["R", "G", "B", "A"]),
+        _ => None,
+    }
+}
+
+fn channel(name: &str, curve: Option<&PropertyValue>) -> CurveChannel {
+    // RichCurveKey reads as [interp, time, value, ...].
+    let keys: Vec<(f64, f64)> = match curve.and_then(|c| c.field("Keys")) {
+        Some(PropertyValue::Array(keys)) => keys
+            .iter()
+            .filter_map(|k| match k {
+                PropertyValue::Numbers(n) if n.len() >= 3 => Some((n[1], n[2])),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    let range = |values: Vec<f64>| {
+        let min = values.iter().copied().reduce(f64::min)?;
+        let max = values.iter().copied().reduce(f64::max)?;
+        Some((min, max))
+    };
+    CurveChannel {
+        channel: name.to_string(),
+        key_count: keys.len(),
+        time_range: range(keys.iter().map(|k| k.0).collect()),
+        value_range: range(keys.iter().map(|k| k.1).collect()),
+    }
+}
+
+fn summarize(name: &str, class: &str, props: &[Property]) -> CurveSummary {
+    let channels = match channel_names(class).unwrap_or_default() {
+        [single] => vec![channel(single, properties::find(props, "FloatCurve"))],
+        // FloatCurves is a fixed-size array, saved as one property per element.
+        names => names
+            .iter()
+            .enumerate()
+            .map(|(i, n)| {
+                let curve = props.iter().find(|p| p.name == "FloatCurves" && p.array_index == i as i32);
+                channel(n, curve.map(|p| &p.value))
+            })
+            .collect(),
+    };
+    CurveSummary { name: name.to_string(), class: class.to_string(), channels }
+}
+
+pub fn inspect(file_path: &Path) -> CodexResult<CurveInfo> {
+    let package = Package::read_file(file_path)?;
+    let class = package.main_class().unwrap_or_default();
+    let mut curves = Vec::new();
+    for export in &package.exports {
+        let export_class = package.export_class(export);
+        if channel_names(&export_class).is_none() {
+            continue;
+        }
+        let props = properties::read_export_properties(file_path, &package, export)?;
+        curves.push(summarize(&export.object_name, &export_class, &props));
+    }
+    if curves.is_empty() {
+        return Err(CodexError::message("no_curves", &[("class", &class)]));
+    }
+    Ok(CurveInfo { class, curves })
+}
+
+#[tauri::command]
+pub fn get_curve_info(file_path: String) -> CodexResult<CurveInfo> {
+    inspect(Path::new(&file_path))
+}
@@ -0,0 +1,138 @@
+// Data assets: instances of DataAsset / PrimaryDataAsset subclasses, grouped by the native
+// class they're built on, with their top-level property values. The class hierarchy comes
+// from the project's C++ headers and, for Blueprint data asset classes, from the Blueprint's
+// native parent.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use tauri::State;
+
+// Most specific first; PrimaryDataAsset is a DataAsset.
+const DATA_ASSET_BASES: &[&str] = &["PrimaryDataAsset", "DataAsset"];
+
+// Long values (big arrays, nested structs) are cut short; the asset dump has the rest.
+const MAX_VALUE_LEN: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataAssetProperty {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataAssetEntry {
+    pub path: String,
+    /// The asset's own class; a Blueprint class ends in `_C`.
+    pub class: String,
+    pub primary_asset_type: Option<String>,
+    pub properties: Vec<DataAssetProperty>,
+    /// Why the properties couldn't be read.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DataAssetGroup {
+    pub native_class: String,
+    /// `DataAsset` or `PrimaryDataAsset`.
+    pub base: String,
+    pub assets: Vec<DataAssetEntry>,
+}
+
+/// `DataAsset` or `PrimaryDataAsset` if `class` derives from one.
+fn data_asset_base(class: &str, parents: &HashMap<String, String>) -> Option<&'static str> {
+    DATA_ASSET_BASES.iter().copied().find(|base| crate::native_classes::derives_from(parents, class, base))
+}
+
+/// Native class behind an asset's class: the class itself, or a Blueprint class's native parent.
+fn native_class<'a>(index: &'a AssetIndex, blueprints: &HashMap<String, &'a IndexedAsset>, class: &'a str) -> &'a str {
+    match blueprints.get(class).copied() {
+        Some(blueprint) => crate::asset_manager::native_parent(index, blueprint).unwrap_or(class),
+        None => class,
+    }
+}
+
+fn truncate(mut value: String) -> String {
+    if value.chars().count() > MAX_VALUE_LEN {
+        value = value.chars().take(MAX_VALUE_LEN).collect();
+        value.push('…');
+    }
+    value
+}
+
+fn key_properties(file_path: &Path) -> CodexResult<Vec<DataAssetProperty>> {
+    let package = Package::read_file(file_path)?;
+    let Some(main) = package.main_export() else {
+        return Ok(Vec::new());
+    };
+    let props = properties::read_export_properties(file_path, &package, main)?;
+    Ok(props
+        .into_iter()
+        .filter(|p| !matches!(p.value, PropertyValue::Raw { .. }))
+        .map(|p| {
+            let name = if p.array_index > 0 { format!("{}[{}]", p.name, p.array_index) } else { p.name };
+            DataAssetProperty { name, value: truncate(p.value.display()) }
+        })
+        .collect())
+}
+
+/// Data assets grouped by native class, largest group first. `class_filter` matches the
+/// native class or the asset's own class, case-insensitively.
+pub fn list(index: &AssetIndex, class_filter: Option<&str>) -> Vec<DataAssetGroup> {
+    let parents = crate::native_classes::parent_map(&crate::native_classes::index(&index.project));
+    let blueprints: HashMap<String, &IndexedAsset> = index
+        .assets
+        .iter()
+        .filter(|a| a.tag("ParentClass").is_some())
+        .map(|a| (format!("{}_C", a.asset.name), a))
+        .collect();
+
+    let mut groups: BTreeMap<String, DataAssetGroup> = BTreeMap::new();
+    for asset in &index.assets {
+        let Some(class) = asset.class.as_deref() else {
+            continue;
+        };
+        let native = native_class(index, &blueprints, class);
+        let Some(base) = data_asset_base(native, &parents) else {
+            continue;
+        };
+        let matches = |filter: &str| filter.eq_ignore_ascii_case(native) || filter.eq_ignore_ascii_case(class);
+        if !class_filter.is_none_or(matches) {
+            continue;
+        }
+        let (properties, error) = match key_properties(Path::new(&asset.asset.file_path)) {
+            Ok(properties) => (properties, None),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+        let group = groups.entry(native.to_string()).or_insert_with(|| DataAssetGroup {
+            native_class: native.to_string(),
+            base: base.to_string(),
+            assets: Vec::new(),
+        });
+        group.assets.push(DataAssetEntry {
+            path: asset.asset.path.clone(),
+            class: class.to_string(),
+            primary_asset_type: asset.tag("PrimaryAssetType").map(str::to_string),
+            properties,
+            error,
+        });
+    }
+    let mut groups: Vec<DataAssetGroup> = groups.into_values().collect();
+    groups.sort_by(|a, b| b.assets.len().cmp(&a.assets.len()).then_with(|| a.native_class.cmp(&b.native_class)));
+    groups
+}
+
+#[tauri::command(async)]
+pub fn list_data_assets(
+    manager: State<ScanManager>,
+    project: String,
+    class_filter: Option<String>,
+) -> CodexResult<Vec<DataAssetGroup>> {
+    let index = manager.index(&project)?;
+    Ok(list(&index, class_filter.as_deref().map(str::trim).filter(|f| !f.is_empty())))
+}
@@ -0,0 +1,203 @@
+// `codex://` links, e.g. `codex://asset//Game/Blueprints/BP_Player`. The OS launches the app
+// with the link as an argument; if an instance is already running, the new one hands the
+// link over on a loopback socket and exits, so links always land in the open window.
+//
+// The scheme is registered per user on Windows (HKCU\Software\Classes) and Linux (an
+// xdg handler entry). Tauri 1 doesn't surface macOS open-URL events, so macOS isn't covered.
+
+use serde::Serialize;
+use std::io::{BufRead as _, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+pub const SCHEME: &str = "codex";
+
+// Fixed so a second instance can find the first; loopback only.
+const HANDOFF_PORT: u16 = 47615;
+// Sent back for every handed-over link, so another program on the port isn't mistaken for us.
+const HANDOFF_REPLY: &str = "codex-handoff-ok";
+
+// A link that arrived before the frontend was listening, collected with `take_pending_deep_link`.
+static PENDING: Mutex<Option<DeepLink>> = Mutex::new(None);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DeepLink {
+    /// `codex://asset/<package path>[?project=<folder>]`
+    Asset { path: String, project: Option<String> },
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|h| u8::from_str_radix(std::str::from_utf8(h).ok()?, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+pub fn parse(url: &str) -> Option<DeepLink> {
+    let rest = url.trim().strip_prefix(SCHEME)?.strip_prefix("://")?;
+    let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (kind, target) = rest.split_once('/')?;
+    let project = query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == "project")
+        .map(|(_, value)| percent_decode(value));
+    match kind {
+        "asset" => {
+            // Both `asset//Game/X` and `asset/Game/X` name `/Game/X`.
+            let path = format!("/{}", percent_decode(target).trim_start_matches('/').trim_end_matches('/'));
+            (path.len() > 1).then_some(DeepLink::Asset { path, project })
+        }
+        _ => None,
+    }
+}
+
+/// The link the app was launched with, if any.
+pub fn from_args() -> Option<String> {
+    std::env::args().skip(1).find(|arg| arg.starts_with(&format!("{}://", SCHEME)))
+}
+
+/// Passes `url` to an already running instance; false when there is none, or when whatever
+/// listens on the port doesn't answer like one.
+pub fn forward(url: &str) -> bool {
+    let address = SocketAddr::from((Ipv4Addr::LOCALHOST, HANDOFF_PORT));
+    let Ok(mut stream) = TcpStream::connect_timeout(&address, Duration::from_millis(500)) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+    if writeln!(stream, "{}", url).is_err() {
+        return false;
+    }
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply).is_ok() && reply.trim_end() == HANDOFF_REPLY
+}
+
+/// Shows the main window and tells the frontend which asset to focus.
+pub fn open(app: &AppHandle, url: &str) {
+    let Some(link) = parse(url) else {
+        return;
+    };
+    if let Some(window) = app.get_window("main") {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+    *PENDING.lock().unwrap() = Some(link.clone());
+    let _ = app.emit_all("deep-link", link);
+}
+
+/// Accepts links forwarded by later instances.
+pub fn listen(app: AppHandle) {
+    let Ok(listener) = TcpListener::bind((Ipv4Addr::LOCALHOST, HANDOFF_PORT)) else {
+        return;
+    };
+    std::thread::spawn(move || {
+        for stream in listener.incoming().filter_map(|s| s.ok()) {
+            let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
+            let mut line = String::new();
+            if BufReader::new(&stream).read_line(&mut line).is_ok_and(|n| n > 0) {
+                let _ = (&stream).write_all(format!("{}\n", HANDOFF_REPLY).as_bytes());
+                open(&app, &line);
+            }
+        }
+    });
+}
+
+/// Points the `codex` scheme at this executable for the current user. Re-run on every
+/// start so the registration follows the app when it's moved or updated.
+#[cfg(windows)]
+pub fn register_scheme() {
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Foundation::ERROR_SUCCESS;
+    use windows_sys::Win32::System::Registry::{
+        RegCloseKey, RegCreateKeyExW, RegSetValueExW, HKEY, HKEY_CURRENT_USER, KEY_SET_VALUE, REG_OPTION_NON_VOLATILE,
+        REG_SZ,
+    };
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    let wide = |s: &str| std::ffi::OsStr::new(s).encode_wide().chain(Some(0)).collect::<Vec<u16>>();
+    let key = format!(r"Software\Classes\{}", SCHEME);
+    let command = format!("\"{}\" \"%1\"", exe.display());
+    let entries: [(String, Option<&str>, String); 3] = [
+        (key.clone(), None, "URL:Blueprint Codex".to_string()),
+        (key.clone(), Some("URL Protocol"), String::new()),
+        (format!(r"{}\shell\open\command", key), None, command),
+    ];
+    for (path, name, value) in entries {
+        let (path, name, value) = (wide(&path), name.map(wide), wide(&value));
+        let mut handle: HKEY = std::ptr::null_mut();
+        unsafe {
+            let created = RegCreateKeyExW(
+                HKEY_CURRENT_USER,
+                path.as_ptr(),
+                0,
+                std::ptr::null(),
+                REG_OPTION_NON_VOLATILE,
+                KEY_SET_VALUE,
+                std::ptr::null(),
+                &mut handle,
+                std::ptr::null_mut(),
+            );
+            if created != ERROR_SUCCESS {
+                continue;
+            }
+            // No name sets the key's default value; the size includes the terminating null.
+            RegSetValueExW(
+                handle,
+                name.as_ref().map_or(std::ptr::null(), |n| n.as_ptr()),
+                0,
+                REG_SZ,
+                value.as_ptr().cast(),
+                (value.len() * 2) as u32,
+            );
+            RegCloseKey(handle);
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub fn register_scheme() {
+    use std::process::Command;
+    let (Ok(exe), Some(home)) = (std::env::current_exe(), std::env::var_os("HOME")) else {
+        return;
+    };
+    let applications = std::path::Path::new(&home).join(".local/share/applications");
+    let file_name = "blueprint-codex-url-handler.desktop";
+    let entry = format!(
+        "[Desktop Entry]\nType=Application\nName=Blueprint Codex\nExec=\"{}\" %u\nNoDisplay=true\n\
+         MimeType=x-scheme-handler/{};\n",
+        exe.display(),
+        SCHEME
+    );
+    if std::fs::create_dir_all(&applications).is_err() || std::fs::write(applications.join(file_name), entry).is_err() {
+        return;
+    }
+    let _ = Command::new("xdg-mime").args(["default", file_name, &format!("x-scheme-handler/{}", SCHEME)]).output();
+}
+
+#[cfg(not(any(windows, target_os = "linux")))]
+pub fn register_scheme() {}
+
+/// The last link opened, cleared once read; lets the frontend catch a link that
+/// arrived (or launched the app) before its `deep-link` listener was up.
+#[tauri::command]
+pub fn take_pending_deep_link() -> Option<DeepLink> {
+    PENDING.lock().unwrap().take()
+}
@@ -0,0 +1,156 @@
+// Chaos destruction content: Geometry Collections with their fracture piece counts, and the
+// caches recorded from them. Piece counts live in the collection's native data (a managed
+// array collection saved after the tagged properties), whose group table gives the number of
+// transforms, geometry pieces, vertices and faces.
+
+use crate::error::CodexResult;
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::State;
+
+const CACHE_CLASSES: &[&str] = &["GeometryCollectionCache", "ChaosCacheCollection"];
+
+// A group table has a handful of entries; anything beyond this isn't one.
+const MAX_GROUPS: i32 = 32;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GeometryCollectionInfo {
+    pub path: String,
+    pub size: u64,
+    pub is_large: bool,
+    /// Bones of the fracture hierarchy, clusters included.
+    pub transform_count: Option<i64>,
+    /// Pieces with geometry of their own.
+    pub piece_count: Option<i64>,
+    pub vertex_count: Option<i64>,
+    pub face_count: Option<i64>,
+    pub nanite: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChaosCache {
+    pub path: String,
+    pub class: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DestructionReport {
+    /// Most pieces first.
+    pub geometry_collections: Vec<GeometryCollectionInfo>,
+    pub caches: Vec<ChaosCache>,
+    pub total_size: u64,
+    pub total_pieces: i64,
+}
+
+fn read_i32(data: &[u8], at: usize) -> Option<i32> {
+    data.get(at..at + 4).map(|b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+/// The first `count, (FName, size) * count` table in `data` whose names include `Transform`:
+/// group name -> element count.
+fn group_sizes(package: &Package, data: &[u8]) -> Option<BTreeMap<String, i64>> {
+    'candidates: for at in 0..data.len().saturating_sub(4) {
+        let count = read_i32(data, at)?;
+        if !(1..=MAX_GROUPS).contains(&count) {
+            continue;
+        }
+        let mut groups = BTreeMap::new();
+        for entry in 0..count as usize {
+            let base = at + 4 + entry * 12;
+            let (Some(name), Some(number), Some(size)) =
+                (read_i32(data, base), read_i32(data, base + 4), read_i32(data, base + 8))
+            else {
+                continue 'candidates;
+            };
+            let name = usize::try_from(name).ok().and_then(|i| package.names.get(i));
+            match name {
+                Some(name) if number == 0 && size >= 0 => {
+                    groups.insert(name.clone(), size as i64);
+                }
+                _ => continue 'candidates,
+            }
+        }
+        if groups.contains_key("Transform") {
+            return Some(groups);
+        }
+    }
+    None
+}
+
+/// Export data of the main object, from the `.uasset` and, for split packages, the `.uexp`.
+fn main_export_data(file_path: &Path, package: &Package) -> Option<Vec<u8>> {
+    let export = package.main_export()?;
+    let mut data = std::fs::read(file_path).ok()?;
+    // A split package's export offsets continue past the header into the .uexp.
+    if let Ok(uexp) = std::fs::read(file_path.with_extension("uexp")) {
+        data.extend(uexp);
+    }
+    let start = (export.serial_offset + export.script_serialization_start.max(0)).max(0) as usize;
+    let end = (export.serial_offset + export.serial_size).max(0) as usize;
+    data.get(start..end.min(data.len())).map(|d| d.to_vec())
+}
+
+fn inspect(file_path: &Path, path: &str, size: u64, is_large: bool) -> GeometryCollectionInfo {
+    let mut info = GeometryCollectionInfo {
+        path: path.to_string(),
+        size,
+        is_large,
+        transform_count: None,
+        piece_count: None,
+        vertex_count: None,
+        face_count: None,
+        nanite: None,
+    };
+    let Ok(package) = Package::read_file(file_path) else {
+        return info;
+    };
+    info.nanite = package
+        .main_export()
+        .and_then(|e| properties::read_export_properties(file_path, &package, e).ok())
+        .and_then(|props| properties::find(&props, "EnableNanite").and_then(|v| v.as_bool()));
+    if let Some(groups) = main_export_data(file_path, &package).and_then(|data| group_sizes(&package, &data)) {
+        info.transform_count = groups.get("Transform").copied();
+        info.piece_count = groups.get("Geometry").copied();
+        info.vertex_count = groups.get("Vertices").copied();
+        info.face_count = groups.get("Faces").copied();
+    }
+    info
+}
+
+pub fn report(index: &AssetIndex) -> DestructionReport {
+    let mut report = DestructionReport::default();
+    for asset in &index.assets {
+        let Some(class) = asset.class.as_deref() else {
+            continue;
+        };
+        if class == "GeometryCollection" {
+            let file_path = Path::new(&asset.asset.file_path);
+            report.geometry_collections.push(inspect(file_path, &asset.asset.path, asset.size, asset.asset.is_large));
+        } else if CACHE_CLASSES.contains(&class) {
+            report.caches.push(ChaosCache {
+                path: asset.asset.path.clone(),
+                class: class.to_string(),
+                size: asset.size,
+            });
+        } else {
+            continue;
+        }
+        report.total_size += asset.size;
+    }
+    report.total_pieces = report.geometry_collections.iter().filter_map(|g| g.piece_count).sum();
+    report.geometry_collections.sort_by(|a, b| b.piece_count.cmp(&a.piece_count).then_with(|| b.size.cmp(&a.size)));
+    report.caches.sort_by(|a, b| b.size.cmp(&a.size));
+    report
+}
+
+#[tauri::command(async)]
+pub fn get_destruction_report(manager: State<ScanManager>, project: String) -> CodexResult<DestructionReport> {
+    let index = manager.index(&project)?;
+    Ok(report(&index))
+}
@@ -0,0 +1,127 @@
+// First-run project discovery: walk a few folders (or every drive) looking for `.uproject`
+// files, so the user can pick from a list instead of browsing for one.
+
+use crate::project::UProject;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use walkdir::WalkDir;
+
+const DEFAULT_MAX_DEPTH: usize = 5;
+
+// Folders that never contain a project worth listing and can be huge.
+const SKIPPED_DIRS: &[&str] = &[
+    "Binaries",
+    "DerivedDataCache",
+    "Intermediate",
+    "Saved",
+    "node_modules",
+    "$Recycle.Bin",
+    "System Volume Information",
+    "Windows",
+];
+
+// Bumped by `cancel_project_discovery`; a running search stops when it sees a new value.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectCandidate {
+    pub name: String,
+    pub project: String,
+    pub file_path: String,
+    pub engine_association: String,
+    /// `5.3`, resolved from the association where possible.
+    pub engine_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscoveryResult {
+    pub candidates: Vec<ProjectCandidate>,
+    pub searched_roots: Vec<String>,
+    /// The search was cancelled; `candidates` holds what was found until then.
+    pub cancelled: bool,
+}
+
+/// Every fixed drive on Windows, the home folder elsewhere.
+pub fn default_roots() -> Vec<PathBuf> {
+    if cfg!(windows) {
+        (b'C'..=b'Z').map(|d| PathBuf::from(format!("{}:\\", d as char))).filter(|p| p.is_dir()).collect()
+    } else {
+        std::env::var_os("HOME").map(PathBuf::from).into_iter().collect()
+    }
+}
+
+/// Describes the project whose descriptor is `uproject`, if it can be read.
+pub fn candidate(uproject: &Path) -> Option<ProjectCandidate> {
+    let dir = uproject.parent()?;
+    let loaded = UProject::load(dir).ok()?;
+    Some(ProjectCandidate {
+        name: uproject.file_stem()?.to_string_lossy().to_string(),
+        project: dir.to_string_lossy().to_string(),
+        file_path: loaded.file_path.clone(),
+        engine_version: loaded.engine_release().map(|r| r.to_string()),
+        engine_association: loaded.engine_association,
+    })
+}
+
+fn is_skipped(path: &Path) -> bool {
+    let Some(name) = path.file_name().map(|n| n.to_string_lossy()) else {
+        return false;
+    };
+    name.starts_with('.') || SKIPPED_DIRS.iter().any(|s| s.eq_ignore_ascii_case(&name))
+}
+
+pub fn discover(roots: &[PathBuf], max_depth: usize) -> DiscoveryResult {
+    let generation = GENERATION.load(Ordering::SeqCst);
+    let mut result = DiscoveryResult {
+        candidates: Vec::new(),
+        searched_roots: roots.iter().map(|r| r.to_string_lossy().to_string()).collect(),
+        cancelled: false,
+    };
+    for root in roots {
+        // Files first, so a project's `.uproject` is seen before its subfolders are entered.
+        let mut walk = WalkDir::new(root)
+            .max_depth(max_depth)
+            .sort_by(|a, b| b.file_type().is_file().cmp(&a.file_type().is_file()))
+            .into_iter();
+        while let Some(entry) = walk.next() {
+            if GENERATION.load(Ordering::SeqCst) != generation {
+                result.cancelled = true;
+                return result;
+            }
+            let Ok(entry) = entry else {
+                continue;
+            };
+            if entry.depth() > 0 && entry.file_type().is_dir() && is_skipped(entry.path()) {
+                walk.skip_current_dir();
+                continue;
+            }
+            let is_uproject = entry.file_type().is_file()
+                && entry.path().extension().is_some_and(|e| e.eq_ignore_ascii_case("uproject"));
+            if !is_uproject {
+                continue;
+            }
+            result.candidates.extend(candidate(entry.path()));
+            // Nothing below a project's own folder is another project worth listing.
+            walk.skip_current_dir();
+        }
+    }
+    result.candidates.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()).then(a.project.cmp(&b.project)));
+    result
+}
+
+/// Searches `roots` (every drive or the home folder when omitted) down to `max_depth`.
+#[tauri::command(async)]
+pub fn discover_projects(roots: Option<Vec<String>>, max_depth: Option<usize>) -> DiscoveryResult {
+    let roots = match roots {
+        Some(roots) if !roots.is_empty() => roots.into_iter().map(PathBuf::from).collect(),
+        _ => default_roots(),
+    };
+    discover(&roots, max_depth.unwrap_or(DEFAULT_MAX_DEPTH))
+}
+
+/// Stops any running `discover_projects` search.
+#[tauri::command]
+pub fn cancel_project_discovery() {
+    GENERATION.fetch_add(1, Ordering::SeqCst);
+}
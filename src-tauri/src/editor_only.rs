@@ -0,0 +1,120 @@
+// Editor-only content for shipping audits: editor utility Blueprints and widgets, scripted
+// actions and editor tutorials, which never cook, plus assets that only editor-only content
+// references and so only exist for the editor's sake. Runtime content referencing any of them
+// is reported separately, since a cook either fails on it or drags the asset in anyway.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::HashMap;
+use tauri::State;
+
+// Asset classes that only exist in the editor.
+const EDITOR_ONLY_CLASSES: &[&str] = &["EditorUtilityBlueprint", "EditorUtilityWidgetBlueprint", "EditorTutorial"];
+
+// Native bases of editor-only Blueprints, whatever their asset class.
+const EDITOR_ONLY_BASES: &[&str] = &[
+    "EditorUtilityObject",
+    "EditorUtilityActor",
+    "EditorUtilityWidget",
+    "AssetActionUtility",
+    "ActorActionUtility",
+    "EditorTutorial",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EditorOnlyAsset {
+    pub path: String,
+    pub class: String,
+    pub size: u64,
+    /// `class` for editor-only classes, `referenced` for assets only editor-only content uses.
+    pub reason: String,
+    /// For `referenced`: the editor-only assets that use it.
+    pub referencers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EditorOnlyReference {
+    /// Runtime asset holding the reference.
+    pub referencer: String,
+    pub asset: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EditorOnlyReport {
+    pub assets: Vec<EditorOnlyAsset>,
+    pub total_size: u64,
+    /// Runtime content referencing editor-only classes; these need fixing before a cook.
+    pub runtime_references: Vec<EditorOnlyReference>,
+}
+
+fn is_editor_only_class(index: &AssetIndex, parents: &HashMap<String, String>, asset: &IndexedAsset) -> bool {
+    if asset.class.as_deref().is_some_and(|c| EDITOR_ONLY_CLASSES.contains(&c)) {
+        return true;
+    }
+    if asset.tag("ParentClass").is_none() {
+        return false;
+    }
+    let Some(native) = crate::asset_manager::native_parent(index, asset) else {
+        return false;
+    };
+    EDITOR_ONLY_BASES.iter().any(|base| crate::native_classes::derives_from(parents, native, base))
+}
+
+pub fn find(index: &AssetIndex) -> EditorOnlyReport {
+    let parents = crate::native_classes::parent_map(&crate::native_classes::index(&index.project));
+    let referencers = index.referencers();
+    let by_class: Vec<bool> = index.assets.iter().map(|a| is_editor_only_class(index, &parents, a)).collect();
+
+    // Anything referenced only from editor-only content is editor-only too, transitively.
+    let mut editor_only = by_class.clone();
+    loop {
+        let mut changed = false;
+        for (i, refs) in referencers.iter().enumerate() {
+            if !editor_only[i] && !refs.is_empty() && refs.iter().all(|&r| editor_only[r]) {
+                editor_only[i] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let mut report = EditorOnlyReport::default();
+    for (i, asset) in index.assets.iter().enumerate() {
+        if by_class[i] {
+            for &r in referencers[i].iter().filter(|&&r| !editor_only[r]) {
+                report.runtime_references.push(EditorOnlyReference {
+                    referencer: index.assets[r].asset.path.clone(),
+                    asset: asset.asset.path.clone(),
+                });
+            }
+        }
+        if !editor_only[i] {
+            continue;
+        }
+        report.total_size += asset.size;
+        report.assets.push(EditorOnlyAsset {
+            path: asset.asset.path.clone(),
+            class: asset.class.clone().unwrap_or_else(|| asset.asset.asset_type.clone()),
+            size: asset.size,
+            reason: if by_class[i] { "class" } else { "referenced" }.to_string(),
+            referencers: if by_class[i] {
+                Vec::new()
+            } else {
+                referencers[i].iter().map(|&r| index.assets[r].asset.path.clone()).collect()
+            },
+        });
+    }
+    report.assets.sort_by(|a, b| a.reason.cmp(&b.reason).then_with(|| a.path.cmp(&b.path)));
+    report.runtime_references.sort_by(|a, b| a.referencer.cmp(&b.referencer).then_with(|| a.asset.cmp(&b.asset)));
+    report
+}
+
+#[tauri::command(async)]
+pub fn find_editor_only_assets(manager: State<ScanManager>, project: String) -> CodexResult<EditorOnlyReport> {
+    let index = manager.index(&project)?;
+    Ok(find(&index))
+}
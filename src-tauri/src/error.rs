@@ -0,0 +1,191 @@
+// Errors handed to the frontend. They serialize as `{ "code": ..., "params": {...},
+// "message": ... }`: the UI looks `code` up in its message catalog and fills in `params`, so
+// nothing it shows depends on the English text, which is only there for logs and for codes
+// the catalog doesn't know yet.
+
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug, Clone)]
+pub enum CodexError {
+    /// The folder has no `.uproject` / `.uefnproject` descriptor.
+    NotAProject { path: String },
+    /// The project has no Content folder (or mounted content root) to scan.
+    ContentMissing { path: String },
+    /// `kind` is `not_found`, `permission_denied`, `locked` or `other`.
+    Io { path: String, kind: String, message: String },
+    /// The file was read but isn't what it claims to be (bad package, bad JSON, ...).
+    ParseFailed { path: String, message: String },
+    /// The project's engine association doesn't resolve to an installed engine.
+    EngineNotFound { association: String },
+    /// Any other catalog message, e.g. `not_a_metasound` with a `class` param.
+    Message { code: &'static str, params: BTreeMap<&'static str, String> },
+    /// Errors from libraries (git, serde, ...) that only have English text.
+    Failed { message: String },
+}
+
+// English templates for every code; `{name}` is replaced by the param of that name. The
+// frontend catalog (src/services/messages.ts) mirrors these keys.
+const MESSAGES: &[(&str, &str)] = &[
+    ("not_a_project", "{path} is not an Unreal project"),
+    ("content_missing", "Content folder not found in {path}"),
+    ("io", "{path}: {message}"),
+    ("parse_failed", "{path}: {message}"),
+    ("engine_not_found", "Engine {association} is not installed"),
+    ("failed", "{message}"),
+    ("already_mounted", "{mount} is already mounted"),
+    ("asset_claimed", "{path} is claimed by {user}"),
+    ("asset_not_found", "{path} not found"),
+    ("blueprint_export_out_of_range", "Blueprint export out of range"),
+    ("claim_not_held", "{path} is not claimed by {user}"),
+    ("empty_mount_point", "Mount point can't be empty"),
+    ("empty_platform", "Platform can't be empty"),
+    ("empty_search", "Search text can't be empty"),
+    ("http_bad_response", "Unexpected response from {url}: {message}"),
+    ("http_failed", "Request to {url} failed: {message}"),
+    ("http_status", "{url} returned HTTP {status}"),
+    ("http_unavailable", "curl is not available on this machine"),
+    ("invalid_asset_path", "Invalid asset path"),
+    ("invalid_import_data", "{path} has invalid import data: {message}"),
+    ("invalid_issue_key", "'{key}' is not an issue key"),
+    ("invalid_owners_rule", ".codexowners line {line}: {message}"),
+    ("invalid_report_time", "Invalid report time '{time}', expected HH:MM"),
+    ("invalid_review_transition", "Can't move {path} from {from} to {to}"),
+    ("invalid_url", "'{url}' is not an http(s) URL"),
+    ("level_not_found", "Level {level} is not in the project"),
+    ("lfs_not_fetched", "{path} at {rev} is an LFS object that has not been fetched"),
+    ("missing_at_revision", "{path} does not exist at {rev}"),
+    ("no_curves", "No curves in package ({class})"),
+    ("no_issue_tracker", "No issue tracker is configured"),
+    ("no_level", "No level in package"),
+    ("no_main_export", "Package has no main export"),
+    ("no_notifier", "No webhook is configured"),
+    ("no_report_schedule", "No report schedule is configured"),
+    ("no_source_art_folder", "No source art folder found"),
+    ("no_team_sync", "No team sync location is configured"),
+    ("no_work_tree", "Repository has no work tree"),
+    ("not_a_behavior_tree", "Not a Behavior Tree or Blackboard ({class})"),
+    ("not_a_folder", "{path} is not a folder"),
+    ("not_a_level_sequence", "Not a Level Sequence ({class})"),
+    ("not_a_metasound", "Not a MetaSound ({class})"),
+    ("not_a_niagara_asset", "Not a Niagara system or emitter ({class})"),
+    ("not_an_interface", "{path} is not a Blueprint interface"),
+    ("not_scan_output", "{path} is not a scan output file"),
+    ("outside_repository", "{path} is not inside the repository"),
+    ("project_not_watched", "{project} is not being watched"),
+    ("reviewer_required", "Reviewer is required"),
+    ("source_art_folder_missing", "Source art folder not found: {path}"),
+    ("team_sync_read_only", "Team sync is read-only here, so claims can't reach the team"),
+    ("unknown_output_format", "Unknown scan output format '{format}'"),
+    ("unknown_review_state", "Unknown review state '{state}'"),
+    ("unsupported_vcs", "Unsupported version control system: {vcs}"),
+    ("user_required", "User name is required"),
+];
+
+pub type CodexResult<T> = Result<T, CodexError>;
+
+impl CodexError {
+    pub fn io(path: &Path, error: &std::io::Error) -> CodexError {
+        let kind = if crate::file_lock::is_lock_error(error) {
+            "locked"
+        } else {
+            match error.kind() {
+                std::io::ErrorKind::NotFound => "not_found",
+                std::io::ErrorKind::PermissionDenied => "permission_denied",
+                _ => "other",
+            }
+        };
+        CodexError::Io { path: path.to_string_lossy().to_string(), kind: kind.to_string(), message: error.to_string() }
+    }
+
+    /// No content root: `ContentMissing` for a real project, `NotAProject` for a random folder.
+    pub fn missing_content(project: &Path) -> CodexError {
+        let path = project.to_string_lossy().to_string();
+        let is_project =
+            crate::project::find_uproject(project).is_some() || crate::uefn::find_uefnproject(project).is_some();
+        if is_project {
+            CodexError::ContentMissing { path }
+        } else {
+            CodexError::NotAProject { path }
+        }
+    }
+
+    pub fn parse(path: &Path, message: impl fmt::Display) -> CodexError {
+        CodexError::ParseFailed { path: path.to_string_lossy().to_string(), message: message.to_string() }
+    }
+
+    /// A catalog message by code, e.g. `CodexError::message("not_a_folder", &[("path", &path.display())])`.
+    pub fn message(code: &'static str, params: &[(&'static str, &dyn fmt::Display)]) -> CodexError {
+        CodexError::Message { code, params: params.iter().map(|(k, v)| (*k, v.to_string())).collect() }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            CodexError::NotAProject { .. } => "not_a_project",
+            CodexError::ContentMissing { .. } => "content_missing",
+            CodexError::Io { .. } => "io",
+            CodexError::ParseFailed { .. } => "parse_failed",
+            CodexError::EngineNotFound { .. } => "engine_not_found",
+            CodexError::Message { code, .. } => code,
+            CodexError::Failed { .. } => "failed",
+        }
+    }
+
+    pub fn params(&self) -> BTreeMap<&'static str, String> {
+        let owned = |pairs: &[(&'static str, &String)]| pairs.iter().map(|(k, v)| (*k, v.to_string())).collect();
+        match self {
+            CodexError::NotAProject { path } | CodexError::ContentMissing { path } => owned(&[("path", path)]),
+            CodexError::Io { path, kind, message } => owned(&[("path", path), ("kind", kind), ("message", message)]),
+            CodexError::ParseFailed { path, message } => owned(&[("path", path), ("message", message)]),
+            CodexError::EngineNotFound { association } => owned(&[("association", association)]),
+            CodexError::Message { params, .. } => params.clone(),
+            CodexError::Failed { message } => owned(&[("message", message)]),
+        }
+    }
+}
+
+impl Serialize for CodexError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("CodexError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("params", &self.params())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl fmt::Display for CodexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = self.code();
+        let template = MESSAGES.iter().find(|(c, _)| *c == code).map(|(_, t)| *t).unwrap_or(code);
+        let mut text = template.to_string();
+        for (name, value) in self.params() {
+            text = text.replace(&format!("{{{}}}", name), &value);
+        }
+        f.write_str(&text)
+    }
+}
+
+impl std::error::Error for CodexError {}
+
+impl From<String> for CodexError {
+    fn from(message: String) -> CodexError {
+        CodexError::Failed { message }
+    }
+}
+
+impl From<&str> for CodexError {
+    fn from(message: &str) -> CodexError {
+        CodexError::Failed { message: message.to_string() }
+    }
+}
+
+// Lets the parsers' own `Result<_, String>` helpers keep using `?` on calls that return this.
+impl From<CodexError> for String {
+    fn from(error: CodexError) -> String {
+        error.to_string()
+    }
+}
@@ -0,0 +1,73 @@
+// Dropping a `.uproject` (or `.uefnproject`), or the folder holding one, anywhere on the
+// window opens that project: validated and scanned here, then announced with
+// `project-loaded` so the frontend doesn't have to rescan what we already have.
+
+use crate::error::CodexResult;
+use crate::manager::ScanManager;
+use crate::scan::{ScanOptions, UnrealAsset};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::{FileDropEvent, GlobalWindowEvent, Manager, WindowEvent};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectLoaded {
+    pub project: String,
+    pub name: String,
+    pub assets: Vec<UnrealAsset>,
+}
+
+fn is_descriptor(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e.eq_ignore_ascii_case("uproject") || e.eq_ignore_ascii_case("uefnproject"))
+}
+
+/// The project folder a dropped path stands for.
+pub fn project_root(dropped: &Path) -> CodexResult<PathBuf> {
+    let root = if dropped.is_file() && is_descriptor(dropped) {
+        dropped.parent().map(Path::to_path_buf).unwrap_or_default()
+    } else {
+        dropped.to_path_buf()
+    };
+    if crate::uefn::find_uefnproject(&root).is_some() {
+        return Ok(root);
+    }
+    // Loading the descriptor catches a corrupt .uproject before we scan anything.
+    crate::project::UProject::load(&root)?;
+    Ok(root)
+}
+
+fn load(manager: &ScanManager, window: &tauri::Window, dropped: &Path) -> CodexResult<ProjectLoaded> {
+    let root = project_root(dropped)?;
+    let project = root.to_string_lossy().to_string();
+    let _ = window.emit("project-drop-started", &project);
+    let assets = manager.scan(window, &project, ScanOptions::default())?;
+    let name = crate::project::find_uproject(&root)
+        .or_else(|| crate::uefn::find_uefnproject(&root))
+        .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_default();
+    Ok(ProjectLoaded { project, name, assets })
+}
+
+/// Window event hook; only drops are handled, and only the first dropped path that looks
+/// like a project is used.
+pub fn handle(event: GlobalWindowEvent) {
+    let WindowEvent::FileDrop(FileDropEvent::Dropped(paths)) = event.event() else {
+        return;
+    };
+    let dropped = paths.iter().find(|p| is_descriptor(p)).or(paths.first()).cloned();
+    let Some(dropped) = dropped else {
+        return;
+    };
+    let window = event.window().clone();
+    // Scanning takes a while; keep it off the event loop.
+    std::thread::spawn(move || {
+        let manager = window.state::<ScanManager>();
+        match load(&manager, &window, &dropped) {
+            Ok(loaded) => {
+                let _ = window.emit("project-loaded", loaded);
+            }
+            Err(error) => {
+                let _ = window.emit("project-drop-failed", error);
+            }
+        }
+    });
+}
@@ -0,0 +1,35 @@
+// Files the editor is saving (or holds open exclusively) can't be opened on Windows until
+// it lets go. Reads go through here so a brief lock costs a short wait rather than an error.
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+// Waits between attempts; roughly half a second in total before giving up.
+const RETRY_DELAYS_MS: [u64; 4] = [25, 50, 100, 250];
+
+#[cfg(windows)]
+pub fn is_lock_error(error: &io::Error) -> bool {
+    const ERROR_SHARING_VIOLATION: i32 = 32;
+    const ERROR_LOCK_VIOLATION: i32 = 33;
+    matches!(error.raw_os_error(), Some(ERROR_SHARING_VIOLATION | ERROR_LOCK_VIOLATION))
+}
+
+#[cfg(not(windows))]
+pub fn is_lock_error(_error: &io::Error) -> bool {
+    false
+}
+
+pub fn open(path: &Path) -> io::Result<File> {
+    let mut delays = RETRY_DELAYS_MS.iter();
+    loop {
+        match File::open(path) {
+            Err(e) if is_lock_error(&e) => match delays.next() {
+                Some(ms) => std::thread::sleep(Duration::from_millis(*ms)),
+                None => return Err(e),
+            },
+            result => return result,
+        }
+    }
+}
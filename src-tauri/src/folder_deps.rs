@@ -0,0 +1,75 @@
+// Cross-folder references: dependencies rolled up to Content folders at a given depth, to
+// show which feature folders are entangled with which.
+
+use crate::error::CodexResult;
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderDependencyMatrix {
+    pub depth: usize,
+    pub folders: Vec<String>,
+    /// `matrix[from][to]`: references from assets in `folders[from]` to assets in `folders[to]`.
+    pub matrix: Vec<Vec<usize>>,
+    /// Folder pairs that reference each other in both directions.
+    pub entangled: Vec<(String, String)>,
+}
+
+/// `/Game/Characters/Hero/BP_Hero` at depth 1 is `/Game/Characters`. Assets shallower
+/// than `depth` roll up to their own folder.
+pub fn folder_at_depth(path: &str, depth: usize) -> String {
+    let mut segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    segments.pop();
+    // The mount point (`Game`, a plugin name) is always kept.
+    segments.truncate(depth + 1);
+    format!("/{}", segments.join("/"))
+}
+
+pub fn matrix(index: &AssetIndex, depth: usize) -> FolderDependencyMatrix {
+    let mut counts: BTreeMap<(String, String), usize> = BTreeMap::new();
+    let mut folders: BTreeMap<String, usize> = BTreeMap::new();
+    for asset in &index.assets {
+        let from = folder_at_depth(&asset.asset.path, depth);
+        folders.entry(from.clone()).or_default();
+        for dep in &asset.dependencies {
+            let Some(target) = index.get(dep) else {
+                continue;
+            };
+            let to = folder_at_depth(&target.asset.path, depth);
+            *counts.entry((from.clone(), to)).or_default() += 1;
+        }
+    }
+    for (i, (_, slot)) in folders.iter_mut().enumerate() {
+        *slot = i;
+    }
+
+    let mut matrix = vec![vec![0; folders.len()]; folders.len()];
+    for ((from, to), count) in &counts {
+        matrix[folders[from]][folders[to]] = *count;
+    }
+    let entangled = counts
+        .keys()
+        .filter(|(from, to)| from < to && counts.contains_key(&(to.clone(), from.clone())))
+        .cloned()
+        .collect();
+
+    FolderDependencyMatrix {
+        depth,
+        folders: folders.into_keys().collect(),
+        matrix,
+        entangled,
+    }
+}
+
+#[tauri::command]
+pub fn get_folder_dependency_matrix(
+    manager: State<ScanManager>,
+    project: String,
+    depth: Option<usize>,
+) -> CodexResult<FolderDependencyMatrix> {
+    let index = manager.index(&project)?;
+    Ok(matrix(&index, depth.unwrap_or(1).max(1)))
+}
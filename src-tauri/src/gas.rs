@@ -0,0 +1,214 @@
+// Gameplay Ability System overview: ability and effect Blueprints (recognised by their native
+// parent, through project subclasses of GameplayAbility / GameplayEffect), the project's
+// AttributeSet classes with their attributes, and which assets grant each ability. Ability
+// and effect settings come from the Blueprint's class default object, which only stores
+// values changed from the parent, so unset fields mean "inherited".
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GameplayAbilityInfo {
+    pub path: String,
+    pub native_class: Option<String>,
+    pub ability_tags: Vec<String>,
+    pub cooldown_effect: Option<String>,
+    pub cost_effect: Option<String>,
+    /// Assets that reference the ability (characters, ability sets), other abilities aside.
+    pub granted_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GameplayEffectInfo {
+    pub path: String,
+    pub native_class: Option<String>,
+    /// `Instant`, `HasDuration` or `Infinite`.
+    pub duration_policy: Option<String>,
+    pub modifier_count: usize,
+    pub modified_attributes: Vec<String>,
+    pub granted_tags: Vec<String>,
+    /// Abilities using it as their cooldown.
+    pub cooldown_for: Vec<String>,
+    /// Abilities using it as their cost.
+    pub cost_for: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AttributeSetInfo {
+    pub name: String,
+    pub module: String,
+    pub header: String,
+    /// `FGameplayAttributeData` members declared in the header.
+    pub attributes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GasOverview {
+    pub abilities: Vec<GameplayAbilityInfo>,
+    pub effects: Vec<GameplayEffectInfo>,
+    pub attribute_sets: Vec<AttributeSetInfo>,
+}
+
+/// Properties of a Blueprint's class default object.
+fn class_defaults(asset: &IndexedAsset) -> Vec<Property> {
+    let file_path = Path::new(&asset.asset.file_path);
+    let Ok(package) = Package::read_file(file_path) else {
+        return Vec::new();
+    };
+    let cdo = format!("Default__{}_C", asset.asset.name);
+    package
+        .exports
+        .iter()
+        .find(|e| e.outer_index == 0 && e.object_name == cdo)
+        .and_then(|e| properties::read_export_properties(file_path, &package, e).ok())
+        .unwrap_or_default()
+}
+
+/// Every name inside a tag container (or any nested value), e.g. `Ability.Skill.Dash`.
+fn collect_names(value: &PropertyValue, names: &mut BTreeSet<String>) {
+    match value {
+        PropertyValue::Name(name) if !name.is_empty() && name != "None" => {
+            names.insert(name.clone());
+        }
+        PropertyValue::Struct { properties, .. } => properties.iter().for_each(|p| collect_names(&p.value, names)),
+        PropertyValue::Array(items) => items.iter().for_each(|v| collect_names(v, names)),
+        _ => {}
+    }
+}
+
+fn tags(props: &[Property], names: &[&str]) -> Vec<String> {
+    let mut tags = BTreeSet::new();
+    for value in names.iter().filter_map(|name| properties::find(props, name)) {
+        collect_names(value, &mut tags);
+    }
+    tags.into_iter().collect()
+}
+
+fn class_package(props: &[Property], name: &str) -> Option<String> {
+    properties::find(props, name)?.as_str().map(|p| crate::package::package_of_object_path(p).to_string())
+}
+
+fn enum_value(value: Option<&PropertyValue>) -> Option<String> {
+    let value = value?.as_str()?;
+    Some(value.rsplit("::").next().unwrap_or(value).to_string())
+}
+
+fn effect(asset: &IndexedAsset, native_class: Option<String>) -> GameplayEffectInfo {
+    let props = class_defaults(asset);
+    let modifiers = match properties::find(&props, "Modifiers") {
+        Some(PropertyValue::Array(modifiers)) => modifiers.as_slice(),
+        _ => &[],
+    };
+    let modified_attributes: BTreeSet<String> = modifiers
+        .iter()
+        .filter_map(|m| m.field("Attribute")?.field("AttributeName")?.as_str())
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    GameplayEffectInfo {
+        path: asset.asset.path.clone(),
+        native_class,
+        duration_policy: enum_value(properties::find(&props, "DurationPolicy")),
+        modifier_count: modifiers.len(),
+        modified_attributes: modified_attributes.into_iter().collect(),
+        granted_tags: tags(&props, &["InheritableOwnedTagsContainer", "InheritableGameplayEffectTags"]),
+        cooldown_for: Vec::new(),
+        cost_for: Vec::new(),
+    }
+}
+
+/// `FGameplayAttributeData Health;` members. A header with several attribute sets gives each
+/// of them all of its attributes.
+fn attribute_names(header: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = header;
+    while let Some(at) = rest.find("FGameplayAttributeData") {
+        rest = &rest[at + "FGameplayAttributeData".len()..];
+        let declaration = rest.split([';', '=', '{', '(', ')', ',']).next().unwrap_or_default().trim();
+        let is_identifier =
+            !declaration.is_empty() && declaration.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if is_identifier && !names.iter().any(|n| n == declaration) {
+            names.push(declaration.to_string());
+        }
+    }
+    names
+}
+
+pub fn overview(index: &AssetIndex) -> GasOverview {
+    let native_classes = crate::native_classes::index(&index.project);
+    let parents = crate::native_classes::parent_map(&native_classes);
+    let referencers = index.referencers();
+    let mut overview = GasOverview::default();
+    let mut abilities = BTreeSet::new();
+
+    let mut ability_assets = Vec::new();
+    for (i, asset) in index.assets.iter().enumerate() {
+        if asset.tag("ParentClass").is_none() {
+            continue;
+        }
+        let native = crate::asset_manager::native_parent(index, asset);
+        let derives = |base: &str| native.is_some_and(|n| crate::native_classes::derives_from(&parents, n, base));
+        if derives("GameplayAbility") {
+            abilities.insert(asset.asset.path.clone());
+            ability_assets.push((i, asset, native.map(str::to_string)));
+        } else if derives("GameplayEffect") {
+            overview.effects.push(effect(asset, native.map(str::to_string)));
+        }
+    }
+
+    for (i, asset, native_class) in ability_assets {
+        let props = class_defaults(asset);
+        let granted_by = referencers[i]
+            .iter()
+            .map(|&r| index.assets[r].asset.path.clone())
+            .filter(|path| !abilities.contains(path))
+            .collect();
+        overview.abilities.push(GameplayAbilityInfo {
+            path: asset.asset.path.clone(),
+            native_class,
+            ability_tags: tags(&props, &["AbilityTags"]),
+            cooldown_effect: class_package(&props, "CooldownGameplayEffectClass"),
+            cost_effect: class_package(&props, "CostGameplayEffectClass"),
+            granted_by,
+        });
+    }
+
+    let effect_by_path: HashMap<String, usize> =
+        overview.effects.iter().enumerate().map(|(i, e)| (e.path.to_lowercase(), i)).collect();
+    for ability in &overview.abilities {
+        let uses = [(&ability.cooldown_effect, true), (&ability.cost_effect, false)];
+        for (effect, cooldown) in uses {
+            let Some(&e) = effect.as_ref().and_then(|p| effect_by_path.get(&p.to_lowercase())) else {
+                continue;
+            };
+            let list = if cooldown { &mut overview.effects[e].cooldown_for } else { &mut overview.effects[e].cost_for };
+            list.push(ability.path.clone());
+        }
+    }
+
+    for class in
+        native_classes.iter().filter(|c| crate::native_classes::derives_from(&parents, &c.name, "AttributeSet"))
+    {
+        let text = std::fs::read_to_string(index.project.join(&class.header)).unwrap_or_default();
+        overview.attribute_sets.push(AttributeSetInfo {
+            name: class.name.clone(),
+            module: class.module.clone(),
+            header: class.header.clone(),
+            attributes: attribute_names(&text),
+        });
+    }
+    overview
+}
+
+#[tauri::command(async)]
+pub fn get_gas_overview(manager: State<ScanManager>, project: String) -> CodexResult<GasOverview> {
+    let index = manager.index(&project)?;
+    Ok(overview(&index))
+}
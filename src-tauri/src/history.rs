@@ -0,0 +1,353 @@
+// Git history for content: which commits touched an asset, how its size moved, and
+// who has been changing what across the Content folder.
+
+use crate::error::{CodexError, CodexResult};
+use crate::vcs;
+use git2::{DiffOptions, Oid, Repository, Sort, Tree};
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetCommit {
+    pub id: String,
+    pub short_id: String,
+    pub author: String,
+    pub email: String,
+    /// Seconds since the Unix epoch.
+    pub time: i64,
+    pub message: String,
+    pub change: ChangeKind,
+    /// Size of the asset after this commit; LFS pointers report the real object size.
+    pub size: Option<u64>,
+    pub size_delta: i64,
+}
+
+pub struct AssetRepo {
+    pub repo: Repository,
+    /// Path of the asset relative to the work tree, with forward slashes.
+    pub relative_path: String,
+}
+
+impl AssetRepo {
+    pub fn open(file_path: &Path) -> CodexResult<AssetRepo> {
+        // Canonicalise the folder rather than the file so deleted assets still resolve.
+        let dir = file_path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let dir = std::fs::canonicalize(dir).map_err(|e| CodexError::io(dir, &e))?;
+        let absolute = dir.join(file_path.file_name().ok_or_else(|| CodexError::message("invalid_asset_path", &[]))?);
+        let repo = Repository::discover(&dir).map_err(|e| e.message().to_string())?;
+        let workdir = repo.workdir().ok_or_else(|| CodexError::message("no_work_tree", &[]))?;
+        let workdir = std::fs::canonicalize(workdir).unwrap_or_else(|_| workdir.to_path_buf());
+        let relative_path = relative_to(&absolute, &workdir)?;
+        Ok(AssetRepo { repo, relative_path })
+    }
+
+    /// Blob id of the asset in a tree, if it exists there.
+    pub fn blob_in(&self, tree: &Tree) -> Option<Oid> {
+        tree.get_path(Path::new(&self.relative_path)).ok().map(|e| e.id())
+    }
+
+    /// Logical size of a blob, looking through LFS pointers.
+    pub fn blob_size(&self, id: Oid) -> Option<u64> {
+        let blob = self.repo.find_blob(id).ok()?;
+        match vcs::parse_lfs_pointer(&self.relative_path, blob.content()) {
+            Some(pointer) => pointer.object_size,
+            None => Some(blob.size() as u64),
+        }
+    }
+
+    /// Contents of the asset at `rev`; an empty rev or `WORKTREE` reads the file on disk.
+    /// LFS pointers are swapped for the object from the local LFS store when it is there.
+    pub fn read_revision(&self, rev: &str) -> CodexResult<Vec<u8>> {
+        let bytes = if rev.is_empty() || rev.eq_ignore_ascii_case("WORKTREE") {
+            let workdir = self.repo.workdir().ok_or_else(|| CodexError::message("no_work_tree", &[]))?;
+            let path = workdir.join(&self.relative_path);
+            std::fs::read(&path).map_err(|e| CodexError::io(&path, &e))?
+        } else {
+            let commit = self
+                .repo
+                .revparse_single(rev)
+                .and_then(|o| o.peel_to_commit())
+                .map_err(|e| format!("{}: {}", rev, e.message()))?;
+            let tree = commit.tree().map_err(|e| e.message().to_string())?;
+            let id = self
+                .blob_in(&tree)
+                .ok_or_else(|| {
+                    CodexError::message("missing_at_revision", &[("path", &self.relative_path), ("rev", &rev)])
+                })?;
+            let blob = self.repo.find_blob(id).map_err(|e| e.message().to_string())?;
+            blob.content().to_vec()
+        };
+        let Some(pointer) = vcs::parse_lfs_pointer(&self.relative_path, &bytes) else {
+            return Ok(bytes);
+        };
+        let oid = pointer.oid.as_deref().and_then(|o| o.strip_prefix("sha256:")).unwrap_or_default();
+        if oid.len() < 4 {
+            return Err(CodexError::parse(Path::new(&self.relative_path), "invalid LFS pointer"));
+        }
+        let object = self.repo.path().join("lfs").join("objects").join(&oid[..2]).join(&oid[2..4]).join(oid);
+        std::fs::read(&object)
+            .map_err(|_| CodexError::message("lfs_not_fetched", &[("path", &self.relative_path), ("rev", &rev)]))
+    }
+}
+
+fn relative_to(path: &Path, root: &Path) -> CodexResult<String> {
+    path.strip_prefix(root)
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .map_err(|_| CodexError::message("outside_repository", &[("path", &path.display())]))
+}
+
+pub fn asset_history(file_path: &Path, limit: Option<usize>) -> CodexResult<Vec<AssetCommit>> {
+    let repo = AssetRepo::open(file_path)?;
+    let mut walk = repo.repo.revwalk().map_err(|e| e.message().to_string())?;
+    walk.push_head().map_err(|e| e.message().to_string())?;
+    // Side-branch commits would report a change again when their merge lands it on the first parent.
+    walk.simplify_first_parent().map_err(|e| e.message().to_string())?;
+    walk.set_sorting(Sort::TIME | Sort::TOPOLOGICAL).map_err(|e| e.message().to_string())?;
+
+    let mut history = Vec::new();
+    for id in walk.filter_map(|id| id.ok()) {
+        if limit.is_some_and(|l| history.len() >= l) {
+            break;
+        }
+        let Ok(commit) = repo.repo.find_commit(id) else {
+            continue;
+        };
+        let Ok(tree) = commit.tree() else {
+            continue;
+        };
+        let current = repo.blob_in(&tree);
+        // Merges only count when the asset differs from the first parent, like `git log --first-parent`.
+        let previous = commit.parent(0).ok().and_then(|p| p.tree().ok()).and_then(|t| repo.blob_in(&t));
+        let change = match (previous, current) {
+            (None, Some(_)) => ChangeKind::Added,
+            (Some(_), None) => ChangeKind::Deleted,
+            (Some(a), Some(b)) if a != b => ChangeKind::Modified,
+            _ => continue,
+        };
+        let size = current.and_then(|b| repo.blob_size(b));
+        let previous_size = previous.and_then(|b| repo.blob_size(b));
+        let author = commit.author();
+        history.push(AssetCommit {
+            short_id: id.to_string()[..8].to_string(),
+            id: id.to_string(),
+            author: author.name().unwrap_or_default().to_string(),
+            email: author.email().unwrap_or_default().to_string(),
+            time: commit.time().seconds(),
+            message: String::from_utf8_lossy(commit.summary_bytes().unwrap_or_default()).to_string(),
+            change,
+            size,
+            size_delta: size.unwrap_or(0) as i64 - previous_size.unwrap_or(0) as i64,
+        });
+    }
+    Ok(history)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorStats {
+    pub author: String,
+    pub email: String,
+    pub commits: usize,
+    pub assets_touched: usize,
+    pub changes: usize,
+    pub first_commit: i64,
+    pub last_commit: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChurnedAsset {
+    pub path: String,
+    pub changes: usize,
+    pub authors: Vec<String>,
+    pub last_changed: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthorShare {
+    pub author: String,
+    pub changes: usize,
+    pub share: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderOwnership {
+    pub folder: String,
+    pub total_changes: usize,
+    /// Sorted by number of changes, so the first entry is the folder's de facto owner.
+    pub authors: Vec<AuthorShare>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContributorStats {
+    pub authors: Vec<AuthorStats>,
+    pub most_churned: Vec<ChurnedAsset>,
+    pub folders: Vec<FolderOwnership>,
+}
+
+const MOST_CHURNED_LIMIT: usize = 50;
+
+#[derive(Default)]
+struct AuthorAccumulator {
+    name: String,
+    commits: usize,
+    assets: HashSet<String>,
+    changes: usize,
+    first_commit: i64,
+    last_commit: i64,
+}
+
+/// Content package paths (`/Game/...`) changed by commits since `since`, grouped by author.
+pub fn contributor_stats(project: &Path, since: Option<i64>) -> CodexResult<ContributorStats> {
+    let content = std::fs::canonicalize(project.join("Content")).map_err(|_| CodexError::missing_content(project))?;
+    let repo = Repository::discover(&content).map_err(|e| e.message().to_string())?;
+    let workdir = repo.workdir().ok_or_else(|| CodexError::message("no_work_tree", &[]))?;
+    let workdir = std::fs::canonicalize(workdir).unwrap_or_else(|_| workdir.to_path_buf());
+    let content_prefix = relative_to(&content, &workdir)?;
+
+    let mut walk = repo.revwalk().map_err(|e| e.message().to_string())?;
+    walk.push_head().map_err(|e| e.message().to_string())?;
+    walk.set_sorting(Sort::TIME).map_err(|e| e.message().to_string())?;
+
+    let mut authors: HashMap<String, AuthorAccumulator> = HashMap::new();
+    let mut assets: HashMap<String, (usize, BTreeSet<String>, i64)> = HashMap::new();
+    let mut folders: HashMap<String, HashMap<String, usize>> = HashMap::new();
+
+    for id in walk.filter_map(|id| id.ok()) {
+        let Ok(commit) = repo.find_commit(id) else {
+            continue;
+        };
+        let time = commit.time().seconds();
+        if since.is_some_and(|s| time < s) {
+            break;
+        }
+        // Merge commits repeat changes already counted on their branches.
+        if commit.parent_count() > 1 {
+            continue;
+        }
+        let Ok(tree) = commit.tree() else {
+            continue;
+        };
+        let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+        let mut options = DiffOptions::new();
+        options.pathspec(&content_prefix);
+        let Ok(diff) = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut options)) else {
+            continue;
+        };
+        let changed: Vec<String> = diff
+            .deltas()
+            .filter_map(|d| d.new_file().path().or_else(|| d.old_file().path()).map(Path::to_path_buf))
+            .filter_map(|p| package_path(&p.to_string_lossy(), &content_prefix))
+            .collect();
+        if changed.is_empty() {
+            continue;
+        }
+
+        let signature = commit.author();
+        let email = signature.email().unwrap_or_default().to_lowercase();
+        let name = signature.name().unwrap_or_default().to_string();
+        let author = authors.entry(email).or_insert_with(|| AuthorAccumulator {
+            // Walking newest first, so the first name seen is the current one.
+            name: name.clone(),
+            first_commit: time,
+            last_commit: time,
+            ..Default::default()
+        });
+        author.commits += 1;
+        author.changes += changed.len();
+        author.first_commit = author.first_commit.min(time);
+        author.last_commit = author.last_commit.max(time);
+
+        for path in changed {
+            author.assets.insert(path.clone());
+            let folder = path.rsplit_once('/').map(|(d, _)| d.to_string()).unwrap_or_default();
+            *folders.entry(folder).or_default().entry(author.name.clone()).or_default() += 1;
+            let entry = assets.entry(path).or_insert((0, BTreeSet::new(), time));
+            entry.0 += 1;
+            entry.1.insert(author.name.clone());
+            entry.2 = entry.2.max(time);
+        }
+    }
+
+    let mut author_stats: Vec<AuthorStats> = authors
+        .into_iter()
+        .map(|(email, a)| AuthorStats {
+            author: a.name,
+            email,
+            commits: a.commits,
+            assets_touched: a.assets.len(),
+            changes: a.changes,
+            first_commit: a.first_commit,
+            last_commit: a.last_commit,
+        })
+        .collect();
+    author_stats.sort_by(|a, b| b.changes.cmp(&a.changes).then_with(|| a.author.cmp(&b.author)));
+
+    let mut most_churned: Vec<ChurnedAsset> = assets
+        .into_iter()
+        .map(|(path, (changes, authors, last_changed))| ChurnedAsset {
+            path,
+            changes,
+            authors: authors.into_iter().collect(),
+            last_changed,
+        })
+        .collect();
+    most_churned.sort_by(|a, b| b.changes.cmp(&a.changes).then_with(|| a.path.cmp(&b.path)));
+    most_churned.truncate(MOST_CHURNED_LIMIT);
+
+    let mut folder_stats: Vec<FolderOwnership> = folders
+        .into_iter()
+        .map(|(folder, per_author)| {
+            let total_changes: usize = per_author.values().sum();
+            let mut authors: Vec<AuthorShare> = per_author
+                .into_iter()
+                .map(|(author, changes)| AuthorShare {
+                    author,
+                    changes,
+                    share: changes as f64 / total_changes as f64,
+                })
+                .collect();
+            authors.sort_by(|a, b| b.changes.cmp(&a.changes).then_with(|| a.author.cmp(&b.author)));
+            FolderOwnership {
+                folder,
+                total_changes,
+                authors,
+            }
+        })
+        .collect();
+    folder_stats.sort_by(|a, b| a.folder.cmp(&b.folder));
+
+    Ok(ContributorStats {
+        authors: author_stats,
+        most_churned,
+        folders: folder_stats,
+    })
+}
+
+// `Content/Props/SM_Rock.uasset` -> `/Game/Props/SM_Rock`, skipping anything that isn't a package.
+fn package_path(repo_path: &str, content_prefix: &str) -> Option<String> {
+    let relative = repo_path.replace('\\', "/");
+    let inside = relative.strip_prefix(content_prefix)?.trim_start_matches('/');
+    let (stem, ext) = inside.rsplit_once('.')?;
+    if !ext.eq_ignore_ascii_case("uasset") && !ext.eq_ignore_ascii_case("umap") {
+        return None;
+    }
+    Some(format!("/Game/{}", stem))
+}
+
+#[tauri::command]
+pub fn get_asset_history(file_path: String, limit: Option<usize>) -> CodexResult<Vec<AssetCommit>> {
+    asset_history(&PathBuf::from(file_path), limit)
+}
+
+#[tauri::command]
+pub fn get_contributor_stats(project: String, since: Option<i64>) -> CodexResult<ContributorStats> {
+    contributor_stats(Path::new(&project), since)
+}
@@ -0,0 +1,140 @@
+// Outbound HTTP for the integrations (issue trackers, webhooks, team sync). Requests go through the
+// system `curl`, which ships with Windows 10+, macOS and every Linux desktop, so TLS and
+// proxies come from the OS setup instead of a bundled stack.
+
+use crate::error::{CodexError, CodexResult};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const TIMEOUT_SECS: &str = "20";
+
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: u16,
+    pub body: Vec<u8>,
+    /// Seconds the request took as curl measured it, without the cost of starting curl.
+    pub time_total: f64,
+}
+
+impl Response {
+    pub fn json(&self, url: &str) -> CodexResult<serde_json::Value> {
+        serde_json::from_slice(&self.body)
+            .map_err(|e| CodexError::message("http_bad_response", &[("url", &url), ("message", &e)]))
+    }
+
+    /// Fails on anything but 2xx.
+    pub fn ok(self, url: &str) -> CodexResult<Response> {
+        if (200..300).contains(&self.status) {
+            Ok(self)
+        } else {
+            Err(CodexError::message("http_status", &[("url", &url), ("status", &self.status)]))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Request<'a> {
+    pub method: &'a str,
+    pub url: &'a str,
+    pub headers: Vec<(&'a str, String)>,
+    /// `user:password` for basic auth.
+    pub basic_auth: Option<String>,
+    /// `aws:amz:<region>:s3`; signs the request with `basic_auth` as `key:secret`.
+    pub aws_sigv4: Option<String>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl<'a> Request<'a> {
+    pub fn get(url: &'a str) -> Request<'a> {
+        Request { method: "GET", url, ..Default::default() }
+    }
+
+    pub fn post_json(url: &'a str, body: &serde_json::Value) -> Request<'a> {
+        Request {
+            method: "POST",
+            url,
+            headers: vec![("Content-Type", "application/json".to_string())],
+            body: Some(body.to_string().into_bytes()),
+            ..Default::default()
+        }
+    }
+
+    pub fn put(url: &'a str, body: Vec<u8>) -> Request<'a> {
+        Request { method: "PUT", url, body: Some(body), ..Default::default() }
+    }
+
+    pub fn header(mut self, name: &'a str, value: impl Into<String>) -> Request<'a> {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    pub fn basic_auth(mut self, user: &str, password: &str) -> Request<'a> {
+        self.basic_auth = Some(format!("{}:{}", user, password));
+        self
+    }
+
+    /// S3-compatible signing (AWS, MinIO, R2, ...).
+    pub fn aws_sigv4(mut self, region: &str, access_key: &str, secret_key: &str) -> Request<'a> {
+        self.aws_sigv4 = Some(format!("aws:amz:{}:s3", region));
+        self.basic_auth(access_key, secret_key)
+    }
+
+    pub fn send(self) -> CodexResult<Response> {
+        let mut curl = Command::new("curl");
+        // `-w` appends the status and timing on their own line after the body.
+        curl.args(["-sS", "-L", "--max-time", TIMEOUT_SECS, "-X", self.method, "-w", "\n%{http_code} %{time_total}"]);
+        // Credentials and headers go through stdin config so they never show up in `ps`.
+        curl.args(["-K", "-"]);
+        let mut config = String::new();
+        for (name, value) in &self.headers {
+            config.push_str(&format!("header = \"{}: {}\"\n", name, escape(value)));
+        }
+        if let Some(auth) = &self.basic_auth {
+            config.push_str(&format!("user = \"{}\"\n", escape(auth)));
+        }
+        if let Some(provider) = &self.aws_sigv4 {
+            config.push_str(&format!("aws-sigv4 = \"{}\"\n", escape(provider)));
+        }
+        if let Some(body) = &self.body {
+            config.push_str(&format!("data-binary = \"{}\"\n", escape(&String::from_utf8_lossy(body))));
+        }
+        config.push_str(&format!("url = \"{}\"\n", escape(self.url)));
+        run(curl, &config, self.url)
+    }
+}
+
+// Quoted curl config values take C-style escapes.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n").replace('\r', "\\r").replace('\t', "\\t")
+}
+
+fn run(mut curl: Command, config: &str, url: &str) -> CodexResult<Response> {
+    #[cfg(windows)]
+    {
+        // CREATE_NO_WINDOW: no console flashing up behind the app.
+        use std::os::windows::process::CommandExt;
+        curl.creation_flags(0x0800_0000);
+    }
+    let mut child = curl
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|_| CodexError::message("http_unavailable", &[]))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(config.as_bytes());
+    }
+    let output = child.wait_with_output().map_err(|_| CodexError::message("http_unavailable", &[]))?;
+    if !output.status.success() {
+        let message = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(CodexError::message("http_failed", &[("url", &url), ("message", &message)]));
+    }
+    let mut body = output.stdout;
+    let split = body.iter().rposition(|&b| b == b'\n').unwrap_or(0);
+    let trailer = String::from_utf8_lossy(&body[split..]).into_owned();
+    let mut fields = trailer.split_whitespace();
+    let status = fields.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let time_total = fields.next().and_then(|s| s.parse().ok()).unwrap_or(0.0);
+    body.truncate(split);
+    Ok(Response { status, body, time_total })
+}
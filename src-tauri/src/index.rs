@@ -0,0 +1,168 @@
+// Project-wide view of scanned assets together with what their package headers say
+// (class, registry tags, dependencies). Built on demand by analysis commands.
+
+use crate::package::Package;
+use crate::scan::UnrealAsset;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct IndexedAsset {
+    pub asset: UnrealAsset,
+    pub class: Option<String>,
+    pub tags: BTreeMap<String, String>,
+    pub dependencies: Vec<String>,
+    /// Bytes on disk, including split-off `.uexp`/`.ubulk`/`.uptnl` payloads.
+    pub size: u64,
+    pub error: Option<String>,
+}
+
+impl IndexedAsset {
+    pub fn from_asset(asset: UnrealAsset) -> IndexedAsset {
+        let file_path = PathBuf::from(&asset.file_path);
+        let mut indexed = IndexedAsset {
+            asset,
+            class: None,
+            tags: BTreeMap::new(),
+            dependencies: Vec::new(),
+            size: package_size(&file_path),
+            error: None,
+        };
+        if !indexed.asset.is_package() {
+            return indexed;
+        }
+        match Package::read_file(&file_path) {
+            Ok(package) => {
+                indexed.class = package.main_class();
+                indexed.tags = package.main_registry_object().map(|o| o.tags.clone()).unwrap_or_default();
+                indexed.dependencies = package.dependencies();
+            }
+            Err(e) => indexed.error = Some(e.to_string()),
+        }
+        indexed
+    }
+
+    pub fn is_class(&self, class: &str) -> bool {
+        self.class.as_deref() == Some(class)
+    }
+
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(|v| v.as_str())
+    }
+
+    pub fn folder(&self) -> &str {
+        self.asset.path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AssetIndex {
+    pub project: PathBuf,
+    pub assets: Vec<IndexedAsset>,
+    by_path: HashMap<String, usize>,
+}
+
+impl AssetIndex {
+    pub fn from_assets(project: &Path, assets: Vec<UnrealAsset>) -> AssetIndex {
+        let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+        let chunk_size = assets.len().div_ceil(threads).max(1);
+        let mut indexed: Vec<IndexedAsset> = Vec::with_capacity(assets.len());
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = assets
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || chunk.iter().cloned().map(IndexedAsset::from_asset).collect::<Vec<_>>())
+                })
+                .collect();
+            for handle in handles {
+                indexed.extend(handle.join().unwrap_or_default());
+            }
+        });
+        AssetIndex::from_indexed(project, indexed)
+    }
+
+    pub fn from_indexed(project: &Path, assets: Vec<IndexedAsset>) -> AssetIndex {
+        let by_path = assets
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (a.asset.path.to_lowercase(), i))
+            .collect();
+        AssetIndex {
+            project: project.to_path_buf(),
+            assets,
+            by_path,
+        }
+    }
+
+    /// Looks up an asset by package path (`/Game/Folder/Asset`), ignoring case like the engine does.
+    pub fn get(&self, ue_path: &str) -> Option<&IndexedAsset> {
+        self.by_path
+            .get(&crate::package::package_of_object_path(ue_path).to_lowercase())
+            .map(|&i| &self.assets[i])
+    }
+
+    pub fn contains(&self, ue_path: &str) -> bool {
+        self.get(ue_path).is_some()
+    }
+
+    /// Reverse dependency map: asset index -> indices of the project assets that reference it.
+    pub fn referencers(&self) -> Vec<Vec<usize>> {
+        let mut referencers = vec![Vec::new(); self.assets.len()];
+        for (i, asset) in self.assets.iter().enumerate() {
+            for dep in &asset.dependencies {
+                if let Some(&target) = self.by_path.get(&crate::package::package_of_object_path(dep).to_lowercase()) {
+                    if target != i && referencers[target].last() != Some(&i) {
+                        referencers[target].push(i);
+                    }
+                }
+            }
+        }
+        referencers
+    }
+
+    pub fn of_class<'a>(&'a self, class: &'a str) -> impl Iterator<Item = &'a IndexedAsset> + 'a {
+        self.assets.iter().filter(move |a| a.is_class(class))
+    }
+}
+
+fn package_size(file_path: &Path) -> u64 {
+    ["uexp", "ubulk", "uptnl"]
+        .iter()
+        .map(|ext| file_path.with_extension(ext))
+        .chain(std::iter::once(file_path.to_path_buf()))
+        .filter_map(|p| std::fs::metadata(p).ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// `path` is `folder` itself or lives somewhere below it.
+pub fn is_in_folder(path: &str, folder: &str) -> bool {
+    let folder = folder.trim_end_matches('/');
+    // `get` rather than slicing: the folder's length can fall inside a multi-byte character.
+    path.get(..folder.len()).is_some_and(|p| p.eq_ignore_ascii_case(folder))
+        && path.get(folder.len()..).is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_in_folder;
+
+    #[test]
+    fn folder_and_descendants_match() {
+        assert!(is_in_folder("/Game/Maps", "/Game/Maps"));
+        assert!(is_in_folder("/Game/Maps/Arena", "/Game/Maps"));
+        assert!(is_in_folder("/game/maps/Arena", "/Game/Maps/"));
+        assert!(!is_in_folder("/Game/MapsOld/Arena", "/Game/Maps"));
+        assert!(!is_in_folder("/Game", "/Game/Maps"));
+    }
+
+    #[test]
+    fn non_ascii_paths_do_not_panic() {
+        assert!(is_in_folder("/Game/Персонажи/BP_Hero", "/Game/Персонажи"));
+        assert!(!is_in_folder("/Game/Персонажи/BP_Hero", "/Game/Maps"));
+        // "/Game/П" is 8 bytes; a 7-byte folder ends inside the П.
+        assert!(!is_in_folder("/Game/Персонажи", "/Game/X"));
+        assert!(!is_in_folder("/Game/Пx", "/Game/ab"));
+        assert!(!is_in_folder("/Gäme", "/Game"));
+    }
+}
@@ -0,0 +1,244 @@
+// Damaged-package detection: empty files, wrong magic, and headers that point past the end
+// of the file, which is what a half-finished sync usually leaves behind. Files the editor holds
+// open and valid packages this reader doesn't parse (cooked, newer, compressed, byte-swapped)
+// are reported on their own and never counted as damage.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::package::{Package, PACKAGE_FILE_TAG, PACKAGE_FILE_TAG_SWAPPED};
+use crate::scan::UnrealAsset;
+use serde::Serialize;
+use std::io::Read;
+use std::path::Path;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum Corruption {
+    ZeroBytes,
+    /// The file is a Git LFS pointer rather than package data.
+    LfsPointer,
+    BadMagic { magic: String },
+    /// The header claims more data than the file holds.
+    Truncated { expected: u64, actual: u64 },
+    /// The file ends inside the header itself.
+    TruncatedHeader { actual: u64 },
+}
+
+/// What inspecting one package found. Only `Damaged` counts as corrupt.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "status")]
+pub enum Inspection {
+    Intact,
+    /// Held open by the editor; nothing can be said until it lets go.
+    Locked,
+    /// A valid package in a layout this reader doesn't parse, e.g. `unversioned`.
+    Unsupported { reason: String },
+    /// Couldn't be opened or parsed, for reasons that don't point at damage.
+    Unreadable { error: String },
+    Damaged { problem: Corruption },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CorruptAsset {
+    pub path: String,
+    pub file_path: String,
+    pub size: u64,
+    pub problem: Corruption,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedAsset {
+    pub path: String,
+    /// Unsupported layout code, or the read error.
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IntegrityReport {
+    pub corrupt: Vec<CorruptAsset>,
+    pub unsupported: Vec<SkippedAsset>,
+    pub unreadable: Vec<SkippedAsset>,
+    /// Held open by the editor, at scan time or now.
+    pub locked: Vec<String>,
+}
+
+fn damaged(problem: Corruption) -> Inspection {
+    Inspection::Damaged { problem }
+}
+
+pub fn inspect(file_path: &Path) -> Inspection {
+    let size = std::fs::metadata(file_path).map(|m| m.len()).unwrap_or(0);
+    if size == 0 {
+        return damaged(Corruption::ZeroBytes);
+    }
+    let mut file = match crate::file_lock::open(file_path) {
+        Ok(file) => file,
+        Err(e) if crate::file_lock::is_lock_error(&e) => return Inspection::Locked,
+        Err(e) => return Inspection::Unreadable { error: e.to_string() },
+    };
+    let mut magic = [0u8; 4];
+    match file.read_exact(&mut magic) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+            return damaged(Corruption::Truncated { expected: 4, actual: size });
+        }
+        Err(e) => return Inspection::Unreadable { error: e.to_string() },
+    }
+    drop(file);
+    let tag = u32::from_le_bytes(magic);
+    if tag == PACKAGE_FILE_TAG_SWAPPED {
+        return Inspection::Unsupported { reason: "byte_swapped".to_string() };
+    }
+    if tag != PACKAGE_FILE_TAG {
+        if crate::vcs::read_lfs_pointer(file_path).is_some() {
+            return damaged(Corruption::LfsPointer);
+        }
+        return damaged(Corruption::BadMagic { magic: format!("{:08X}", tag) });
+    }
+
+    let package = match Package::read_file(file_path) {
+        Ok(package) => package,
+        Err(CodexError::Io { kind, .. }) if kind == "locked" => return Inspection::Locked,
+        Err(error) => {
+            if let Some(layout) = Package::unsupported_layout(file_path) {
+                return Inspection::Unsupported { reason: layout.code().to_string() };
+            }
+            return match error {
+                CodexError::ParseFailed { message, .. } if message == crate::package::UNEXPECTED_EOF => {
+                    damaged(Corruption::TruncatedHeader { actual: size })
+                }
+                error => Inspection::Unreadable { error: error.to_string() },
+            };
+        }
+    };
+    // Cooked packages keep export data in a .uexp sibling that continues the same offsets.
+    let uexp = std::fs::metadata(file_path.with_extension("uexp")).map(|m| m.len()).unwrap_or(0);
+    let available = size + uexp;
+    let header_end = package.summary.total_header_size.max(0) as u64;
+    let exports_end =
+        package.exports.iter().map(|e| (e.serial_offset + e.serial_size).max(0) as u64).max().unwrap_or(0);
+    let expected = header_end.max(exports_end);
+    if expected > available {
+        return damaged(Corruption::Truncated { expected, actual: available });
+    }
+    Inspection::Intact
+}
+
+pub fn check(assets: Vec<UnrealAsset>) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+    for asset in assets.into_iter().filter(|a| a.is_package()) {
+        // The scan already found it held open; probing again would only wait on the lock.
+        let inspection = if asset.locked { Inspection::Locked } else { inspect(Path::new(&asset.file_path)) };
+        match inspection {
+            Inspection::Intact => {}
+            Inspection::Locked => report.locked.push(asset.path),
+            Inspection::Unsupported { reason } => report.unsupported.push(SkippedAsset { path: asset.path, reason }),
+            Inspection::Unreadable { error } => {
+                report.unreadable.push(SkippedAsset { path: asset.path, reason: error })
+            }
+            Inspection::Damaged { problem } => report.corrupt.push(CorruptAsset {
+                size: asset.size,
+                path: asset.path,
+                file_path: asset.file_path,
+                problem,
+            }),
+        }
+    }
+    report
+}
+
+/// Only the genuinely damaged packages.
+pub fn find_corrupt(assets: Vec<UnrealAsset>) -> Vec<CorruptAsset> {
+    check(assets).corrupt
+}
+
+#[tauri::command]
+pub fn find_corrupt_assets(manager: State<ScanManager>, project: String) -> CodexResult<IntegrityReport> {
+    Ok(check(manager.assets(&project)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn package_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("codex-integrity-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.uasset", name));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    /// Summary start: tag, legacy version -8, UE3 version, then the UE4/UE5/licensee versions.
+    fn summary(tag: u32, ue4: i32, ue5: i32) -> Vec<u8> {
+        let mut bytes = tag.to_le_bytes().to_vec();
+        for value in [-8i32, 864, ue4, ue5, 0] {
+            bytes.extend(value.to_le_bytes());
+        }
+        bytes.resize(256, 0);
+        bytes
+    }
+
+    fn asset(file_path: &Path, locked: bool) -> UnrealAsset {
+        UnrealAsset {
+            name: "SM_Test".to_string(),
+            path: "/Game/SM_Test".to_string(),
+            file_path: file_path.to_string_lossy().to_string(),
+            asset_type: "StaticMesh".to_string(),
+            size: std::fs::metadata(file_path).map(|m| m.len()).unwrap_or(0),
+            modified: 0,
+            is_large: false,
+            mount: "/Game".to_string(),
+            owner: None,
+            locked,
+            is_starter_content: false,
+        }
+    }
+
+    fn unsupported_reason(inspection: Inspection) -> Option<String> {
+        match inspection {
+            Inspection::Unsupported { reason } => Some(reason),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn unversioned_packages_are_unsupported_not_corrupt() {
+        let path = package_file("unversioned", &summary(PACKAGE_FILE_TAG, 0, 0));
+        assert_eq!(unsupported_reason(inspect(&path)).as_deref(), Some("unversioned"));
+        let report = check(vec![asset(&path, false)]);
+        assert!(report.corrupt.is_empty());
+        assert_eq!(report.unsupported.len(), 1);
+    }
+
+    #[test]
+    fn newer_and_byte_swapped_packages_are_unsupported() {
+        let too_new = package_file("too_new", &summary(PACKAGE_FILE_TAG, 522, 9999));
+        assert_eq!(unsupported_reason(inspect(&too_new)).as_deref(), Some("too_new"));
+        let swapped = package_file("swapped", &summary(PACKAGE_FILE_TAG_SWAPPED, 522, 1010));
+        assert_eq!(unsupported_reason(inspect(&swapped)).as_deref(), Some("byte_swapped"));
+    }
+
+    #[test]
+    fn locked_assets_are_skipped() {
+        // Even an empty file isn't reported while the scan saw it locked.
+        let path = package_file("locked", &[]);
+        let report = check(vec![asset(&path, true)]);
+        assert!(report.corrupt.is_empty());
+        assert_eq!(report.locked, vec!["/Game/SM_Test".to_string()]);
+    }
+
+    #[test]
+    fn truncation_and_bad_magic_are_damage() {
+        let mut cut = PACKAGE_FILE_TAG.to_le_bytes().to_vec();
+        cut.extend((-8i32).to_le_bytes());
+        let cut = package_file("cut", &cut);
+        assert!(matches!(inspect(&cut), Inspection::Damaged { problem: Corruption::TruncatedHeader { .. } }));
+
+        let garbage = package_file("garbage", b"not a package at all");
+        assert!(matches!(inspect(&garbage), Inspection::Damaged { problem: Corruption::BadMagic { .. } }));
+        assert_eq!(check(vec![asset(&garbage, false)]).corrupt.len(), 1);
+    }
+}
@@ -0,0 +1,87 @@
+// Blueprint interfaces and the Blueprints implementing them, from the `ImplementedInterfaces`
+// registry tag with a fallback to the Blueprint's own property list.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, PropertyValue};
+use serde::Serialize;
+use std::path::Path;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InterfaceImplementors {
+    pub interface: String,
+    /// Functions the interface declares.
+    pub functions: Vec<String>,
+    pub implementors: Vec<String>,
+}
+
+pub fn is_interface(asset: &IndexedAsset) -> bool {
+    asset.tag("BlueprintType") == Some("BPTYPE_Interface")
+}
+
+/// Interface packages a Blueprint implements.
+pub fn implemented_interfaces(asset: &IndexedAsset) -> Vec<String> {
+    if let Some(tag) = asset.tag("ImplementedInterfaces") {
+        return crate::t3d::asset_paths(tag);
+    }
+    // Older saves don't carry the tag; the property is only worth reading when the
+    // Blueprint depends on some interface at all, which the caller checks.
+    let file_path = Path::new(&asset.asset.file_path);
+    let Ok(package) = Package::read_file(file_path) else {
+        return Vec::new();
+    };
+    let Some(main) = package.main_export() else {
+        return Vec::new();
+    };
+    let props = properties::read_export_properties(file_path, &package, main).unwrap_or_default();
+    match properties::find(&props, "ImplementedInterfaces") {
+        Some(PropertyValue::Array(items)) => items
+            .iter()
+            .filter_map(|i| i.field("Interface").and_then(|v| v.as_str()))
+            .map(|p| crate::package::package_of_object_path(p).to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn implementors(index: &AssetIndex, ue_path: &str) -> CodexResult<InterfaceImplementors> {
+    let interface = index.get(ue_path).ok_or_else(|| CodexError::message("asset_not_found", &[("path", &ue_path)]))?;
+    if !is_interface(interface) {
+        return Err(CodexError::message("not_an_interface", &[("path", &ue_path)]));
+    }
+    let path = interface.asset.path.clone();
+
+    let implementors = index
+        .assets
+        .iter()
+        .filter(|a| a.asset.path != path && a.dependencies.iter().any(|d| d.eq_ignore_ascii_case(&path)))
+        .filter(|a| implemented_interfaces(a).iter().any(|i| i.eq_ignore_ascii_case(&path)))
+        .map(|a| a.asset.path.clone())
+        .collect();
+
+    let file_path = Path::new(&interface.asset.file_path);
+    let functions = Package::read_file(file_path)
+        .ok()
+        .and_then(|package| {
+            let main =
+                package.exports.iter().position(|e| e.outer_index == 0 && package.export_class(e) == "Blueprint")?;
+            crate::blueprint::summarize(file_path, &package, main).ok()
+        })
+        .map(|summary| summary.functions)
+        .unwrap_or_default();
+
+    Ok(InterfaceImplementors { interface: path, functions, implementors })
+}
+
+#[tauri::command]
+pub fn get_interface_implementors(
+    manager: State<ScanManager>,
+    project: String,
+    ue_path: String,
+) -> CodexResult<InterfaceImplementors> {
+    let index = manager.index(&project)?;
+    implementors(&index, &ue_path)
+}
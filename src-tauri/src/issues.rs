@@ -0,0 +1,202 @@
+// Issue tracker linkage: Jira keys attached to assets in the codex database, and, when a
+// tracker is configured, their live status from the Jira REST API so an asset's open bugs
+// show up next to it.
+
+use crate::codex_db::{CodexDb, IssueLink, IssueTracker};
+use crate::error::{CodexError, CodexResult};
+use crate::http::Request;
+use serde::Serialize;
+use std::path::Path;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueStatus {
+    pub summary: String,
+    pub status: String,
+    /// Jira's status category: `new`, `indeterminate` or `done`.
+    pub category: String,
+    pub issue_type: Option<String>,
+    pub open: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetIssue {
+    #[serde(flatten)]
+    pub link: IssueLink,
+    /// Browse URL, when a tracker is configured.
+    pub url: Option<String>,
+    pub status: Option<IssueStatus>,
+    /// Why the status couldn't be fetched.
+    pub error: Option<CodexError>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IssueTrackerSettings {
+    pub base_url: String,
+    pub user: String,
+    pub has_token: bool,
+}
+
+/// `GAME-1234`, upper-cased; Jira keys are a project key, a dash and a number.
+pub fn normalize_key(key: &str) -> CodexResult<String> {
+    let key = key.trim().to_ascii_uppercase();
+    let valid = key.split_once('-').is_some_and(|(project, number)| {
+        project.starts_with(|c: char| c.is_ascii_alphabetic())
+            && project.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !number.is_empty()
+            && number.chars().all(|c| c.is_ascii_digit())
+    });
+    if valid {
+        Ok(key)
+    } else {
+        Err(CodexError::message("invalid_issue_key", &[("key", &key)]))
+    }
+}
+
+pub fn link(project: &Path, ue_path: &str, key: &str, linked_by: &str) -> CodexResult<Vec<IssueLink>> {
+    let path = crate::package::package_of_object_path(ue_path).to_string();
+    let key = normalize_key(key)?;
+    CodexDb::update(project, |db| {
+        let links = db.issues.entry(path.clone()).or_default();
+        if !links.iter().any(|l| l.key == key) {
+            let timestamp = crate::scan::unix_seconds(SystemTime::now());
+            links.push(IssueLink { key, linked_by: linked_by.to_string(), timestamp });
+            db.issue_changes.insert(path, timestamp);
+        }
+        Ok(links.clone())
+    })
+}
+
+pub fn unlink(project: &Path, ue_path: &str, key: &str) -> CodexResult<Vec<IssueLink>> {
+    let path = crate::package::package_of_object_path(ue_path).to_string();
+    let key = normalize_key(key)?;
+    CodexDb::update(project, |db| {
+        let links = db.issues.get_mut(&path).map(|links| {
+            links.retain(|l| l.key != key);
+            links.clone()
+        });
+        if links.as_ref().is_some_and(Vec::is_empty) {
+            db.issues.remove(&path);
+        }
+        if links.is_some() {
+            db.issue_changes.insert(path, crate::scan::unix_seconds(SystemTime::now()));
+        }
+        Ok(links.unwrap_or_default())
+    })
+}
+
+fn browse_url(tracker: &IssueTracker, key: &str) -> String {
+    format!("{}/browse/{}", tracker.base_url.trim_end_matches('/'), key)
+}
+
+pub fn fetch_status(tracker: &IssueTracker, key: &str) -> CodexResult<IssueStatus> {
+    let url =
+        format!("{}/rest/api/2/issue/{}?fields=summary,status,issuetype", tracker.base_url.trim_end_matches('/'), key);
+    let mut request = Request::get(&url).header("Accept", "application/json");
+    if let Some(token) = &tracker.token {
+        request = request.basic_auth(&tracker.user, token);
+    }
+    let issue = request.send()?.ok(&url)?.json(&url)?;
+    let fields = &issue["fields"];
+    let text = |value: &serde_json::Value| value.as_str().map(str::to_string);
+    let category = text(&fields["status"]["statusCategory"]["key"]).unwrap_or_default();
+    Ok(IssueStatus {
+        summary: text(&fields["summary"]).unwrap_or_default(),
+        status: text(&fields["status"]["name"]).unwrap_or_default(),
+        open: category != "done",
+        category,
+        issue_type: text(&fields["issuetype"]["name"]),
+    })
+}
+
+pub fn asset_issues(project: &Path, ue_path: &str, with_status: bool) -> CodexResult<Vec<AssetIssue>> {
+    let db = CodexDb::read(project)?;
+    let links = db.issues.get(crate::package::package_of_object_path(ue_path)).cloned().unwrap_or_default();
+    let tracker = db.issue_tracker.as_ref();
+    Ok(links
+        .into_iter()
+        .map(|link| {
+            let fetched = tracker.filter(|_| with_status).map(|t| fetch_status(t, &link.key));
+            let (status, error) = match fetched {
+                Some(Ok(status)) => (Some(status), None),
+                Some(Err(error)) => (None, Some(error)),
+                None => (None, None),
+            };
+            AssetIssue { url: tracker.map(|t| browse_url(t, &link.key)), link, status, error }
+        })
+        .collect())
+}
+
+fn settings(tracker: &IssueTracker) -> IssueTrackerSettings {
+    IssueTrackerSettings {
+        base_url: tracker.base_url.clone(),
+        user: tracker.user.clone(),
+        has_token: tracker.token.is_some(),
+    }
+}
+
+#[tauri::command]
+pub fn link_asset_issue(project: String, path: String, key: String, linked_by: String) -> CodexResult<Vec<IssueLink>> {
+    link(Path::new(&project), &path, &key, linked_by.trim())
+}
+
+#[tauri::command]
+pub fn unlink_asset_issue(project: String, path: String, key: String) -> CodexResult<Vec<IssueLink>> {
+    unlink(Path::new(&project), &path, &key)
+}
+
+/// Linked issues for an asset; `with_status` looks each one up on the tracker.
+#[tauri::command(async)]
+pub fn get_asset_issues(project: String, path: String, with_status: Option<bool>) -> CodexResult<Vec<AssetIssue>> {
+    asset_issues(Path::new(&project), &path, with_status.unwrap_or(true))
+}
+
+/// Assets linked to an issue key.
+#[tauri::command]
+pub fn find_assets_for_issue(project: String, key: String) -> CodexResult<Vec<String>> {
+    let key = normalize_key(&key)?;
+    let db = CodexDb::read(Path::new(&project))?;
+    Ok(db.issues.into_iter().filter(|(_, links)| links.iter().any(|l| l.key == key)).map(|(path, _)| path).collect())
+}
+
+#[tauri::command]
+pub fn get_issue_tracker(project: String) -> CodexResult<Option<IssueTrackerSettings>> {
+    Ok(CodexDb::read(Path::new(&project))?.issue_tracker.as_ref().map(settings))
+}
+
+/// An empty `base_url` removes the tracker. A missing `token` keeps the stored one.
+#[tauri::command]
+pub fn set_issue_tracker(
+    project: String,
+    base_url: String,
+    user: String,
+    token: Option<String>,
+) -> CodexResult<Option<IssueTrackerSettings>> {
+    let base_url = base_url.trim().trim_end_matches('/').to_string();
+    if !base_url.is_empty() && !base_url.starts_with("https://") && !base_url.starts_with("http://") {
+        return Err(CodexError::message("invalid_url", &[("url", &base_url)]));
+    }
+    CodexDb::update(Path::new(&project), |db| {
+        if base_url.is_empty() {
+            db.issue_tracker = None;
+            return Ok(None);
+        }
+        let token = match token {
+            Some(token) => Some(token).filter(|t| !t.is_empty()),
+            None => db.issue_tracker.take().and_then(|t| t.token),
+        };
+        let tracker = IssueTracker { base_url, user: user.trim().to_string(), token };
+        let shown = settings(&tracker);
+        db.issue_tracker = Some(tracker);
+        Ok(Some(shown))
+    })
+}
+
+/// Fetches one issue with the stored settings, to check them.
+#[tauri::command(async)]
+pub fn test_issue_tracker(project: String, key: String) -> CodexResult<IssueStatus> {
+    let tracker = CodexDb::read(Path::new(&project))?
+        .issue_tracker
+        .ok_or_else(|| CodexError::message("no_issue_tracker", &[]))?;
+    fetch_status(&tracker, &normalize_key(&key)?)
+}
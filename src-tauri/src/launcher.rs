@@ -0,0 +1,165 @@
+// Epic Games Launcher library: the engines it installed (LauncherInstalled.dat and the
+// `.item` manifests) and the projects it lists, which come from each engine version's
+// saved EditorSettings.ini.
+
+use crate::config::ConfigValue;
+use crate::discovery::{self, ProjectCandidate};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LauncherEngine {
+    /// `UE_5.3`
+    pub app_name: String,
+    pub version: Option<String>,
+    pub install_location: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LauncherLibrary {
+    pub engines: Vec<LauncherEngine>,
+    pub projects: Vec<ProjectCandidate>,
+}
+
+// `C:\ProgramData\Epic` on Windows, `~/Library/Application Support/Epic` on macOS. There is
+// no Linux launcher.
+fn launcher_data_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        let program_data = std::env::var_os("PROGRAMDATA").unwrap_or_else(|| "C:\\ProgramData".into());
+        Some(Path::new(&program_data).join("Epic"))
+    } else if cfg!(target_os = "macos") {
+        Some(Path::new(&std::env::var_os("HOME")?).join("Library/Application Support/Epic"))
+    } else {
+        None
+    }
+}
+
+// Per-user engine data, one folder per engine version (`5.3/Saved/Config/...`).
+fn engine_user_dir() -> Option<PathBuf> {
+    if cfg!(windows) {
+        Some(Path::new(&std::env::var_os("LOCALAPPDATA")?).join("UnrealEngine"))
+    } else if cfg!(target_os = "macos") {
+        Some(Path::new(&std::env::var_os("HOME")?).join("Library/Application Support/Epic/UnrealEngine"))
+    } else {
+        Some(Path::new(&std::env::var_os("HOME")?).join(".config/Epic/UnrealEngine"))
+    }
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    serde_json::from_slice(&std::fs::read(path).ok()?).ok()
+}
+
+fn engine_version(app_name: &str, install_location: &Path) -> Option<String> {
+    crate::project::build_version(install_location)
+        .map(|r| r.to_string())
+        .or_else(|| app_name.strip_prefix("UE_").map(str::to_string))
+}
+
+pub fn installed_engines() -> Vec<LauncherEngine> {
+    let Some(data) = launcher_data_dir() else {
+        return Vec::new();
+    };
+    // Keyed by install location; the .dat and the manifests usually list the same engines.
+    let mut engines: BTreeMap<String, LauncherEngine> = BTreeMap::new();
+    let mut add = |app_name: &str, location: &str| {
+        if app_name.starts_with("UE_") && !location.is_empty() && Path::new(location).is_dir() {
+            engines.entry(location.to_string()).or_insert_with(|| LauncherEngine {
+                app_name: app_name.to_string(),
+                version: engine_version(app_name, Path::new(location)),
+                install_location: location.to_string(),
+            });
+        }
+    };
+
+    if let Some(dat) = read_json(&data.join("UnrealEngineLauncher/LauncherInstalled.dat")) {
+        for item in dat.get("InstallationList").and_then(|l| l.as_array()).into_iter().flatten() {
+            let field = |key: &str| item.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+            add(field("AppName"), field("InstallLocation"));
+        }
+    }
+    let manifests = std::fs::read_dir(data.join("EpicGamesLauncher/Data/Manifests")).into_iter().flatten();
+    let items =
+        manifests.filter_map(|e| e.ok()).map(|e| e.path()).filter(|p| p.extension().is_some_and(|e| e == "item"));
+    for path in items {
+        let Some(item) = read_json(&path) else {
+            continue;
+        };
+        if item.get("bIsIncompleteInstall").and_then(|v| v.as_bool()) == Some(true) {
+            continue;
+        }
+        let field = |key: &str| item.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+        add(field("AppName"), field("InstallLocation"));
+    }
+    engines.into_values().collect()
+}
+
+// Saved config writes arrays as repeated `Key=` lines, which `IniFile` would collapse.
+fn saved_array(text: &str, key: &str) -> Vec<String> {
+    text.lines()
+        .filter_map(|line| {
+            let (k, v) = line.trim().split_once('=')?;
+            k.trim_start_matches('+').eq_ignore_ascii_case(key).then(|| v.trim().to_string())
+        })
+        .collect()
+}
+
+fn editor_settings_files() -> Vec<PathBuf> {
+    let Some(dir) = engine_user_dir() else {
+        return Vec::new();
+    };
+    let platform = if cfg!(windows) {
+        "Windows"
+    } else if cfg!(target_os = "macos") {
+        "Mac"
+    } else {
+        "Linux"
+    };
+    let versions = std::fs::read_dir(dir).into_iter().flatten().filter_map(|e| e.ok()).map(|e| e.path());
+    versions
+        .flat_map(|version| {
+            // UE5 saves editor config under `<Platform>Editor`, UE4 under `<Platform>`.
+            [format!("{}Editor", platform), platform.to_string()]
+                .map(|folder| version.join("Saved/Config").join(folder).join("EditorSettings.ini"))
+        })
+        .filter(|p| p.is_file())
+        .collect()
+}
+
+/// The launcher's "My Projects": recently opened projects plus everything in the folders
+/// projects were created in.
+pub fn launcher_projects() -> Vec<ProjectCandidate> {
+    let mut uprojects: Vec<PathBuf> = Vec::new();
+    for settings in editor_settings_files() {
+        let Ok(text) = std::fs::read_to_string(&settings) else {
+            continue;
+        };
+        // `(ProjectName="C:/.../MyGame.uproject",LastOpenTime=...)` in UE5, a bare path in UE4.
+        for entry in saved_array(&text, "RecentlyOpenedProjectFiles") {
+            let value = ConfigValue::parse(&entry);
+            let path = value.get_str("ProjectName").or(value.as_str()).unwrap_or_default().to_string();
+            uprojects.push(PathBuf::from(path.trim_matches('"')));
+        }
+        for folder in saved_array(&text, "CreatedProjectPaths") {
+            let projects = std::fs::read_dir(folder.trim_matches('"')).into_iter().flatten().filter_map(|e| e.ok());
+            for project in projects.map(|e| e.path()).filter(|p| p.is_dir()) {
+                uprojects.extend(crate::project::find_uproject(&project));
+            }
+        }
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    let mut projects: Vec<ProjectCandidate> = uprojects
+        .iter()
+        .filter(|p| p.is_file())
+        .filter(|p| seen.insert(std::fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf())))
+        .filter_map(|p| discovery::candidate(p))
+        .collect();
+    projects.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()).then(a.project.cmp(&b.project)));
+    projects
+}
+
+#[tauri::command(async)]
+pub fn list_launcher_projects() -> LauncherLibrary {
+    LauncherLibrary { engines: installed_engines(), projects: launcher_projects() }
+}
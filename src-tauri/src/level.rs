@@ -0,0 +1,68 @@
+// Level (.umap) overview: what the persistent level contains and, for uncooked maps,
+// what its Level Blueprint does.
+
+use crate::blueprint::{self, BlueprintSummary};
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LevelInfo {
+    pub name: String,
+    pub actor_count: usize,
+    /// Actor class -> number placed in the persistent level.
+    pub actor_classes: BTreeMap<String, usize>,
+    pub level_blueprint: Option<BlueprintSummary>,
+}
+
+/// Export index of the persistent level.
+pub fn persistent_level(package: &Package) -> Option<usize> {
+    package.exports.iter().position(|e| package.export_class(e) == "Level")
+}
+
+/// Export indices of the actors placed in `level`.
+pub fn actors(package: &Package, level: usize) -> Vec<usize> {
+    // Actors are direct subobjects of the level; their components hang off the actors.
+    (0..package.exports.len())
+        .filter(|&i| {
+            let export = &package.exports[i];
+            let class = package.export_class(export);
+            export.outer_index == level as i32 + 1
+                && class != "Model"
+                && !class.ends_with("Component")
+                && !class.starts_with("LevelScriptBlueprint")
+        })
+        .collect()
+}
+
+pub fn inspect(file_path: &Path) -> CodexResult<LevelInfo> {
+    let package = Package::read_file(file_path)?;
+    let level = persistent_level(&package).ok_or_else(|| CodexError::message("no_level", &[]))?;
+
+    let mut actor_classes = BTreeMap::new();
+    let actors = actors(&package, level);
+    for &actor in &actors {
+        *actor_classes.entry(package.export_class(&package.exports[actor])).or_insert(0) += 1;
+    }
+    let actor_count = actors.len();
+
+    let level_blueprint = package
+        .exports
+        .iter()
+        .position(|e| package.export_class(e) == "LevelScriptBlueprint")
+        .and_then(|i| blueprint::summarize(file_path, &package, i).ok());
+
+    Ok(LevelInfo {
+        name: package.summary.package_name.rsplit('/').next().unwrap_or_default().to_string(),
+        actor_count,
+        actor_classes,
+        level_blueprint,
+    })
+}
+
+#[tauri::command]
+pub fn get_level_info(file_path: String) -> CodexResult<LevelInfo> {
+    inspect(Path::new(&file_path))
+}
@@ -0,0 +1,139 @@
+// Rough per-level memory footprint: everything a level pulls in through its dependency
+// closure, with textures sized from their dimensions and compression and meshes from their
+// vertex and triangle counts. Good enough to compare maps, not a substitute for profiling.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+use tauri::State;
+
+const LARGEST_ASSETS: usize = 25;
+
+// A full mip chain adds a third on top of the top mip.
+const MIP_CHAIN_FACTOR: f64 = 4.0 / 3.0;
+// Position, tangent basis, two UV channels and a color, plus 32-bit indices.
+const BYTES_PER_VERTEX: u64 = 36;
+const BYTES_PER_TRIANGLE: u64 = 12;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetEntry {
+    pub path: String,
+    pub class: String,
+    pub bytes: u64,
+    /// False when only the size on disk was available.
+    pub estimated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LevelBudget {
+    pub level: String,
+    pub asset_count: usize,
+    pub texture_bytes: u64,
+    pub mesh_bytes: u64,
+    /// Everything else, counted at its size on disk.
+    pub other_bytes: u64,
+    pub total_bytes: u64,
+    /// Levels referenced from this one (streaming sublevels), which are not included.
+    pub streamed_levels: Vec<String>,
+    pub largest: Vec<BudgetEntry>,
+}
+
+// Bytes per pixel of the format a compression setting usually cooks to on desktop.
+fn bytes_per_pixel(compression: &str) -> f64 {
+    match compression {
+        "TC_Alpha" | "TC_DistanceFieldFont" => 0.5,
+        "TC_HDR" => 8.0,
+        "TC_HalfFloat" | "TC_SingleFloat" => 2.0,
+        "TC_VectorDisplacementmap" | "TC_EditorIcon" | "TC_Displacementmap" => 4.0,
+        _ => 1.0,
+    }
+}
+
+fn texture_bytes(texture: &IndexedAsset) -> Option<u64> {
+    let settings = crate::texture_settings::texture_settings(texture);
+    let (width, height) = settings.dimensions.as_deref()?.split_once('x')?;
+    let pixels = width.trim().parse::<f64>().ok()? * height.trim().parse::<f64>().ok()?;
+    let faces = if settings.class.starts_with("TextureCube") { 6.0 } else { 1.0 };
+    Some((pixels * faces * bytes_per_pixel(&settings.compression) * MIP_CHAIN_FACTOR) as u64)
+}
+
+fn mesh_bytes(mesh: &IndexedAsset) -> Option<u64> {
+    let count = |tag: &str| mesh.tag(tag).and_then(|v| v.parse::<u64>().ok());
+    let vertices = count("Vertices")?;
+    let triangles = count("Triangles").unwrap_or(0);
+    Some(vertices * BYTES_PER_VERTEX + triangles * BYTES_PER_TRIANGLE)
+}
+
+pub fn find_level<'a>(index: &'a AssetIndex, level: &str) -> Option<&'a IndexedAsset> {
+    let normalized = level.replace('\\', "/");
+    index.get(level).or_else(|| index.assets.iter().find(|a| a.asset.file_path.replace('\\', "/") == normalized))
+}
+
+pub fn estimate(index: &AssetIndex, level: &IndexedAsset) -> LevelBudget {
+    let mut seen: HashSet<&str> = HashSet::from([level.asset.path.as_str()]);
+    let mut streamed_levels = Vec::new();
+    // World Partition maps keep their actors in separate packages that nothing references.
+    let external_folder = crate::build_state::external_actors_folder(level);
+    let mut queue: VecDeque<&IndexedAsset> = VecDeque::from([level]);
+    for actor in index.assets.iter().filter(|a| crate::index::is_in_folder(&a.asset.path, &external_folder)) {
+        seen.insert(actor.asset.path.as_str());
+        queue.push_back(actor);
+    }
+
+    let mut entries = Vec::new();
+    while let Some(asset) = queue.pop_front() {
+        for dep in &asset.dependencies {
+            let Some(target) = index.get(dep) else {
+                continue;
+            };
+            if !seen.insert(target.asset.path.as_str()) {
+                continue;
+            }
+            if target.asset.asset_type == "Level" {
+                streamed_levels.push(target.asset.path.clone());
+                continue;
+            }
+            queue.push_back(target);
+        }
+        let class = asset.class.clone().unwrap_or_else(|| asset.asset.asset_type.clone());
+        let estimate = match class.as_str() {
+            c if c.starts_with("Texture") && !c.starts_with("TextureRenderTarget") => texture_bytes(asset),
+            "StaticMesh" | "SkeletalMesh" => mesh_bytes(asset),
+            _ => None,
+        };
+        entries.push(BudgetEntry {
+            path: asset.asset.path.clone(),
+            estimated: estimate.is_some(),
+            bytes: estimate.unwrap_or(asset.size),
+            class,
+        });
+    }
+
+    let sum = |pred: &dyn Fn(&BudgetEntry) -> bool| entries.iter().filter(|e| pred(e)).map(|e| e.bytes).sum::<u64>();
+    let textures = sum(&|e| e.class.starts_with("Texture"));
+    let meshes = sum(&|e| e.class == "StaticMesh" || e.class == "SkeletalMesh");
+    let total = sum(&|_| true);
+    entries.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+    streamed_levels.sort();
+    LevelBudget {
+        level: level.asset.path.clone(),
+        asset_count: entries.len(),
+        texture_bytes: textures,
+        mesh_bytes: meshes,
+        other_bytes: total - textures - meshes,
+        total_bytes: total,
+        streamed_levels,
+        largest: entries.into_iter().take(LARGEST_ASSETS).collect(),
+    }
+}
+
+/// `level` is a package path (`/Game/Maps/Arena`) or the .umap file path.
+#[tauri::command(async)]
+pub fn estimate_level_budget(manager: State<ScanManager>, project: String, level: String) -> CodexResult<LevelBudget> {
+    let index = manager.index(&project)?;
+    let level =
+        find_level(&index, &level).ok_or_else(|| CodexError::message("level_not_found", &[("level", &level)]))?;
+    Ok(estimate(&index, level))
+}
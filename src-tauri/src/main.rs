@@ -3,73 +3,191 @@
   windows_subsystem = "windows"
 )]
 
-use serde::{Deserialize, Serialize};
-use std::path::Path;
-use walkdir::WalkDir;
+mod ai_assets;
+mod asset_diff;
+mod asset_dump;
+mod asset_manager;
+mod audio;
+mod benchmark;
+mod blueprint;
+mod broken_refs;
+mod build_state;
+mod chunks;
+mod claims;
+mod codex_db;
+mod compat;
+mod config;
+mod curves;
+mod data_assets;
+mod deep_link;
+mod destruction;
+mod discovery;
+mod editor_only;
+mod error;
+mod file_drop;
+mod file_lock;
+mod folder_deps;
+mod gas;
+mod history;
+mod http;
+mod index;
+mod integrity;
+mod interfaces;
+mod issues;
+mod launcher;
+mod level;
+mod level_budget;
+mod manager;
+mod map_check;
+mod metasound;
+mod native_classes;
+mod niagara;
+mod notifier;
+mod ownership;
+mod package;
+mod physics;
+mod platform_readiness;
+mod project;
+mod properties;
+mod property_search;
+mod quick_open;
+mod readmes;
+mod reports;
+mod reviews;
+mod scan;
+mod scan_output;
+mod scheduler;
+mod sequencer;
+mod shared_ddc;
+mod skeletons;
+mod source_art;
+mod starter;
+mod t3d;
+mod team_sync;
+mod texture_settings;
+mod tray;
+mod ue5_features;
+mod uefn;
+mod usage;
+mod vcs;
+mod workspace;
+mod world_building;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct UnrealAsset {
-    name: String,
-    path: String,      // Relative path e.g. /Game/Folder/Asset
-    file_path: String, // Absolute file path
-    asset_type: String,
-}
-
-#[tauri::command]
-fn scan_unreal_project(path: String) -> Result<Vec<UnrealAsset>, String> {
-    let content_path = Path::new(&path).join("Content");
-    if !content_path.exists() {
-        return Err("Content folder not found".to_string());
-    }
-
-    let mut assets = Vec::new();
-
-    for entry in WalkDir::new(&content_path).into_iter().filter_map(|e| e.ok()) {
-        let path = entry.path();
-        if path.is_file() {
-            if let Some(ext) = path.extension() {
-                if ext == "uasset" || ext == "umap" {
-                    let file_name = path.file_stem().unwrap().to_string_lossy().to_string();
-
-                    // Determine type based on prefix (heuristic)
-                    let asset_type = if ext == "umap" {
-                        "Level".to_string()
-                    } else if file_name.starts_with("BP_") {
-                        "Blueprint".to_string()
-                    } else if file_name.starts_with("M_") {
-                        "Material".to_string()
-                    } else if file_name.starts_with("SM_") {
-                        "StaticMesh".to_string()
-                    } else if file_name.starts_with("T_") {
-                        "Texture".to_string()
-                    } else {
-                        "Asset".to_string()
-                    };
-
-                    // Calculate relative path for UE reference (e.g. /Game/...)
-                    if let Ok(relative_path) = path.strip_prefix(&content_path) {
-                        let ue_path = format!("/Game/{}", relative_path.to_string_lossy().replace("\\", "/"));
-                        // Remove extension for UE path
-                        let ue_path_no_ext = ue_path.rsplit_once('.').map(|(a, _)| a).unwrap_or(&ue_path).to_string();
-
-                        assets.push(UnrealAsset {
-                            name: file_name,
-                            path: ue_path_no_ext,
-                            file_path: path.to_string_lossy().to_string(),
-                            asset_type,
-                        });
-                    }
-                }
-            }
-        }
-    }
-
-    Ok(assets)
-}
+use tauri::Manager;
 
 fn main() {
+  // A link opened while we're already running goes to that instance instead.
+  let launch_link = deep_link::from_args();
+  if launch_link.as_deref().is_some_and(deep_link::forward) {
+    return;
+  }
   tauri::Builder::default()
-    .invoke_handler(tauri::generate_handler![scan_unreal_project])
+    .manage(manager::ScanManager::default())
+    .setup(move |app| {
+      app.state::<manager::ScanManager>().scheduler.start(app.handle());
+      reports::start(app.handle());
+      team_sync::start(app.handle());
+      deep_link::register_scheme();
+      deep_link::listen(app.handle());
+      if let Some(url) = &launch_link {
+        deep_link::open(&app.handle(), url);
+      }
+      Ok(())
+    })
+    .system_tray(tray::system_tray())
+    .on_system_tray_event(tray::handle_tray_event)
+    .on_window_event(file_drop::handle)
+    .on_window_event(tray::handle_window_event)
+    .invoke_handler(tauri::generate_handler![
+      manager::scan_unreal_project,
+      scan_output::scan_unreal_project_to_file,
+      scan_output::release_scan_file,
+      asset_manager::get_asset_manager_report,
+      chunks::estimate_chunk_sizes,
+      source_art::get_source_file,
+      source_art::get_missing_source_report,
+      source_art::scan_source_art,
+      vcs::check_lfs_integrity,
+      vcs::generate_vcs_config,
+      history::get_asset_history,
+      history::get_contributor_stats,
+      asset_diff::diff_asset_versions,
+      compat::check_engine_compat,
+      integrity::find_corrupt_assets,
+      scheduler::set_rescan_interval,
+      scheduler::pause_background_scans,
+      scheduler::watch_project,
+      scheduler::unwatch_project,
+      scheduler::list_watched_projects,
+      benchmark::benchmark_scan,
+      quick_open::quick_open,
+      t3d::parse_t3d,
+      niagara::get_niagara_info,
+      uefn::get_verse_devices,
+      usage::get_usage_heatmap,
+      folder_deps::get_folder_dependency_matrix,
+      workspace::mount_content_root,
+      workspace::unmount_content_root,
+      workspace::list_content_roots,
+      level::get_level_info,
+      interfaces::get_interface_implementors,
+      skeletons::get_skeleton_map,
+      audio::get_audio_hierarchy,
+      metasound::get_metasound_info,
+      ai_assets::get_ai_asset_info,
+      curves::get_curve_info,
+      physics::audit_physics_setup,
+      ownership::get_asset_owner,
+      reviews::set_review_state,
+      reviews::get_review_state,
+      reviews::list_pending_reviews,
+      discovery::discover_projects,
+      discovery::cancel_project_discovery,
+      launcher::list_launcher_projects,
+      readmes::generate_folder_readmes,
+      asset_dump::dump_asset_json,
+      property_search::search_asset_properties,
+      broken_refs::find_broken_references,
+      map_check::get_map_issues,
+      build_state::get_build_state,
+      ue5_features::audit_ue5_features,
+      platform_readiness::audit_platform_readiness,
+      texture_settings::get_texture_settings_report,
+      level_budget::estimate_level_budget,
+      deep_link::take_pending_deep_link,
+      issues::link_asset_issue,
+      issues::unlink_asset_issue,
+      issues::get_asset_issues,
+      issues::find_assets_for_issue,
+      issues::get_issue_tracker,
+      issues::set_issue_tracker,
+      issues::test_issue_tracker,
+      notifier::get_notifier,
+      notifier::set_notifier,
+      notifier::test_notifier,
+      reports::get_report_schedule,
+      reports::set_report_schedule,
+      reports::run_report_now,
+      team_sync::get_team_sync,
+      team_sync::set_team_sync,
+      team_sync::sync_codex_db_now,
+      claims::claim_asset,
+      claims::release_asset,
+      claims::list_asset_claims,
+      claims::get_asset_claim,
+      native_classes::get_native_coverage,
+      native_classes::find_unreferenced_native_classes,
+      data_assets::list_data_assets,
+      gas::get_gas_overview,
+      sequencer::get_sequence_info,
+      destruction::get_destruction_report,
+      world_building::get_world_building_info,
+      editor_only::find_editor_only_assets,
+      shared_ddc::check_shared_ddc,
+      workspace::save_workspace,
+      workspace::load_workspace,
+      workspace::get_last_workspace
+    ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
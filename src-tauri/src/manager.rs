@@ -0,0 +1,234 @@
+// One place that owns scan results: the asset list and package index of every project the
+// app has looked at, the content roots mounted into them, the background rescan scheduler and
+// the quick-open index. Commands read from here instead of rescanning, and rescans patch what's
+// here, so they all agree.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::quick_open::QuickOpenIndex;
+use crate::scan::{ContentRoot, LargeAsset, ScanOptions, UnrealAsset};
+use crate::scheduler::{RescanScheduler, ScanDelta, WatchedProject};
+use crate::workspace::MountedRoot;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use tauri::State;
+
+struct ProjectState {
+    options: ScanOptions,
+    assets: Vec<UnrealAsset>,
+    // Built the first time a command needs it, then patched by rescans.
+    index: Option<Arc<AssetIndex>>,
+    // Bumped on every change, so an index built from an older asset list isn't stored.
+    revision: u64,
+    // Only kept for the current project, and rebuilt with `assets`, whose indices it holds.
+    quick_open: Option<QuickOpenIndex>,
+}
+
+#[derive(Default)]
+pub struct ScanManager {
+    pub scheduler: RescanScheduler,
+    projects: RwLock<HashMap<String, ProjectState>>,
+    // The project the quick-open index is kept for.
+    current: RwLock<Option<String>>,
+    // Roots mounted from outside each project, keyed by canonical project path.
+    mounts: RwLock<HashMap<String, Vec<MountedRoot>>>,
+}
+
+fn project_key(project: &Path) -> String {
+    std::fs::canonicalize(project).unwrap_or_else(|_| project.to_path_buf()).to_string_lossy().to_string()
+}
+
+impl ScanManager {
+    /// A full scan plus everything that hangs off one: the stored results, the background
+    /// rescan baseline, the quick-open index and the large-asset report.
+    pub fn scan(&self, window: &tauri::Window, path: &str, options: ScanOptions) -> CodexResult<Vec<UnrealAsset>> {
+        let assets = crate::scan::scan_project(path, &self.content_roots(Path::new(path)), &options)?;
+        let previous = self.current.write().unwrap().replace(path.to_string());
+        if let Some(previous) = previous.filter(|p| p != path) {
+            if let Some(state) = self.projects.write().unwrap().get_mut(&previous) {
+                state.quick_open = None;
+            }
+        }
+        self.store(path, &options, &assets);
+        self.scheduler.track(path, &options, &assets);
+
+        let large: Vec<LargeAsset> = assets
+            .iter()
+            .filter(|a| a.is_large)
+            .map(|a| LargeAsset {
+                path: a.path.clone(),
+                file_path: a.file_path.clone(),
+                size: a.size,
+                threshold: options.threshold_for(&a.asset_type),
+            })
+            .collect();
+        let _ = window.emit("large-asset", large);
+
+        Ok(assets)
+    }
+
+    /// Scans `project` and keeps watching it for changes alongside any other watched projects.
+    pub fn watch(&self, project: &str, options: &ScanOptions) -> CodexResult<WatchedProject> {
+        let assets = crate::scan::scan_project(project, &self.content_roots(Path::new(project)), options)?;
+        self.store(project, options, &assets);
+        Ok(self.scheduler.track(project, options, &assets))
+    }
+
+    /// Replaces the stored results for `project`. Watching it is up to the caller.
+    fn store(&self, project: &str, options: &ScanOptions, assets: &[UnrealAsset]) {
+        let quick_open = self.is_current(project).then(|| QuickOpenIndex::build(assets));
+        let mut projects = self.projects.write().unwrap();
+        let revision = projects.get(project).map(|p| p.revision + 1).unwrap_or(0);
+        projects.insert(
+            project.to_string(),
+            ProjectState { options: options.clone(), assets: assets.to_vec(), index: None, revision, quick_open },
+        );
+    }
+
+    fn is_current(&self, project: &str) -> bool {
+        self.current.read().unwrap().as_deref() == Some(project)
+    }
+
+    /// Assets of the current project with a word starting with `query`, best first.
+    pub fn quick_open(&self, query: &str, limit: usize) -> Vec<UnrealAsset> {
+        let current = self.current.read().unwrap().clone();
+        let projects = self.projects.read().unwrap();
+        let Some(state) = current.and_then(|c| projects.get(&c)) else {
+            return Vec::new();
+        };
+        let found = state.quick_open.as_ref().map(|index| index.lookup(&state.assets, query, limit));
+        found.unwrap_or_default().into_iter().cloned().collect()
+    }
+
+    pub fn forget(&self, project: &str) {
+        self.projects.write().unwrap().remove(project);
+        self.scheduler.untrack(project);
+    }
+
+    /// Assets of `project`, scanning it with default options the first time it's asked for.
+    /// A project loaded this way isn't watched; only `scan` and `watch` start that.
+    pub fn assets(&self, project: &str) -> CodexResult<Vec<UnrealAsset>> {
+        if let Some(state) = self.projects.read().unwrap().get(project) {
+            return Ok(state.assets.clone());
+        }
+        let options = ScanOptions::default();
+        let assets = crate::scan::scan_project(project, &self.content_roots(Path::new(project)), &options)?;
+        self.store(project, &options, &assets);
+        Ok(assets)
+    }
+
+    /// The project's own content roots plus anything mounted into it from elsewhere.
+    pub fn content_roots(&self, project: &Path) -> Vec<ContentRoot> {
+        let mut roots = crate::scan::project_content_roots(project);
+        roots.extend(
+            self.mounted_roots(project)
+                .into_iter()
+                .map(|r| ContentRoot { path: PathBuf::from(r.path), mount: r.mount }),
+        );
+        roots
+    }
+
+    pub fn mounted_roots(&self, project: &Path) -> Vec<MountedRoot> {
+        let mounts = self.mounts.read().unwrap_or_else(|e| e.into_inner());
+        mounts.get(&project_key(project)).cloned().unwrap_or_default()
+    }
+
+    /// Changes the roots mounted into `project`. `change` edits a copy that's kept only if it
+    /// succeeds. The stored results no longer cover the right roots afterwards: a watched project
+    /// is scanned again straight away, anything else is dropped and scanned when next needed.
+    pub fn update_mounts(
+        &self,
+        project: &str,
+        change: impl FnOnce(&mut Vec<MountedRoot>) -> CodexResult<()>,
+    ) -> CodexResult<Vec<MountedRoot>> {
+        let key = project_key(Path::new(project));
+        let roots = {
+            let mut mounts = self.mounts.write().unwrap_or_else(|e| e.into_inner());
+            let mut roots = mounts.get(&key).cloned().unwrap_or_default();
+            change(&mut roots)?;
+            mounts.insert(key, roots.clone());
+            roots
+        };
+        let watched = self.scheduler.status().projects.iter().any(|p| p.project == project);
+        // Rescanning in place keeps a watched project's event channel and paused state.
+        let rescanned = watched && self.watch(project, &self.options(project)).is_ok();
+        if !rescanned {
+            self.forget(project);
+        }
+        Ok(roots)
+    }
+
+    /// Options of the last full scan of `project`, so a rescan classifies assets the same way.
+    pub fn options(&self, project: &str) -> ScanOptions {
+        self.projects.read().unwrap().get(project).map(|p| p.options.clone()).unwrap_or_default()
+    }
+
+    pub fn index(&self, project: &str) -> CodexResult<Arc<AssetIndex>> {
+        let assets = self.assets(project)?;
+        let revision = {
+            let projects = self.projects.read().unwrap();
+            let state = projects.get(project);
+            if let Some(index) = state.and_then(|p| p.index.clone()) {
+                return Ok(index);
+            }
+            state.map(|p| p.revision)
+        };
+        // Reading every package header is the slow part, so it happens without the lock.
+        let index = Arc::new(AssetIndex::from_assets(Path::new(project), assets));
+        if let Some(state) = self.projects.write().unwrap().get_mut(project) {
+            if Some(state.revision) == revision {
+                state.index = Some(index.clone());
+            }
+        }
+        Ok(index)
+    }
+
+    /// Folds a background rescan into the stored results. Only the changed packages are
+    /// re-read for the index.
+    pub fn apply(&self, delta: &ScanDelta, unlocked: &[UnrealAsset]) {
+        let mut projects = self.projects.write().unwrap();
+        let Some(state) = projects.get_mut(&delta.project) else {
+            return;
+        };
+        // An asset that unlocked and changed in the same pass shows up twice.
+        let mut seen = HashSet::new();
+        let changed: Vec<&UnrealAsset> = delta
+            .added
+            .iter()
+            .chain(&delta.modified)
+            .chain(unlocked)
+            .filter(|a| seen.insert(a.file_path.as_str()))
+            .collect();
+        let removed: HashSet<String> = delta
+            .removed
+            .iter()
+            .map(|p| p.to_lowercase())
+            .chain(changed.iter().map(|a| a.path.to_lowercase()))
+            .collect();
+        let keep = |asset: &UnrealAsset| !removed.contains(&asset.path.to_lowercase());
+
+        state.assets.retain(|a| keep(a));
+        state.assets.extend(changed.iter().map(|a| (*a).clone()));
+        state.revision += 1;
+        if let Some(index) = state.index.take() {
+            let mut indexed: Vec<IndexedAsset> = index.assets.iter().filter(|a| keep(&a.asset)).cloned().collect();
+            indexed.extend(changed.iter().map(|a| IndexedAsset::from_asset((*a).clone())));
+            state.index = Some(Arc::new(AssetIndex::from_indexed(&index.project, indexed)));
+        }
+
+        if self.is_current(&delta.project) {
+            state.quick_open = Some(QuickOpenIndex::build(&state.assets));
+        }
+    }
+}
+
+#[tauri::command]
+pub fn scan_unreal_project(
+    window: tauri::Window,
+    manager: State<ScanManager>,
+    path: String,
+    options: Option<ScanOptions>,
+) -> CodexResult<Vec<UnrealAsset>> {
+    manager.scan(&window, &path, options.unwrap_or_default())
+}
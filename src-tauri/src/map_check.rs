@@ -0,0 +1,256 @@
+// Map check without the editor: problems visible in the .umap itself (actors whose class is
+// gone, references to deleted packages, meshes with nothing assigned, unbuilt lighting)
+// merged with the MapCheck output the editor last logged for the level.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, PropertyValue};
+use crate::scan::ContentRoot;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MapIssueCategory {
+    MissingActorClass,
+    MissingReference,
+    NullReference,
+    LightingNeedsRebuild,
+    Other,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MapIssue {
+    pub category: MapIssueCategory,
+    pub severity: Severity,
+    /// `package` for findings from the .umap, `log` for the editor's own map check.
+    pub source: String,
+    pub actor: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MapIssues {
+    pub level: String,
+    pub issues: Vec<MapIssue>,
+    /// Editor log the `log` issues came from: the newest one that loaded this level.
+    pub log_file: Option<String>,
+}
+
+fn project_of(file_path: &Path) -> Option<PathBuf> {
+    file_path
+        .ancestors()
+        .find(|dir| crate::project::find_uproject(dir).is_some() || crate::uefn::find_uefnproject(dir).is_some())
+        .map(Path::to_path_buf)
+}
+
+/// `Some(exists)` for packages under one of `roots`, `None` for mounts we can't see.
+fn package_exists(roots: &[ContentRoot], package_path: &str) -> Option<bool> {
+    let root = roots.iter().find(|r| crate::index::is_in_folder(package_path, &r.mount))?;
+    let relative = package_path[root.mount.len()..].trim_start_matches('/');
+    let base = root.path.join(relative);
+    Some(["uasset", "umap"].iter().any(|ext| base.with_extension(ext).is_file()))
+}
+
+fn package_issue(category: MapIssueCategory, severity: Severity, actor: Option<String>, message: String) -> MapIssue {
+    MapIssue { category, severity, source: "package".to_string(), actor, message }
+}
+
+fn is_movable(props: &[properties::Property]) -> bool {
+    properties::find(props, "Mobility").and_then(|m| m.as_str()).is_some_and(|m| m.ends_with("Movable"))
+}
+
+fn inspect_package(file_path: &Path, package: &Package, roots: &[ContentRoot]) -> CodexResult<Vec<MapIssue>> {
+    let mut issues = Vec::new();
+    let Some(level) = crate::level::persistent_level(package) else {
+        return Err(CodexError::message("no_level", &[]));
+    };
+    let actors = crate::level::actors(package, level);
+
+    for &actor in &actors {
+        let export = &package.exports[actor];
+        let Some(class_path) = package.object_path(export.class_index).filter(|_| export.class_index < 0) else {
+            continue;
+        };
+        let class_package = crate::package::package_of_object_path(&class_path);
+        if package_exists(roots, class_package) == Some(false) {
+            issues.push(package_issue(
+                MapIssueCategory::MissingActorClass,
+                Severity::Error,
+                Some(export.object_name.clone()),
+                format!("Class {} no longer exists", class_path),
+            ));
+        }
+    }
+
+    for dep in package.import_packages().iter().filter(|p| !p.starts_with("/Script/")) {
+        if package_exists(roots, dep) == Some(false) {
+            issues.push(package_issue(
+                MapIssueCategory::MissingReference,
+                Severity::Error,
+                None,
+                format!("References missing package {}", dep),
+            ));
+        }
+    }
+
+    // Static mesh actors with no mesh; Blueprint components inherit theirs from the class.
+    let mut lights = 0;
+    let mut no_precomputed_lighting = false;
+    for export in &package.exports {
+        let class = package.export_class(export);
+        let outer = usize::try_from(export.outer_index - 1).ok().and_then(|o| package.exports.get(o));
+        let outer_class = outer.map(|o| package.export_class(o)).unwrap_or_default();
+        if class == "StaticMeshComponent" && outer_class == "StaticMeshActor" {
+            let props = properties::read_export_properties(file_path, package, export).unwrap_or_default();
+            let mesh = properties::find(&props, "StaticMesh");
+            if matches!(mesh, None | Some(PropertyValue::Object(None))) {
+                issues.push(package_issue(
+                    MapIssueCategory::NullReference,
+                    Severity::Warning,
+                    outer.map(|o| o.object_name.clone()),
+                    "Static mesh actor has NULL StaticMesh property".to_string(),
+                ));
+            }
+        } else if class.ends_with("LightComponent") && outer.is_some() {
+            let props = properties::read_export_properties(file_path, package, export).unwrap_or_default();
+            if !is_movable(&props) {
+                lights += 1;
+            }
+        } else if class == "WorldSettings" {
+            let props = properties::read_export_properties(file_path, package, export).unwrap_or_default();
+            no_precomputed_lighting =
+                properties::find(&props, "bForceNoPrecomputedLighting").and_then(|v| v.as_bool()).unwrap_or(false);
+        }
+    }
+
+    if lights > 0 && !no_precomputed_lighting {
+        let level_props = properties::read_export_properties(file_path, package, &package.exports[level])?;
+        let build_data = properties::find(&level_props, "MapBuildData")
+            .and_then(|v| v.as_str())
+            .map(crate::package::package_of_object_path);
+        let message = match build_data {
+            None => Some(format!("{} static or stationary lights but lighting has never been built", lights)),
+            Some(path) => {
+                (package_exists(roots, path) == Some(false)).then(|| format!("Built lighting data {} is missing", path))
+            }
+        };
+        if let Some(message) = message {
+            issues.push(package_issue(MapIssueCategory::LightingNeedsRebuild, Severity::Warning, None, message));
+        }
+    }
+    Ok(issues)
+}
+
+fn log_category(message: &str) -> MapIssueCategory {
+    let lower = message.to_lowercase();
+    if lower.contains("lighting needs to be rebuilt") || lower.contains("lighting build") {
+        MapIssueCategory::LightingNeedsRebuild
+    } else if lower.contains("null") || lower.contains("has no ") {
+        MapIssueCategory::NullReference
+    } else if lower.contains("failed to load") || lower.contains("can't find file") || lower.contains("missing") {
+        MapIssueCategory::MissingReference
+    } else {
+        MapIssueCategory::Other
+    }
+}
+
+fn log_issue(line: &str) -> Option<MapIssue> {
+    // `[2024.05.01-10.00.00:123][  0]MapCheck: Warning: ...`
+    let (severity, message) = if let Some(start) = line.find("MapCheck: ") {
+        let rest = &line[start + "MapCheck: ".len()..];
+        match rest.split_once(": ") {
+            Some(("Error", msg)) => (Severity::Error, msg),
+            Some(("Warning" | "PerfWarning", msg)) => (Severity::Warning, msg),
+            Some(("Info" | "Display", msg)) => (Severity::Info, msg),
+            _ => (Severity::Info, rest),
+        }
+    } else if let Some(start) = line.find("LogLinker: Warning: ") {
+        (Severity::Warning, &line[start + "LogLinker: Warning: ".len()..])
+    } else {
+        return None;
+    };
+    // The summary line carries no finding of its own.
+    if message.starts_with("Map check complete") {
+        return None;
+    }
+    Some(MapIssue {
+        category: log_category(message),
+        severity,
+        source: "log".to_string(),
+        actor: None,
+        message: message.trim().to_string(),
+    })
+}
+
+// Log lines don't say which map they belong to, so findings are attributed to the map
+// loaded last before them.
+fn issues_in_log(text: &str, package_name: &str) -> Option<Vec<MapIssue>> {
+    let package_name = package_name.to_lowercase();
+    let mut loaded = false;
+    let mut current = false;
+    let mut issues: Vec<MapIssue> = Vec::new();
+    for line in text.lines() {
+        if line.contains("LoadMap") || line.contains("Loading map") {
+            current = line.to_lowercase().contains(&package_name);
+            if current {
+                // A later load supersedes what an earlier one reported.
+                loaded = true;
+                issues.clear();
+            }
+            continue;
+        }
+        if !current {
+            continue;
+        }
+        if let Some(issue) = log_issue(line) {
+            if !issues.iter().any(|i| i.message == issue.message) {
+                issues.push(issue);
+            }
+        }
+    }
+    loaded.then_some(issues)
+}
+
+fn logged_issues(project: &Path, package_name: &str) -> Option<(PathBuf, Vec<MapIssue>)> {
+    let entries = std::fs::read_dir(project.join("Saved/Logs")).ok()?.filter_map(|e| e.ok());
+    let mut logs: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "log"))
+        .filter_map(|e| Some((e.metadata().ok()?.modified().ok()?, e.path())))
+        .collect();
+    logs.sort_by_key(|log| std::cmp::Reverse(log.0));
+    logs.into_iter().find_map(|(_, path)| {
+        let text = String::from_utf8_lossy(&std::fs::read(&path).ok()?).to_string();
+        issues_in_log(&text, package_name).map(|issues| (path, issues))
+    })
+}
+
+pub fn check(manager: &ScanManager, file_path: &Path) -> CodexResult<MapIssues> {
+    let package = Package::read_file(file_path)?;
+    let project = project_of(file_path);
+    let roots = project.as_deref().map(|p| manager.content_roots(p)).unwrap_or_default();
+    let mut issues = inspect_package(file_path, &package, &roots)?;
+
+    let mut log_file = None;
+    if let Some((path, logged)) = project.as_deref().and_then(|p| logged_issues(p, &package.summary.package_name)) {
+        log_file = Some(path.to_string_lossy().to_string());
+        issues.extend(logged);
+    }
+    Ok(MapIssues { level: package.summary.package_name.clone(), issues, log_file })
+}
+
+/// `level` is the .umap file path.
+#[tauri::command]
+pub fn get_map_issues(manager: State<ScanManager>, level: String) -> CodexResult<MapIssues> {
+    check(&manager, Path::new(&level))
+}
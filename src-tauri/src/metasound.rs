@@ -0,0 +1,82 @@
+// MetaSound sources and patches: the graph's interface (inputs and outputs) from the saved
+// frontend document, and the patches it instantiates.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use crate::properties::{self, PropertyValue};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MetaSoundVertex {
+    pub name: String,
+    pub type_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MetaSoundInfo {
+    pub class: String,
+    pub inputs: Vec<MetaSoundVertex>,
+    pub outputs: Vec<MetaSoundVertex>,
+    /// Interfaces the graph implements, e.g. `UE.Source.OneShot`.
+    pub interfaces: Vec<String>,
+    pub referenced_patches: Vec<String>,
+}
+
+fn vertices(interface: Option<&PropertyValue>, list: &str) -> Vec<MetaSoundVertex> {
+    match interface.and_then(|i| i.field(list)) {
+        Some(PropertyValue::Array(items)) => items
+            .iter()
+            .map(|v| MetaSoundVertex {
+                name: v.field("Name").and_then(|n| n.as_str()).unwrap_or_default().to_string(),
+                type_name: v.field("TypeName").and_then(|n| n.as_str()).unwrap_or_default().to_string(),
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+pub fn inspect(file_path: &Path) -> CodexResult<MetaSoundInfo> {
+    let package = Package::read_file(file_path)?;
+    let class = package.main_class().unwrap_or_default();
+    if class != "MetaSoundSource" && class != "MetaSoundPatch" {
+        return Err(CodexError::message("not_a_metasound", &[("class", &class)]));
+    }
+    let main = package.main_export().ok_or_else(|| CodexError::message("no_main_export", &[]))?;
+    let props = properties::read_export_properties(file_path, &package, main)?;
+
+    // The property was renamed from RootMetaSoundDocument in 5.0 to RootMetasoundDocument.
+    let document =
+        properties::find(&props, "RootMetasoundDocument").or_else(|| properties::find(&props, "RootMetaSoundDocument"));
+    let interface = document.and_then(|d| d.field("RootGraph")).and_then(|g| g.field("Interface"));
+    let interfaces = match document.and_then(|d| d.field("Interfaces")) {
+        Some(PropertyValue::Array(items)) => {
+            items.iter().filter_map(|i| i.field("Name").and_then(|n| n.as_str())).map(str::to_string).collect()
+        }
+        _ => Vec::new(),
+    };
+
+    // Patch nodes hard-reference the patch asset, so they show up in the import table.
+    let referenced_patches: BTreeSet<String> = package
+        .imports
+        .iter()
+        .enumerate()
+        .filter(|(_, import)| import.class_name == "MetaSoundPatch")
+        .filter_map(|(i, _)| package.object_path(-(i as i32) - 1))
+        .map(|p| crate::package::package_of_object_path(&p).to_string())
+        .collect();
+
+    Ok(MetaSoundInfo {
+        class,
+        inputs: vertices(interface, "Inputs"),
+        outputs: vertices(interface, "Outputs"),
+        interfaces,
+        referenced_patches: referenced_patches.into_iter().collect(),
+    })
+}
+
+#[tauri::command]
+pub fn get_metasound_info(file_path: String) -> CodexResult<MetaSoundInfo> {
+    inspect(Path::new(&file_path))
+}
@@ -0,0 +1,403 @@
+// The project's C++ classes, read from the UCLASS declarations in its headers (Source/ and
+// every plugin's Source/), and how Blueprints sit on top of them: for each Blueprint, whether
+// it derives directly from an engine class, a project class or another Blueprint. That split
+// is what a "move logic to C++" push tracks. The same index, checked against assets and
+// config, finds classes nothing outside C++ refers to.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Component, Path};
+use tauri::State;
+use walkdir::{DirEntry, WalkDir};
+
+// Generated and third-party code declares no project classes worth counting.
+const SKIPPED_DIRS: &[&str] = &["Intermediate", "Binaries", "ThirdParty"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NativeClass {
+    /// Reflected name, without the `A`/`U` prefix: `MyCharacter` for `AMyCharacter`.
+    pub name: String,
+    pub module: String,
+    /// Relative to the project folder.
+    pub header: String,
+    /// As written in the header, prefix included.
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ParentKind {
+    Engine,
+    Project,
+    Blueprint,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlueprintParent {
+    pub path: String,
+    /// Class name for native parents, package path for Blueprint parents.
+    pub parent: Option<String>,
+    pub kind: ParentKind,
+    /// First native class up the chain; the direct parent unless that's a Blueprint.
+    pub native_parent: Option<String>,
+    pub native_is_project: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ClassUsage {
+    pub class: String,
+    pub module: Option<String>,
+    /// Blueprints deriving directly from the class.
+    pub blueprints: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct NativeCoverage {
+    pub blueprint_count: usize,
+    pub engine_parents: usize,
+    pub project_parents: usize,
+    pub blueprint_parents: usize,
+    pub unknown_parents: usize,
+    /// Blueprints with a project class as their first native ancestor, directly or not.
+    pub project_native_ancestors: usize,
+    pub native_classes: Vec<NativeClass>,
+    /// Every project class, most derived-from first; unused ones have zero.
+    pub project_classes: Vec<ClassUsage>,
+    /// Engine classes Blueprints derive from directly, most derived-from first.
+    pub engine_classes: Vec<ClassUsage>,
+    pub blueprints: Vec<BlueprintParent>,
+}
+
+/// `AMyCharacter` -> `MyCharacter`; names without a type prefix stay as they are.
+fn reflected_name(name: &str) -> &str {
+    let mut chars = name.chars();
+    match (chars.next(), chars.next()) {
+        (Some('A' | 'U'), Some(c)) if c.is_ascii_uppercase() => &name[1..],
+        _ => name,
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// `MYGAME_API AMyCharacter final : public ACharacter, public IFoo` -> name and first base.
+fn parse_declaration(declaration: &str) -> Option<(String, Option<String>)> {
+    let (head, bases) = match declaration.split_once(':') {
+        Some((head, bases)) => (head, Some(bases)),
+        None => (declaration, None),
+    };
+    let name = head.split_whitespace().rfind(|t| *t != "final")?;
+    if !is_identifier(name) {
+        return None;
+    }
+    let parent = bases
+        .and_then(|b| b.split(',').next())
+        .and_then(|b| b.split_whitespace().find(|t| !matches!(*t, "public" | "protected" | "private" | "virtual")))
+        .map(|p| p.split('<').next().unwrap_or(p).trim_start_matches(':').to_string());
+    Some((name.to_string(), parent))
+}
+
+/// Classes declared with `UCLASS(...)` in a header, with their first base class.
+pub fn parse_header(text: &str) -> Vec<(String, Option<String>)> {
+    let mut classes = Vec::new();
+    let mut rest = text;
+    while let Some(at) = rest.find("UCLASS(") {
+        rest = &rest[at + "UCLASS(".len()..];
+        // Specifiers can nest, e.g. `meta=(BlueprintSpawnableComponent)`.
+        let mut depth = 1;
+        let mut end = rest.len();
+        for (i, c) in rest.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = i + 1;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        rest = &rest[end..];
+        let Some(class_at) = rest.find("class ") else {
+            break;
+        };
+        let declaration_end = rest[class_at..].find(['{', ';']).map(|e| class_at + e).unwrap_or(rest.len());
+        if let Some(class) = parse_declaration(&rest[class_at + "class ".len()..declaration_end]) {
+            classes.push(class);
+        }
+        rest = &rest[declaration_end..];
+    }
+    classes
+}
+
+/// Module folder: the first one under the nearest `Source` folder.
+fn module_of(relative: &Path) -> Option<String> {
+    let components: Vec<&str> = relative
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect();
+    let source = components.iter().rposition(|c| c.eq_ignore_ascii_case("Source"))?;
+    components.get(source + 1).filter(|_| source + 2 < components.len()).map(|m| m.to_string())
+}
+
+fn walk(root: &Path) -> impl Iterator<Item = DirEntry> {
+    WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| {
+            !(e.file_type().is_dir() && SKIPPED_DIRS.iter().any(|d| e.file_name().eq_ignore_ascii_case(d)))
+        })
+        .filter_map(|e| e.ok())
+}
+
+/// Every UCLASS in the project's and its plugins' source folders.
+pub fn index(project: &Path) -> Vec<NativeClass> {
+    let mut classes = Vec::new();
+    for root in [project.join("Source"), project.join("Plugins")] {
+        for entry in walk(&root) {
+            let path = entry.path();
+            if !path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("h")) {
+                continue;
+            }
+            let relative = path.strip_prefix(project).unwrap_or(path);
+            let (Some(module), Ok(text)) = (module_of(relative), std::fs::read_to_string(path)) else {
+                continue;
+            };
+            for (name, parent) in parse_header(&text) {
+                classes.push(NativeClass {
+                    name: reflected_name(&name).to_string(),
+                    module: module.clone(),
+                    header: relative.to_string_lossy().replace('\\', "/"),
+                    parent,
+                });
+            }
+        }
+    }
+    classes.sort_by(|a, b| a.module.cmp(&b.module).then_with(|| a.name.cmp(&b.name)));
+    classes
+}
+
+/// Reflected class -> reflected parent, for the project's classes.
+pub fn parent_map(classes: &[NativeClass]) -> HashMap<String, String> {
+    classes.iter().filter_map(|c| Some((c.name.clone(), reflected_name(c.parent.as_deref()?).to_string()))).collect()
+}
+
+/// `class` is `base` or derives from it through project classes; engine classes past the
+/// first one aren't known.
+pub fn derives_from(parents: &HashMap<String, String>, class: &str, base: &str) -> bool {
+    let mut current = class;
+    for _ in 0..32 {
+        if current == base {
+            return true;
+        }
+        match parents.get(current) {
+            Some(parent) => current = parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+fn is_blueprint(asset: &IndexedAsset) -> bool {
+    asset.class.as_deref().is_some_and(|c| c.ends_with("Blueprint")) && !crate::interfaces::is_interface(asset)
+}
+
+fn usage(counts: BTreeMap<String, usize>, modules: &HashMap<&str, &str>) -> Vec<ClassUsage> {
+    let mut usage: Vec<ClassUsage> = counts
+        .into_iter()
+        .map(|(class, blueprints)| {
+            let module = modules.get(class.as_str()).map(|m| m.to_string());
+            ClassUsage { class, module, blueprints }
+        })
+        .collect();
+    usage.sort_by(|a, b| b.blueprints.cmp(&a.blueprints).then_with(|| a.class.cmp(&b.class)));
+    usage
+}
+
+pub fn coverage(index: &AssetIndex, native_classes: Vec<NativeClass>) -> NativeCoverage {
+    let modules: HashMap<&str, &str> = native_classes.iter().map(|c| (c.name.as_str(), c.module.as_str())).collect();
+    let project_modules: BTreeSet<&str> = modules.values().copied().collect();
+    let mut report = NativeCoverage::default();
+    let mut project_counts: BTreeMap<String, usize> = modules.keys().map(|c| (c.to_string(), 0)).collect();
+    let mut engine_counts: BTreeMap<String, usize> = BTreeMap::new();
+    for asset in index.assets.iter().filter(|a| is_blueprint(a)) {
+        let parent_path = asset.tag("ParentClass").map(crate::package::strip_object_reference);
+        let (kind, parent) = match parent_path {
+            None => (ParentKind::Unknown, None),
+            Some(path) => match path.strip_prefix("/Script/") {
+                Some(script) => {
+                    let (module, class) = script.split_once('.').unwrap_or(("", script));
+                    if project_modules.contains(module) || modules.contains_key(class) {
+                        *project_counts.entry(class.to_string()).or_default() += 1;
+                        (ParentKind::Project, Some(class.to_string()))
+                    } else {
+                        *engine_counts.entry(class.to_string()).or_default() += 1;
+                        (ParentKind::Engine, Some(class.to_string()))
+                    }
+                }
+                None => (ParentKind::Blueprint, Some(crate::package::package_of_object_path(path).to_string())),
+            },
+        };
+        match kind {
+            ParentKind::Engine => report.engine_parents += 1,
+            ParentKind::Project => report.project_parents += 1,
+            ParentKind::Blueprint => report.blueprint_parents += 1,
+            ParentKind::Unknown => report.unknown_parents += 1,
+        }
+        let native_parent = crate::asset_manager::native_parent(index, asset).map(str::to_string);
+        let native_is_project = native_parent.as_deref().is_some_and(|n| modules.contains_key(n));
+        if native_is_project {
+            report.project_native_ancestors += 1;
+        }
+        report.blueprints.push(BlueprintParent {
+            path: asset.asset.path.clone(),
+            parent,
+            kind,
+            native_parent,
+            native_is_project,
+        });
+    }
+    report.blueprint_count = report.blueprints.len();
+    report.project_classes = usage(project_counts, &modules);
+    report.engine_classes = usage(engine_counts, &HashMap::new());
+    report.native_classes = native_classes;
+    report
+}
+
+#[tauri::command(async)]
+pub fn get_native_coverage(manager: State<ScanManager>, project: String) -> CodexResult<NativeCoverage> {
+    let index = manager.index(&project)?;
+    Ok(coverage(&index, self::index(Path::new(&project))))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UnreferencedNativeClasses {
+    pub class_count: usize,
+    pub unreferenced: Vec<NativeClass>,
+}
+
+/// `/Script/MyGame.MyLib:DoThing` -> `("MyGame", "MyLib")`; CDO names count as their class.
+fn script_class(path: &str) -> Option<(&str, &str)> {
+    let (module, object) = path.strip_prefix("/Script/")?.split_once('.')?;
+    let class = object.split([':', '.']).next().unwrap_or(object);
+    Some((module, class.strip_prefix("Default__").unwrap_or(class)))
+}
+
+/// `/Script/<module>.<class>` mentions in config text, e.g. `GameModeClass=` values and
+/// `[/Script/MyGame.MySettings]` sections.
+fn config_references(text: &str) -> Vec<(String, String)> {
+    let mut references = Vec::new();
+    let mut rest = text;
+    while let Some(at) = rest.find("/Script/") {
+        rest = &rest[at..];
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '/' | '.' | ':')))
+            .unwrap_or(rest.len());
+        if let Some((module, class)) = script_class(&rest[..end]) {
+            references.push((module.to_string(), class.to_string()));
+        }
+        rest = &rest[end.max(1)..];
+    }
+    references
+}
+
+fn config_files(project: &Path) -> Vec<std::path::PathBuf> {
+    let mut files = Vec::new();
+    for root in [project.join("Config"), project.join("Plugins")] {
+        for entry in walk(&root) {
+            let path = entry.path();
+            let in_config = path.parent().and_then(|p| p.file_name()).is_some_and(|n| n.eq_ignore_ascii_case("Config"));
+            if in_config && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("ini")) {
+                files.push(path.to_path_buf());
+            }
+        }
+    }
+    files
+}
+
+/// Project classes nothing outside C++ refers to: no asset derives from, instances or imports
+/// them, and no config file names them. A class whose subclass is referenced counts as
+/// referenced. Classes only used from C++ (spawned or created in code) show up here too, so
+/// the list is a starting point for pruning rather than a delete list.
+pub fn find_unreferenced(
+    project: &Path,
+    index: &AssetIndex,
+    native_classes: Vec<NativeClass>,
+) -> UnreferencedNativeClasses {
+    let modules: BTreeSet<&str> = native_classes.iter().map(|c| c.module.as_str()).collect();
+    let names: BTreeSet<&str> = native_classes.iter().map(|c| c.name.as_str()).collect();
+    let mut referenced: BTreeSet<String> = BTreeSet::new();
+    let mut mark = |module: &str, class: &str| {
+        if names.contains(class) && (module.is_empty() || modules.contains(module)) {
+            referenced.insert(class.to_string());
+        }
+    };
+
+    for asset in &index.assets {
+        if let Some(class) = &asset.class {
+            mark("", class);
+        }
+        for tag in ["ParentClass", "NativeParentClass"] {
+            if let Some((module, class)) =
+                asset.tag(tag).and_then(|t| script_class(crate::package::strip_object_reference(t)))
+            {
+                mark(module, class);
+            }
+        }
+        if !asset.asset.is_package() {
+            continue;
+        }
+        let Ok(package) = crate::package::Package::read_file(Path::new(&asset.asset.file_path)) else {
+            continue;
+        };
+        for i in 0..package.imports.len() {
+            if let Some(path) = package.object_path(-(i as i32) - 1) {
+                if let Some((module, class)) = script_class(&path) {
+                    mark(module, class);
+                }
+            }
+        }
+    }
+    for file in config_files(project) {
+        let text = std::fs::read_to_string(&file).unwrap_or_default();
+        for (module, class) in config_references(&text) {
+            mark(&module, &class);
+        }
+    }
+
+    // Native bases of referenced classes are in use through them.
+    let parents: HashMap<&str, &str> =
+        native_classes.iter().filter_map(|c| Some((c.name.as_str(), reflected_name(c.parent.as_deref()?)))).collect();
+    for class in referenced.clone() {
+        let mut current = class.as_str();
+        while let Some(parent) = parents.get(current).copied().filter(|p| names.contains(p)) {
+            if !referenced.insert(parent.to_string()) {
+                break;
+            }
+            current = parent;
+        }
+    }
+
+    let class_count = native_classes.len();
+    let unreferenced = native_classes.into_iter().filter(|c| !referenced.contains(&c.name)).collect();
+    UnreferencedNativeClasses { class_count, unreferenced }
+}
+
+#[tauri::command(async)]
+pub fn find_unreferenced_native_classes(
+    manager: State<ScanManager>,
+    project: String,
+) -> CodexResult<UnreferencedNativeClasses> {
+    let index = manager.index(&project)?;
+    let project = Path::new(&project);
+    Ok(find_unreferenced(project, &index, self::index(project)))
+}
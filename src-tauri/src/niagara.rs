@@ -0,0 +1,183 @@
+// Niagara inspection: the emitters a system is built from, its exposed user parameters
+// and the module scripts its stacks call.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NiagaraEmitterInfo {
+    pub name: String,
+    pub enabled: bool,
+    /// Object path of the emitter instance, usually a subobject of the system.
+    pub emitter: Option<String>,
+    /// The emitter asset this one inherits from, if any.
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NiagaraInfo {
+    pub class: String,
+    pub emitters: Vec<NiagaraEmitterInfo>,
+    /// Exposed parameters without the `User.` namespace.
+    pub user_parameters: Vec<String>,
+    pub modules: Vec<String>,
+}
+
+pub fn inspect(file_path: &Path) -> CodexResult<NiagaraInfo> {
+    let package = Package::read_file(file_path)?;
+    let class = package.main_class().unwrap_or_default();
+    if class != "NiagaraSystem" && class != "NiagaraEmitter" {
+        return Err(CodexError::message("not_a_niagara_asset", &[("class", &class)]));
+    }
+    let main = package.main_export().ok_or_else(|| CodexError::message("no_main_export", &[]))?;
+    let props = properties::read_export_properties(file_path, &package, main)?;
+
+    let mut emitters = Vec::new();
+    if class == "NiagaraSystem" {
+        if let Some(PropertyValue::Array(handles)) = properties::find(&props, "EmitterHandles") {
+            for handle in handles {
+                let emitter = emitter_instance(handle).map(str::to_string);
+                let parent = emitter
+                    .as_deref()
+                    .and_then(|path| package.export_by_path(path))
+                    .and_then(|export| properties::read_export_properties(file_path, &package, export).ok())
+                    .and_then(|p| emitter_parent(&p));
+                emitters.push(NiagaraEmitterInfo {
+                    name: handle.field("Name").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                    enabled: handle.field("bIsEnabled").and_then(|v| v.as_bool()).unwrap_or(true),
+                    emitter,
+                    parent,
+                });
+            }
+        }
+    } else {
+        emitters.push(NiagaraEmitterInfo {
+            name: main.object_name.clone(),
+            enabled: true,
+            emitter: Some(format!("{}.{}", package.summary.package_name, main.object_name)),
+            parent: emitter_parent(&props),
+        });
+    }
+
+    let mut user_parameters = BTreeSet::new();
+    if let Some(exposed) = properties::find(&props, "ExposedParameters") {
+        collect_user_parameters(exposed, &mut user_parameters);
+    }
+
+    // Modules live in other packages (engine or project), so they show up as script imports.
+    let modules: BTreeSet<String> = package
+        .imports
+        .iter()
+        .enumerate()
+        .filter(|(_, import)| import.class_name == "NiagaraScript")
+        .filter_map(|(i, _)| package.object_path(-(i as i32) - 1))
+        .collect();
+
+    Ok(NiagaraInfo {
+        class,
+        emitters,
+        user_parameters: user_parameters.into_iter().collect(),
+        modules: modules.into_iter().collect(),
+    })
+}
+
+/// The emitter object of a system's emitter handle. UE 5.1+ saves it under `VersionedInstance`
+/// as `(Emitter=..., Version=...)`; UE4 and 5.0 store the object directly as `Instance`.
+fn emitter_instance(handle: &PropertyValue) -> Option<&str> {
+    handle
+        .field("VersionedInstance")
+        .and_then(|versioned| versioned.field("Emitter"))
+        .and_then(first_object)
+        .or_else(|| handle.field("Instance").and_then(first_object))
+}
+
+fn emitter_parent(props: &[Property]) -> Option<String> {
+    properties::find(props, "VersionedParent")
+        .or_else(|| properties::find(props, "Parent"))
+        .and_then(first_object)
+        .map(|p| crate::package::package_of_object_path(p).to_string())
+}
+
+fn first_object(value: &PropertyValue) -> Option<&str> {
+    match value {
+        PropertyValue::Object(Some(path)) => Some(path.as_str()),
+        PropertyValue::Struct { properties, .. } => properties.iter().find_map(|p| first_object(&p.value)),
+        _ => None,
+    }
+}
+
+fn collect_user_parameters(value: &PropertyValue, out: &mut BTreeSet<String>) {
+    match value {
+        PropertyValue::Name(name) | PropertyValue::Str(name) => {
+            if let Some(parameter) = name.strip_prefix("User.") {
+                out.insert(parameter.to_string());
+            }
+        }
+        PropertyValue::Struct { properties, .. } => {
+            for p in properties {
+                collect_user_parameters(&p.value, out);
+            }
+        }
+        PropertyValue::Array(items) => {
+            for item in items {
+                collect_user_parameters(item, out);
+            }
+        }
+        PropertyValue::Map(entries) => {
+            for (k, v) in entries {
+                collect_user_parameters(k, out);
+                collect_user_parameters(v, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[tauri::command]
+pub fn get_niagara_info(file_path: String) -> CodexResult<NiagaraInfo> {
+    inspect(Path::new(&file_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(name: &str, value: PropertyValue) -> Property {
+        Property { name: name.to_string(), type_name: String::new(), array_index: 0, value }
+    }
+
+    fn handle(fields: Vec<Property>) -> PropertyValue {
+        PropertyValue::Struct { struct_type: "NiagaraEmitterHandle".to_string(), properties: fields }
+    }
+
+    #[test]
+    fn reads_ue5_versioned_instance() {
+        let emitter = "/Game/FX/NS_Fire.NS_Fire:Sparks";
+        let versioned = PropertyValue::Struct {
+            struct_type: "VersionedNiagaraEmitter".to_string(),
+            properties: vec![
+                property("Emitter", PropertyValue::Object(Some(emitter.to_string()))),
+                property("Version", PropertyValue::Guid("00000000000000000000000000000000".to_string())),
+            ],
+        };
+        // 5.1+ still writes an empty `Instance` alongside the versioned one.
+        let ue5 = handle(vec![
+            property("Name", PropertyValue::Name("Sparks".to_string())),
+            property("Instance", PropertyValue::Object(None)),
+            property("VersionedInstance", versioned),
+        ]);
+        assert_eq!(emitter_instance(&ue5), Some(emitter));
+    }
+
+    #[test]
+    fn falls_back_to_ue4_instance() {
+        let emitter = "/Game/FX/P_Smoke.P_Smoke:Smoke";
+        let ue4 = handle(vec![property("Instance", PropertyValue::Object(Some(emitter.to_string())))]);
+        assert_eq!(emitter_instance(&ue4), Some(emitter));
+        assert_eq!(emitter_instance(&handle(Vec::new())), None);
+    }
+}
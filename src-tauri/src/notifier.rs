@@ -0,0 +1,154 @@
+// Audit regressions posted to a Slack or Discord webhook. After a background rescan that
+// changed something, the audits run again and anything not in the previous snapshot (new
+// broken references, new large assets, newly damaged packages) is summarised in one message.
+
+use crate::codex_db::{AuditSnapshot, CodexDb, Notifier};
+use crate::error::{CodexError, CodexResult};
+use crate::http::Request;
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::time::SystemTime;
+use tauri::{AppHandle, Manager, Runtime};
+
+// Per category; the rest is counted.
+const MAX_LISTED: usize = 10;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AuditRegression {
+    pub project: String,
+    pub broken_references: Vec<String>,
+    pub large_assets: Vec<String>,
+    pub corrupt_assets: Vec<String>,
+}
+
+impl AuditRegression {
+    pub fn is_empty(&self) -> bool {
+        self.broken_references.is_empty() && self.large_assets.is_empty() && self.corrupt_assets.is_empty()
+    }
+}
+
+fn is_discord(url: &str) -> bool {
+    url.contains("discord.com/api/webhooks") || url.contains("discordapp.com/api/webhooks")
+}
+
+/// Only genuinely damaged packages count: files held open by the editor or in a layout the
+/// reader doesn't parse are left out. A package that was damaged last time and is locked now
+/// stays in the set, so it isn't announced again once the editor lets go.
+pub fn snapshot(manager: &ScanManager, project: &str, previous: Option<&AuditSnapshot>) -> CodexResult<AuditSnapshot> {
+    let index = manager.index(project)?;
+    let assets = manager.assets(project)?;
+    let large_assets = assets.iter().filter(|a| a.is_large).map(|a| a.path.clone()).collect();
+    let integrity = crate::integrity::check(assets);
+    let still_locked =
+        integrity.locked.into_iter().filter(|path| previous.is_some_and(|p| p.corrupt_assets.contains(path)));
+    Ok(AuditSnapshot {
+        timestamp: crate::scan::unix_seconds(SystemTime::now()),
+        broken_references: crate::broken_refs::find(&index)
+            .broken
+            .into_iter()
+            .map(|b| format!("{} -> {}", b.referencer, b.missing))
+            .collect(),
+        large_assets,
+        corrupt_assets: integrity.corrupt.into_iter().map(|c| c.path).chain(still_locked).collect(),
+    })
+}
+
+fn new_entries(previous: &BTreeSet<String>, current: &BTreeSet<String>) -> Vec<String> {
+    current.difference(previous).cloned().collect()
+}
+
+pub fn compare(project: &str, previous: &AuditSnapshot, current: &AuditSnapshot) -> AuditRegression {
+    AuditRegression {
+        project: project.to_string(),
+        broken_references: new_entries(&previous.broken_references, &current.broken_references),
+        large_assets: new_entries(&previous.large_assets, &current.large_assets),
+        corrupt_assets: new_entries(&previous.corrupt_assets, &current.corrupt_assets),
+    }
+}
+
+fn summary(regression: &AuditRegression) -> String {
+    let name = Path::new(&regression.project).file_name().map(|n| n.to_string_lossy().to_string());
+    let mut text = format!("Blueprint Codex: new audit findings in {}", name.unwrap_or_default());
+    let sections = [
+        ("broken reference", &regression.broken_references),
+        ("large asset", &regression.large_assets),
+        ("damaged package", &regression.corrupt_assets),
+    ];
+    for (label, entries) in sections.into_iter().filter(|(_, e)| !e.is_empty()) {
+        let plural = if entries.len() == 1 { "" } else { "s" };
+        text.push_str(&format!("\n\n{} new {}{}:", entries.len(), label, plural));
+        for entry in entries.iter().take(MAX_LISTED) {
+            text.push_str(&format!("\n• {}", entry));
+        }
+        if entries.len() > MAX_LISTED {
+            text.push_str(&format!("\n…and {} more", entries.len() - MAX_LISTED));
+        }
+    }
+    text
+}
+
+pub fn post(notifier: &Notifier, text: &str) -> CodexResult<()> {
+    // Discord rejects content over 2000 characters.
+    let body = if is_discord(&notifier.webhook_url) {
+        serde_json::json!({ "content": text.chars().take(2000).collect::<String>() })
+    } else {
+        serde_json::json!({ "text": text })
+    };
+    Request::post_json(&notifier.webhook_url, &body).send()?.ok(&notifier.webhook_url)?;
+    Ok(())
+}
+
+/// Called by the scheduler after a rescan that changed `project`. The first run only records
+/// a baseline; findings that were already there when notifications were set up aren't news.
+pub fn after_rescan<R: Runtime>(app: &AppHandle<R>, project: &str) -> CodexResult<()> {
+    let db = CodexDb::read(Path::new(project))?;
+    let Some(notifier) = db.notifier.filter(|n| n.enabled) else {
+        return Ok(());
+    };
+    let current = snapshot(&app.state::<ScanManager>(), project, db.audit_snapshot.as_ref())?;
+    let regression = db.audit_snapshot.as_ref().map(|previous| compare(project, previous, &current));
+    CodexDb::update(Path::new(project), |db| {
+        db.audit_snapshot = Some(current);
+        Ok(())
+    })?;
+    match regression.filter(|r| !r.is_empty()) {
+        Some(regression) => {
+            let _ = app.emit_all("audit-regression", regression.clone());
+            post(&notifier, &summary(&regression))
+        }
+        None => Ok(()),
+    }
+}
+
+#[tauri::command]
+pub fn get_notifier(project: String) -> CodexResult<Option<Notifier>> {
+    Ok(CodexDb::read(Path::new(&project))?.notifier)
+}
+
+/// An empty `webhook_url` removes the notifier. Changing it starts over from a fresh baseline.
+#[tauri::command]
+pub fn set_notifier(project: String, webhook_url: String, enabled: Option<bool>) -> CodexResult<Option<Notifier>> {
+    let webhook_url = webhook_url.trim().to_string();
+    if !webhook_url.is_empty() && !webhook_url.starts_with("https://") {
+        return Err(CodexError::message("invalid_url", &[("url", &webhook_url)]));
+    }
+    CodexDb::update(Path::new(&project), |db| {
+        if db.notifier.as_ref().map(|n| &n.webhook_url) != Some(&webhook_url) {
+            db.audit_snapshot = None;
+        }
+        db.notifier = Some(webhook_url)
+            .filter(|url| !url.is_empty())
+            .map(|webhook_url| Notifier { webhook_url, enabled: enabled.unwrap_or(true) });
+        Ok(db.notifier.clone())
+    })
+}
+
+/// Posts a test message to the configured webhook.
+#[tauri::command(async)]
+pub fn test_notifier(project: String) -> CodexResult<()> {
+    let notifier =
+        CodexDb::read(Path::new(&project))?.notifier.ok_or_else(|| CodexError::message("no_notifier", &[]))?;
+    post(&notifier, "Blueprint Codex: webhook notifications are set up for this project.")
+}
@@ -0,0 +1,92 @@
+// Asset owners from a CODEOWNERS-style `.codexowners` file in the project root:
+//
+//     # pattern              owners
+//     /Game/Characters/**    @characters
+//     /Game/UI/              @ui @ux
+//
+// Patterns are globs over package paths; a trailing `/` means the whole folder. As with
+// CODEOWNERS, the last matching line wins.
+
+use crate::error::{CodexError, CodexResult};
+use globset::{GlobBuilder, GlobMatcher};
+use serde::Serialize;
+use std::path::Path;
+
+pub const OWNERS_FILE: &str = ".codexowners";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetOwner {
+    pub path: String,
+    pub owners: Vec<String>,
+    /// The pattern that matched, as written in the file.
+    pub rule: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Ownership {
+    rules: Vec<(String, GlobMatcher, Vec<String>)>,
+}
+
+impl Ownership {
+    pub fn load(project: &Path) -> CodexResult<Ownership> {
+        match std::fs::read_to_string(project.join(OWNERS_FILE)) {
+            Ok(text) => Ownership::parse(&text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Ownership::default()),
+            Err(e) => Err(CodexError::io(&project.join(OWNERS_FILE), &e)),
+        }
+    }
+
+    pub fn parse(text: &str) -> CodexResult<Ownership> {
+        let mut rules = Vec::new();
+        for (line_number, raw) in text.lines().enumerate() {
+            let line = raw.split('#').next().unwrap_or("").trim();
+            let mut parts = line.split_whitespace();
+            let Some(pattern) = parts.next() else {
+                continue;
+            };
+            let glob = if pattern.ends_with('/') { format!("{}**", pattern) } else { pattern.to_string() };
+            // `*` stays within one folder, like in CODEOWNERS; `**` crosses folders.
+            let matcher = GlobBuilder::new(&glob.to_lowercase())
+                .literal_separator(true)
+                .build()
+                .map_err(|e| {
+                    CodexError::message("invalid_owners_rule", &[("line", &(line_number + 1)), ("message", &e)])
+                })?
+                .compile_matcher();
+            rules.push((pattern.to_string(), matcher, parts.map(str::to_string).collect()));
+        }
+        Ok(Ownership { rules })
+    }
+
+    /// Package paths are case-insensitive, so patterns and paths are compared lowercased.
+    pub fn owner_of(&self, ue_path: &str) -> AssetOwner {
+        let package = crate::package::package_of_object_path(ue_path);
+        let key = package.to_lowercase();
+        let rule = self.rules.iter().rev().find(|(_, matcher, _)| matcher.is_match(&key));
+        AssetOwner {
+            path: package.to_string(),
+            owners: rule.map(|(_, _, owners)| owners.clone()).unwrap_or_default(),
+            rule: rule.map(|(pattern, _, _)| pattern.clone()),
+        }
+    }
+
+    /// Owners joined for a single report column, e.g. `@ui @ux`.
+    pub fn owner_label(&self, ue_path: &str) -> Option<String> {
+        let owner = self.owner_of(ue_path);
+        (!owner.owners.is_empty()).then(|| owner.owners.join(" "))
+    }
+}
+
+/// Fills in `owner` on scanned assets. A malformed owners file leaves them unassigned
+/// rather than failing the scan; `get_asset_owner` reports the error.
+pub fn assign_owners(project: &Path, assets: &mut [crate::scan::UnrealAsset]) {
+    let ownership = Ownership::load(project).unwrap_or_default();
+    for asset in assets {
+        asset.owner = ownership.owner_label(&asset.path);
+    }
+}
+
+#[tauri::command]
+pub fn get_asset_owner(project: String, path: String) -> CodexResult<AssetOwner> {
+    Ok(Ownership::load(Path::new(&project))?.owner_of(&path))
+}
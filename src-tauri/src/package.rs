@@ -0,0 +1,892 @@
+// Minimal reader for the header of editor-saved .uasset/.umap packages
+// (FPackageFileSummary, name/import/export tables and asset registry tags).
+
+use crate::error::{CodexError, CodexResult};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+pub const PACKAGE_FILE_TAG: u32 = 0x9E2A83C1;
+pub const PACKAGE_FILE_TAG_SWAPPED: u32 = 0xC1832A9E;
+
+pub const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+pub const PKG_UNVERSIONED_PROPERTIES: u32 = 0x0000_2000;
+
+// EUnrealEngineObjectUE4Version
+pub const VER_UE4_WORLD_LEVEL_INFO: i32 = 224;
+pub const VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE: i32 = 278;
+pub const VER_UE4_ARRAY_PROPERTY_INNER_TAGS: i32 = 282;
+pub const VER_UE4_CHANGED_CHUNKID_TO_BE_AN_ARRAY_OF_CHUNKIDS: i32 = 326;
+pub const VER_UE4_ENGINE_VERSION_OBJECT: i32 = 336;
+pub const VER_UE4_LOAD_FOR_EDITOR_GAME: i32 = 365;
+pub const VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP: i32 = 384;
+pub const VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG: i32 = 441;
+pub const VER_UE4_PACKAGE_SUMMARY_HAS_COMPATIBLE_ENGINE_VERSION: i32 = 444;
+pub const VER_UE4_SERIALIZE_TEXT_IN_PACKAGES: i32 = 459;
+pub const VER_UE4_ASSET_IMPORT_DATA_AS_JSON: i32 = 464;
+pub const VER_UE4_COOKED_ASSETS_IN_EDITOR_SUPPORT: i32 = 485;
+pub const VER_UE4_INNER_ARRAY_TAG_INFO: i32 = 500;
+pub const VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG: i32 = 503;
+pub const VER_UE4_NAME_HASHES_SERIALIZED: i32 = 504;
+pub const VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS: i32 = 507;
+pub const VER_UE4_TEMPLATE_INDEX_IN_COOKED_EXPORTS: i32 = 508;
+pub const VER_UE4_PROPERTY_TAG_SET_MAP_SUPPORT: i32 = 509;
+pub const VER_UE4_ADDED_SEARCHABLE_NAMES: i32 = 510;
+pub const VER_UE4_64BIT_EXPORTMAP_SERIALSIZES: i32 = 511;
+pub const VER_UE4_ADDED_SOFT_OBJECT_PATH: i32 = 514;
+pub const VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID: i32 = 516;
+pub const VER_UE4_ADDED_PACKAGE_OWNER: i32 = 518;
+pub const VER_UE4_NON_OUTER_PACKAGE_IMPORT: i32 = 520;
+pub const VER_UE4_ASSETREGISTRY_DEPENDENCYFLAGS: i32 = 521;
+
+// EUnrealEngineObjectUE5Version
+pub const VER_UE5_OPTIONAL_RESOURCES: i32 = 1003;
+pub const VER_UE5_LARGE_WORLD_COORDINATES: i32 = 1004;
+pub const VER_UE5_REMOVE_OBJECT_EXPORT_PACKAGE_GUID: i32 = 1005;
+pub const VER_UE5_TRACK_OBJECT_EXPORT_IS_INHERITED: i32 = 1006;
+pub const VER_UE5_FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES: i32 = 1007;
+pub const VER_UE5_ADD_SOFTOBJECTPATH_LIST: i32 = 1008;
+pub const VER_UE5_SCRIPT_SERIALIZATION_OFFSET: i32 = 1010;
+pub const VER_UE5_PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION: i32 = 1011;
+pub const VER_UE5_PROPERTY_TAG_COMPLETE_TYPE_NAME: i32 = 1012;
+pub const VER_UE5_METADATA_SERIALIZATION_OFFSET: i32 = 1014;
+pub const VER_UE5_VERSE_CELLS: i32 = 1015;
+pub const VER_UE5_PACKAGE_SAVED_HASH: i32 = 1016;
+// Newest layout this reader knows about; newer packages are rejected rather than misread.
+pub const VER_UE5_NEWEST_SUPPORTED: i32 = 1017;
+
+/// Parse error for a read past the end of the file.
+pub const UNEXPECTED_EOF: &str = "Unexpected end of package";
+
+// Guards against garbage lengths in damaged files.
+const MAX_STRING_LEN: i32 = 1 << 16;
+const MAX_TABLE_LEN: i32 = 1 << 21;
+
+/// Valid packages this reader declines to parse, as opposed to damaged ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsupportedLayout {
+    ByteSwapped,
+    LegacyVersion,
+    /// Cooked without version numbers.
+    Unversioned,
+    /// Saved by an engine newer than `VER_UE5_NEWEST_SUPPORTED`.
+    TooNew,
+    Compressed,
+}
+
+impl UnsupportedLayout {
+    pub fn code(self) -> &'static str {
+        match self {
+            UnsupportedLayout::ByteSwapped => "byte_swapped",
+            UnsupportedLayout::LegacyVersion => "legacy_version",
+            UnsupportedLayout::Unversioned => "unversioned",
+            UnsupportedLayout::TooNew => "too_new",
+            UnsupportedLayout::Compressed => "compressed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EngineVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+    pub changelist: u32,
+    pub branch: String,
+}
+
+impl std::fmt::Display for EngineVersion {
+    // Same shape as FEngineVersion::ToString, e.g. `5.3.2-29314046+++UE5+Release-5.3`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if self.changelist != 0 {
+            write!(f, "-{}", self.changelist)?;
+        }
+        if !self.branch.is_empty() {
+            write!(f, "+{}", self.branch)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PackageSummary {
+    pub legacy_file_version: i32,
+    pub file_version_ue4: i32,
+    pub file_version_ue5: i32,
+    pub file_version_licensee: i32,
+    pub total_header_size: i32,
+    pub package_name: String,
+    pub package_flags: u32,
+    pub name_count: i32,
+    pub name_offset: i32,
+    pub soft_object_paths_count: i32,
+    pub soft_object_paths_offset: i32,
+    pub gatherable_text_data_count: i32,
+    pub gatherable_text_data_offset: i32,
+    pub export_count: i32,
+    pub export_offset: i32,
+    pub import_count: i32,
+    pub import_offset: i32,
+    pub depends_offset: i32,
+    pub soft_package_references_count: i32,
+    pub soft_package_references_offset: i32,
+    pub searchable_names_offset: i32,
+    pub thumbnail_table_offset: i32,
+    pub saved_by_engine_version: EngineVersion,
+    pub compatible_with_engine_version: EngineVersion,
+    pub asset_registry_data_offset: i32,
+    pub bulk_data_start_offset: i64,
+    pub chunk_ids: Vec<i32>,
+}
+
+impl PackageSummary {
+    pub fn is_filter_editor_only(&self) -> bool {
+        self.package_flags & PKG_FILTER_EDITOR_ONLY != 0
+    }
+
+    pub fn is_unversioned(&self) -> bool {
+        self.file_version_ue4 == 0 && self.file_version_ue5 == 0
+    }
+}
+
+/// FName as stored in tables: index into the name map plus instance number.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NameRef {
+    pub index: i32,
+    pub number: i32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectImport {
+    pub class_package: String,
+    pub class_name: String,
+    pub outer_index: i32,
+    pub object_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ObjectExport {
+    pub class_index: i32,
+    pub super_index: i32,
+    pub outer_index: i32,
+    pub object_name: String,
+    pub object_flags: u32,
+    pub serial_size: i64,
+    pub serial_offset: i64,
+    pub is_asset: bool,
+    pub script_serialization_start: i64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetRegistryObject {
+    pub object_path: String,
+    pub object_class: String,
+    pub tags: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Package {
+    pub summary: PackageSummary,
+    pub names: Vec<String>,
+    pub imports: Vec<ObjectImport>,
+    pub exports: Vec<ObjectExport>,
+    pub soft_package_references: Vec<String>,
+    pub soft_object_paths: Vec<String>,
+    pub asset_registry: Vec<AssetRegistryObject>,
+}
+
+impl Package {
+    pub fn read_file(path: &Path) -> CodexResult<Package> {
+        let file = crate::file_lock::open(path).map_err(|e| CodexError::io(path, &e))?;
+        Package::read(&mut BufReader::new(file)).map_err(|e| CodexError::parse(path, e))
+    }
+
+    /// Why `read_file` refused a package that isn't damaged, if that's what happened. Reads
+    /// the summary again, so only worth calling after a failed read.
+    pub fn unsupported_layout(path: &Path) -> Option<UnsupportedLayout> {
+        let file = crate::file_lock::open(path).ok()?;
+        let mut reader = BufReader::new(file);
+        let mut ar = Archive::new(&mut reader);
+        ar.read_summary().err()?;
+        ar.unsupported
+    }
+
+    /// The summary only as far as the engine versions, which is all it takes to tell which
+    /// engine saved a package. Unlike `read_file` this accepts packages newer than the reader.
+    pub fn read_saved_versions(path: &Path) -> CodexResult<PackageSummary> {
+        let file = crate::file_lock::open(path).map_err(|e| CodexError::io(path, &e))?;
+        let mut reader = BufReader::new(file);
+        Archive::new(&mut reader).read_summary_head(false).map_err(|e| CodexError::parse(path, e))
+    }
+
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Package, String> {
+        let mut ar = Archive::new(reader);
+        let mut package = Package::read_object_tables(&mut ar)?;
+
+        if package.summary.soft_package_references_count > 0 && package.summary.soft_package_references_offset > 0 {
+            ar.seek(package.summary.soft_package_references_offset as u64)?;
+            for _ in 0..checked_len(package.summary.soft_package_references_count)? {
+                let reference = if package.summary.file_version_ue4 >= VER_UE4_ADDED_SOFT_OBJECT_PATH {
+                    let name = ar.read_name_ref()?;
+                    package.name(name)
+                } else {
+                    ar.read_fstring()?
+                };
+                package.soft_package_references.push(reference);
+            }
+        }
+
+        if package.summary.soft_object_paths_count > 0 && package.summary.soft_object_paths_offset > 0 {
+            ar.seek(package.summary.soft_object_paths_offset as u64)?;
+            for _ in 0..checked_len(package.summary.soft_object_paths_count)? {
+                let path = ar.read_soft_object_path_inline(&package)?;
+                package.soft_object_paths.push(path);
+            }
+        }
+
+        if package.summary.asset_registry_data_offset > 0 {
+            // Registry tags are a nice-to-have; a package with a damaged tag block is still usable.
+            if let Ok(objects) = ar.read_asset_registry(&package.summary) {
+                package.asset_registry = objects;
+            }
+        }
+
+        Ok(package)
+    }
+
+    /// Summary, names, imports and exports: enough to name objects and their classes. Used on
+    /// its own where the soft references and registry tags `read` adds aren't needed.
+    fn read_object_tables<R: Read + Seek>(ar: &mut Archive<'_, R>) -> Result<Package, String> {
+        let summary = ar.read_summary()?;
+        ar.file_version_ue4 = summary.file_version_ue4;
+        ar.file_version_ue5 = summary.file_version_ue5;
+
+        let mut package = Package {
+            summary,
+            ..Default::default()
+        };
+
+        ar.seek(package.summary.name_offset as u64)?;
+        let name_count = checked_len(package.summary.name_count)?;
+        package.names.reserve(name_count);
+        for _ in 0..name_count {
+            package.names.push(ar.read_fstring()?);
+            if package.summary.file_version_ue4 >= VER_UE4_NAME_HASHES_SERIALIZED {
+                ar.skip(4)?;
+            }
+        }
+
+        if package.summary.import_count > 0 {
+            ar.seek(package.summary.import_offset as u64)?;
+            for _ in 0..checked_len(package.summary.import_count)? {
+                let import = ar.read_import(&package)?;
+                package.imports.push(import);
+            }
+        }
+
+        if package.summary.export_count > 0 {
+            ar.seek(package.summary.export_offset as u64)?;
+            for _ in 0..checked_len(package.summary.export_count)? {
+                let export = ar.read_export(&package)?;
+                package.exports.push(export);
+            }
+        }
+
+        Ok(package)
+    }
+
+    /// Class of the package's main export, read from the object tables alone.
+    pub fn read_main_class<R: Read + Seek>(reader: &mut R) -> Result<Option<String>, String> {
+        let package = Package::read_object_tables(&mut Archive::new(reader))?;
+        Ok(package.main_export().map(|e| package.export_class(e)))
+    }
+
+    pub fn name(&self, name: NameRef) -> String {
+        let base = usize::try_from(name.index)
+            .ok()
+            .and_then(|i| self.names.get(i))
+            .cloned()
+            .unwrap_or_default();
+        if name.number > 0 {
+            format!("{}_{}", base, name.number - 1)
+        } else {
+            base
+        }
+    }
+
+    /// Short object name for an FPackageIndex (positive = export, negative = import).
+    pub fn object_name(&self, index: i32) -> Option<&str> {
+        if index > 0 {
+            self.exports.get(index as usize - 1).map(|e| e.object_name.as_str())
+        } else if index < 0 {
+            self.imports.get((-index) as usize - 1).map(|i| i.object_name.as_str())
+        } else {
+            None
+        }
+    }
+
+    /// Full object path for an FPackageIndex, e.g. `/Script/Engine.StaticMesh`.
+    pub fn object_path(&self, index: i32) -> Option<String> {
+        let mut parts = Vec::new();
+        let mut current = index;
+        // Outer chains are shallow; the bound only protects against cycles in corrupt data.
+        for _ in 0..32 {
+            if current == 0 {
+                break;
+            }
+            let (name, outer) = if current > 0 {
+                let export = self.exports.get(current as usize - 1)?;
+                (export.object_name.as_str(), export.outer_index)
+            } else {
+                let import = self.imports.get((-current) as usize - 1)?;
+                (import.object_name.as_str(), import.outer_index)
+            };
+            parts.push(name);
+            current = outer;
+        }
+        if index > 0 {
+            parts.push(self.summary.package_name.as_str());
+        }
+        parts.reverse();
+        let mut path = String::new();
+        for (i, part) in parts.iter().enumerate() {
+            if i == 1 {
+                path.push('.');
+            } else if i > 1 {
+                path.push(':');
+            }
+            path.push_str(part);
+        }
+        Some(path)
+    }
+
+    /// Export whose full object path is `path`, e.g. a subobject referenced by a property.
+    pub fn export_by_path(&self, path: &str) -> Option<&ObjectExport> {
+        (0..self.exports.len())
+            .find(|&i| self.object_path(i as i32 + 1).as_deref() == Some(path))
+            .map(|i| &self.exports[i])
+    }
+
+    /// Class name of an export, resolved through the import table.
+    pub fn export_class(&self, export: &ObjectExport) -> String {
+        self.object_name(export.class_index).unwrap_or("Class").to_string()
+    }
+
+    /// The package's primary object: the top-level export named after the package.
+    pub fn main_export(&self) -> Option<&ObjectExport> {
+        let short_name = self.summary.package_name.rsplit('/').next().unwrap_or_default();
+        self.exports
+            .iter()
+            .find(|e| e.outer_index == 0 && e.object_name == short_name)
+            .or_else(|| self.exports.iter().find(|e| e.outer_index == 0 && e.is_asset))
+            .or_else(|| self.exports.iter().find(|e| e.outer_index == 0))
+    }
+
+    pub fn main_class(&self) -> Option<String> {
+        if let Some(object) = self.main_registry_object() {
+            let class = object.object_class.rsplit(['.', '/']).next().unwrap_or_default();
+            if !class.is_empty() {
+                return Some(class.to_string());
+            }
+        }
+        self.main_export().map(|e| self.export_class(e))
+    }
+
+    pub fn main_registry_object(&self) -> Option<&AssetRegistryObject> {
+        let short_name = self.summary.package_name.rsplit('/').next().unwrap_or_default();
+        self.asset_registry
+            .iter()
+            .find(|o| o.object_path.rsplit(['.', '/']).next() == Some(short_name))
+            .or_else(|| self.asset_registry.first())
+    }
+
+    /// Packages this package hard-references through its import table.
+    pub fn import_packages(&self) -> Vec<String> {
+        let mut packages: Vec<String> = self
+            .imports
+            .iter()
+            .filter(|i| i.outer_index == 0 && i.class_name == "Package")
+            .map(|i| i.object_name.clone())
+            .filter(|name| name != &self.summary.package_name)
+            .collect();
+        packages.sort();
+        packages.dedup();
+        packages
+    }
+
+    /// Content packages (not `/Script/` modules) referenced by this package, hard and soft.
+    pub fn dependencies(&self) -> Vec<String> {
+        let mut deps: Vec<String> = self
+            .import_packages()
+            .into_iter()
+            .chain(self.soft_package_references.iter().cloned())
+            .filter(|p| p.starts_with('/') && !p.starts_with("/Script/") && p != &self.summary.package_name)
+            .collect();
+        deps.sort();
+        deps.dedup();
+        deps
+    }
+}
+
+fn checked_len(len: i32) -> Result<usize, String> {
+    if !(0..=MAX_TABLE_LEN).contains(&len) {
+        return Err(format!("Invalid table length {}", len));
+    }
+    Ok(len as usize)
+}
+
+pub(crate) struct Archive<'a, R: Read + Seek> {
+    reader: &'a mut R,
+    pub file_version_ue4: i32,
+    pub file_version_ue5: i32,
+    /// Set when `read_summary` fails on a layout it doesn't support.
+    pub unsupported: Option<UnsupportedLayout>,
+}
+
+impl<'a, R: Read + Seek> Archive<'a, R> {
+    pub fn new(reader: &'a mut R) -> Self {
+        Archive {
+            reader,
+            file_version_ue4: 0,
+            file_version_ue5: 0,
+            unsupported: None,
+        }
+    }
+
+    fn unsupported(&mut self, layout: UnsupportedLayout, message: String) -> Result<PackageSummary, String> {
+        self.unsupported = Some(layout);
+        Err(message)
+    }
+
+    pub fn seek(&mut self, pos: u64) -> Result<(), String> {
+        self.reader.seek(SeekFrom::Start(pos)).map(|_| ()).map_err(|e| e.to_string())
+    }
+
+    pub fn position(&mut self) -> Result<u64, String> {
+        self.reader.stream_position().map_err(|e| e.to_string())
+    }
+
+    pub fn skip(&mut self, bytes: i64) -> Result<(), String> {
+        self.reader.seek(SeekFrom::Current(bytes)).map(|_| ()).map_err(|e| e.to_string())
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<Vec<u8>, String> {
+        let mut buf = vec![0u8; len];
+        self.reader
+            .read_exact(&mut buf)
+            .map_err(|_| UNEXPECTED_EOF.to_string())?;
+        Ok(buf)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut buf = [0u8; N];
+        self.reader
+            .read_exact(&mut buf)
+            .map_err(|_| UNEXPECTED_EOF.to_string())?;
+        Ok(buf)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, String> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, String> {
+        Ok(u16::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16, String> {
+        Ok(i16::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32, String> {
+        Ok(i32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, String> {
+        Ok(u64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, String> {
+        Ok(i64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_f32(&mut self) -> Result<f32, String> {
+        Ok(f32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64, String> {
+        Ok(f64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_bool32(&mut self) -> Result<bool, String> {
+        Ok(self.read_u32()? != 0)
+    }
+
+    pub fn read_guid(&mut self) -> Result<String, String> {
+        let a = self.read_u32()?;
+        let b = self.read_u32()?;
+        let c = self.read_u32()?;
+        let d = self.read_u32()?;
+        Ok(format!("{:08X}{:08X}{:08X}{:08X}", a, b, c, d))
+    }
+
+    pub fn read_fstring(&mut self) -> Result<String, String> {
+        let len = self.read_i32()?;
+        if len == 0 {
+            return Ok(String::new());
+        }
+        if len == i32::MIN || len.abs() > MAX_STRING_LEN {
+            return Err(format!("Invalid string length {}", len));
+        }
+        if len > 0 {
+            let bytes = self.read_bytes(len as usize)?;
+            let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+            // Non-wide FStrings are Latin-1.
+            Ok(bytes[..end].iter().map(|&b| b as char).collect())
+        } else {
+            let units = (-len) as usize;
+            let bytes = self.read_bytes(units * 2)?;
+            let wide: Vec<u16> = bytes
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .take_while(|&u| u != 0)
+                .collect();
+            Ok(String::from_utf16_lossy(&wide))
+        }
+    }
+
+    pub fn read_name_ref(&mut self) -> Result<NameRef, String> {
+        Ok(NameRef {
+            index: self.read_i32()?,
+            number: self.read_i32()?,
+        })
+    }
+
+    pub fn read_name(&mut self, package: &Package) -> Result<String, String> {
+        let name = self.read_name_ref()?;
+        if name.index < 0 || name.index as usize >= package.names.len() {
+            return Err(format!("Name index {} out of range", name.index));
+        }
+        Ok(package.name(name))
+    }
+
+    fn read_engine_version(&mut self) -> Result<EngineVersion, String> {
+        Ok(EngineVersion {
+            major: self.read_u16()?,
+            minor: self.read_u16()?,
+            patch: self.read_u16()?,
+            changelist: self.read_u32()?,
+            branch: self.read_fstring()?,
+        })
+    }
+
+    /// The summary up to and including the engine versions. `check_newest` refuses packages
+    /// newer than `VER_UE5_NEWEST_SUPPORTED`; without it they're read with the newest layout
+    /// known here, which holds for these leading fields.
+    fn read_summary_head(&mut self, check_newest: bool) -> Result<PackageSummary, String> {
+        let tag = self.read_u32()?;
+        if tag == PACKAGE_FILE_TAG_SWAPPED {
+            return self.unsupported(UnsupportedLayout::ByteSwapped, "Byte-swapped packages are not supported".to_string());
+        }
+        if tag != PACKAGE_FILE_TAG {
+            return Err(format!("Not an Unreal package (magic {:08X})", tag));
+        }
+
+        let mut s = PackageSummary {
+            legacy_file_version: self.read_i32()?,
+            ..Default::default()
+        };
+        if s.legacy_file_version >= 0 || s.legacy_file_version < -9 {
+            let message = format!("Unsupported legacy file version {}", s.legacy_file_version);
+            return self.unsupported(UnsupportedLayout::LegacyVersion, message);
+        }
+        if s.legacy_file_version != -4 {
+            self.read_i32()?; // LegacyUE3Version
+        }
+        s.file_version_ue4 = self.read_i32()?;
+        if s.legacy_file_version <= -8 {
+            s.file_version_ue5 = self.read_i32()?;
+        }
+        s.file_version_licensee = self.read_i32()?;
+        if s.is_unversioned() {
+            let message = "Unversioned (cooked) packages are not supported".to_string();
+            return self.unsupported(UnsupportedLayout::Unversioned, message);
+        }
+        if check_newest && s.file_version_ue5 > VER_UE5_NEWEST_SUPPORTED {
+            let message = format!("Package version {} is newer than this reader supports", s.file_version_ue5);
+            return self.unsupported(UnsupportedLayout::TooNew, message);
+        }
+        self.file_version_ue4 = s.file_version_ue4;
+        self.file_version_ue5 = s.file_version_ue5;
+
+        if s.legacy_file_version <= -2 {
+            let count = checked_len(self.read_i32()?)?;
+            for _ in 0..count {
+                if s.legacy_file_version == -2 {
+                    self.skip(8)?; // enum tag + version
+                } else if s.legacy_file_version >= -5 {
+                    self.skip(20)?; // guid + version
+                    self.read_fstring()?; // friendly name
+                } else {
+                    self.skip(20)?; // guid + version
+                }
+            }
+        }
+
+        let ue4 = s.file_version_ue4;
+        let ue5 = s.file_version_ue5;
+
+        if ue5 >= VER_UE5_PACKAGE_SAVED_HASH {
+            self.skip(20)?; // SavedHash
+        }
+        s.total_header_size = self.read_i32()?;
+        s.package_name = self.read_fstring()?;
+        s.package_flags = self.read_u32()?;
+        s.name_count = self.read_i32()?;
+        s.name_offset = self.read_i32()?;
+        if ue5 >= VER_UE5_ADD_SOFTOBJECTPATH_LIST {
+            s.soft_object_paths_count = self.read_i32()?;
+            s.soft_object_paths_offset = self.read_i32()?;
+        }
+        if !s.is_filter_editor_only() && ue4 >= VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID {
+            self.read_fstring()?; // LocalizationId
+        }
+        if ue4 >= VER_UE4_SERIALIZE_TEXT_IN_PACKAGES {
+            s.gatherable_text_data_count = self.read_i32()?;
+            s.gatherable_text_data_offset = self.read_i32()?;
+        }
+        s.export_count = self.read_i32()?;
+        s.export_offset = self.read_i32()?;
+        s.import_count = self.read_i32()?;
+        s.import_offset = self.read_i32()?;
+        if ue5 >= VER_UE5_VERSE_CELLS {
+            self.skip(16)?; // cell export/import counts and offsets
+        }
+        if ue5 >= VER_UE5_METADATA_SERIALIZATION_OFFSET {
+            self.read_i32()?; // MetaDataOffset
+        }
+        s.depends_offset = self.read_i32()?;
+        if ue4 >= VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP {
+            s.soft_package_references_count = self.read_i32()?;
+            s.soft_package_references_offset = self.read_i32()?;
+        }
+        if ue4 >= VER_UE4_ADDED_SEARCHABLE_NAMES {
+            s.searchable_names_offset = self.read_i32()?;
+        }
+        s.thumbnail_table_offset = self.read_i32()?;
+        if ue5 < VER_UE5_PACKAGE_SAVED_HASH {
+            self.skip(16)?; // Guid
+        }
+        if !s.is_filter_editor_only() {
+            if ue4 >= VER_UE4_ADDED_PACKAGE_OWNER {
+                self.skip(16)?; // PersistentGuid
+            }
+            if (VER_UE4_ADDED_PACKAGE_OWNER..VER_UE4_NON_OUTER_PACKAGE_IMPORT).contains(&ue4) {
+                self.skip(16)?; // OwnerPersistentGuid
+            }
+        }
+        let generations = checked_len(self.read_i32()?)?;
+        self.skip(generations as i64 * 8)?;
+        if ue4 >= VER_UE4_ENGINE_VERSION_OBJECT {
+            s.saved_by_engine_version = self.read_engine_version()?;
+        } else {
+            s.saved_by_engine_version.changelist = self.read_u32()?;
+        }
+        if ue4 >= VER_UE4_PACKAGE_SUMMARY_HAS_COMPATIBLE_ENGINE_VERSION {
+            s.compatible_with_engine_version = self.read_engine_version()?;
+        } else {
+            s.compatible_with_engine_version = s.saved_by_engine_version.clone();
+        }
+        Ok(s)
+    }
+
+    pub fn read_summary(&mut self) -> Result<PackageSummary, String> {
+        let mut s = self.read_summary_head(true)?;
+        let ue4 = s.file_version_ue4;
+        self.read_u32()?; // CompressionFlags
+        let compressed_chunks = self.read_i32()?;
+        if compressed_chunks != 0 {
+            return self.unsupported(UnsupportedLayout::Compressed, "Compressed packages are not supported".to_string());
+        }
+        self.read_u32()?; // PackageSource
+        let additional_packages = checked_len(self.read_i32()?)?;
+        for _ in 0..additional_packages {
+            self.read_fstring()?;
+        }
+        if s.legacy_file_version > -7 {
+            self.read_i32()?; // NumTextureAllocations
+        }
+        s.asset_registry_data_offset = self.read_i32()?;
+        s.bulk_data_start_offset = self.read_i64()?;
+        if ue4 >= VER_UE4_WORLD_LEVEL_INFO {
+            self.read_i32()?; // WorldTileInfoDataOffset
+        }
+        if ue4 >= VER_UE4_CHANGED_CHUNKID_TO_BE_AN_ARRAY_OF_CHUNKIDS {
+            let count = checked_len(self.read_i32()?)?;
+            for _ in 0..count {
+                s.chunk_ids.push(self.read_i32()?);
+            }
+        } else if ue4 >= VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE {
+            let chunk = self.read_i32()?;
+            if chunk >= 0 {
+                s.chunk_ids.push(chunk);
+            }
+        }
+        Ok(s)
+    }
+
+    fn read_import(&mut self, package: &Package) -> Result<ObjectImport, String> {
+        let class_package = self.read_name(package)?;
+        let class_name = self.read_name(package)?;
+        let outer_index = self.read_i32()?;
+        let object_name = self.read_name(package)?;
+        if self.file_version_ue4 >= VER_UE4_NON_OUTER_PACKAGE_IMPORT && !package.summary.is_filter_editor_only() {
+            self.read_name_ref()?; // PackageName
+        }
+        if self.file_version_ue5 >= VER_UE5_OPTIONAL_RESOURCES {
+            self.read_bool32()?; // bImportOptional
+        }
+        Ok(ObjectImport {
+            class_package,
+            class_name,
+            outer_index,
+            object_name,
+        })
+    }
+
+    fn read_export(&mut self, package: &Package) -> Result<ObjectExport, String> {
+        let ue4 = self.file_version_ue4;
+        let ue5 = self.file_version_ue5;
+        let class_index = self.read_i32()?;
+        let super_index = self.read_i32()?;
+        if ue4 >= VER_UE4_TEMPLATE_INDEX_IN_COOKED_EXPORTS {
+            self.read_i32()?; // TemplateIndex
+        }
+        let outer_index = self.read_i32()?;
+        let object_name = self.read_name(package)?;
+        let object_flags = self.read_u32()?;
+        let (serial_size, serial_offset) = if ue4 < VER_UE4_64BIT_EXPORTMAP_SERIALSIZES {
+            (self.read_i32()? as i64, self.read_i32()? as i64)
+        } else {
+            (self.read_i64()?, self.read_i64()?)
+        };
+        self.skip(12)?; // bForcedExport, bNotForClient, bNotForServer
+        if ue5 < VER_UE5_REMOVE_OBJECT_EXPORT_PACKAGE_GUID {
+            self.skip(16)?; // PackageGuid
+        }
+        if ue5 >= VER_UE5_TRACK_OBJECT_EXPORT_IS_INHERITED {
+            self.read_bool32()?; // bIsInheritedInstance
+        }
+        self.read_u32()?; // PackageFlags
+        if ue4 >= VER_UE4_LOAD_FOR_EDITOR_GAME {
+            self.read_bool32()?; // bNotAlwaysLoadedForEditorGame
+        }
+        let is_asset = if ue4 >= VER_UE4_COOKED_ASSETS_IN_EDITOR_SUPPORT {
+            self.read_bool32()?
+        } else {
+            false
+        };
+        if ue5 >= VER_UE5_OPTIONAL_RESOURCES {
+            self.read_bool32()?; // bGeneratePublicHash
+        }
+        if ue4 >= VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS {
+            self.skip(20)?; // preload dependency indices
+        }
+        let mut script_serialization_start = 0;
+        if ue5 >= VER_UE5_SCRIPT_SERIALIZATION_OFFSET {
+            script_serialization_start = self.read_i64()?;
+            self.read_i64()?; // ScriptSerializationEndOffset
+        }
+        Ok(ObjectExport {
+            class_index,
+            super_index,
+            outer_index,
+            object_name,
+            object_flags,
+            serial_size,
+            serial_offset,
+            is_asset,
+            script_serialization_start,
+        })
+    }
+
+    /// FSoftObjectPath in its inline (non table-indexed) form.
+    pub fn read_soft_object_path_inline(&mut self, package: &Package) -> Result<String, String> {
+        let asset_path = if self.file_version_ue5 >= VER_UE5_FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES {
+            let package_name = self.read_name(package)?;
+            let asset_name = self.read_name(package)?;
+            if package_name.is_empty() || package_name == "None" {
+                String::new()
+            } else if asset_name.is_empty() || asset_name == "None" {
+                package_name
+            } else {
+                format!("{}.{}", package_name, asset_name)
+            }
+        } else if self.file_version_ue4 >= VER_UE4_ADDED_SOFT_OBJECT_PATH {
+            let name = self.read_name(package)?;
+            if name == "None" {
+                String::new()
+            } else {
+                name
+            }
+        } else {
+            self.read_fstring()?
+        };
+        let sub_path = self.read_fstring()?;
+        if sub_path.is_empty() {
+            Ok(asset_path)
+        } else {
+            Ok(format!("{}:{}", asset_path, sub_path))
+        }
+    }
+
+    fn read_asset_registry(&mut self, summary: &PackageSummary) -> Result<Vec<AssetRegistryObject>, String> {
+        self.seek(summary.asset_registry_data_offset as u64)?;
+        let pre_dependency_format =
+            summary.file_version_ue4 < VER_UE4_ASSETREGISTRY_DEPENDENCYFLAGS || summary.is_filter_editor_only();
+        if !pre_dependency_format {
+            self.read_i64()?; // DependencyDataOffset
+        }
+        let count = checked_len(self.read_i32()?)?;
+        let mut objects = Vec::with_capacity(count);
+        for _ in 0..count {
+            let object_path = self.read_fstring()?;
+            let object_class = self.read_fstring()?;
+            let tag_count = checked_len(self.read_i32()?)?;
+            let mut tags = BTreeMap::new();
+            for _ in 0..tag_count {
+                let key = self.read_fstring()?;
+                let value = self.read_fstring()?;
+                tags.insert(key, value);
+            }
+            objects.push(AssetRegistryObject {
+                object_path,
+                object_class,
+                tags,
+            });
+        }
+        Ok(objects)
+    }
+}
+
+/// Extracts the object path out of an exported text reference such as
+/// `/Script/CoreUObject.Class'/Script/Engine.Actor'` or `Class'"/Game/BP.BP_C"'`.
+pub fn strip_object_reference(value: &str) -> &str {
+    let trimmed = value.trim();
+    match (trimmed.find('\''), trimmed.rfind('\'')) {
+        (Some(start), Some(end)) if end > start => trimmed[start + 1..end].trim_matches('"'),
+        _ => trimmed.trim_matches('"'),
+    }
+}
+
+/// `/Game/Folder/Asset.Asset_C` -> `/Game/Folder/Asset`
+pub fn package_of_object_path(path: &str) -> &str {
+    let path = strip_object_reference(path);
+    path.split(['.', ':']).next().unwrap_or(path)
+}
@@ -0,0 +1,128 @@
+// Physics setup audit: the collision profiles the project defines, the physical materials
+// it has, and static meshes whose body setup misses one or refers to a profile that's gone.
+
+use crate::config::{ConfigValue, IniFile};
+use crate::error::CodexResult;
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tauri::State;
+
+const COLLISION_SECTION: &str = "/Script/Engine.CollisionProfile";
+
+// Profiles BaseEngine.ini defines; projects can remove them but rarely do.
+const ENGINE_PROFILES: &[&str] = &[
+    "NoCollision",
+    "BlockAll",
+    "OverlapAll",
+    "BlockAllDynamic",
+    "OverlapAllDynamic",
+    "IgnoreOnlyPawn",
+    "OverlapOnlyPawn",
+    "Pawn",
+    "Spectator",
+    "CharacterMesh",
+    "PhysicsActor",
+    "Destructible",
+    "InvisibleWall",
+    "InvisibleWallDynamic",
+    "Trigger",
+    "Ragdoll",
+    "Vehicle",
+    "UI",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MeshReference {
+    pub mesh: String,
+    /// Collision profile name or physical material path, depending on the list.
+    pub reference: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PhysicsAudit {
+    pub collision_profiles: Vec<String>,
+    /// Old profile name -> new name, from `+ProfileRedirects`.
+    pub profile_redirects: BTreeMap<String, String>,
+    pub physical_materials: Vec<String>,
+    pub meshes_without_physical_material: Vec<String>,
+    pub missing_profiles: Vec<MeshReference>,
+    /// Meshes pointing at a physical material that no longer exists.
+    pub missing_physical_materials: Vec<MeshReference>,
+}
+
+pub fn collision_profiles(project: &Path) -> (BTreeSet<String>, BTreeMap<String, String>) {
+    let text = std::fs::read_to_string(project.join("Config").join("DefaultEngine.ini")).unwrap_or_default();
+    let base: String = ENGINE_PROFILES.iter().map(|p| format!("+Profiles=(Name=\"{}\")\n", p)).collect();
+    let ini = IniFile::parse(&format!("[{}]\n{}\n{}", COLLISION_SECTION, base, text));
+
+    let profiles = ini
+        .get_all(COLLISION_SECTION, "Profiles")
+        .into_iter()
+        .filter_map(|p| ConfigValue::parse(p).get_str("Name").map(str::to_string))
+        .collect();
+    let redirects = ini
+        .get_all(COLLISION_SECTION, "ProfileRedirects")
+        .into_iter()
+        .map(ConfigValue::parse)
+        .filter_map(|r| Some((r.get_str("OldName")?.to_string(), r.get_str("NewName")?.to_string())))
+        .collect();
+    (profiles, redirects)
+}
+
+/// Physical material and collision profile from a mesh's BodySetup subobject.
+fn body_setup(file_path: &Path) -> Option<(Option<String>, Option<String>)> {
+    let package = Package::read_file(file_path).ok()?;
+    let body = package.exports.iter().find(|e| package.export_class(e) == "BodySetup")?;
+    let props = properties::read_export_properties(file_path, &package, body).ok()?;
+    let material = properties::find(&props, "PhysMaterial").and_then(|v| v.as_str()).map(str::to_string);
+    let profile = properties::find(&props, "DefaultInstance")
+        .and_then(|instance| instance.field("CollisionProfileName"))
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    Some((material, profile))
+}
+
+pub fn audit(index: &AssetIndex) -> PhysicsAudit {
+    let (profiles, redirects) = collision_profiles(&index.project);
+    let mut report = PhysicsAudit {
+        collision_profiles: profiles.iter().cloned().collect(),
+        profile_redirects: redirects.clone(),
+        physical_materials: index.of_class("PhysicalMaterial").map(|a| a.asset.path.clone()).collect(),
+        meshes_without_physical_material: Vec::new(),
+        missing_profiles: Vec::new(),
+        missing_physical_materials: Vec::new(),
+    };
+
+    for mesh in index.of_class("StaticMesh") {
+        let Some((material, profile)) = body_setup(Path::new(&mesh.asset.file_path)) else {
+            continue;
+        };
+        let path = mesh.asset.path.clone();
+        match material {
+            None => report.meshes_without_physical_material.push(path.clone()),
+            Some(material) if material.starts_with("/Game/") && !index.contains(&material) => {
+                report.missing_physical_materials.push(MeshReference { mesh: path.clone(), reference: material })
+            }
+            Some(_) => {}
+        }
+        // "Custom" means per-body responses; "Default" defers to the component.
+        if let Some(profile) = profile.filter(|p| !matches!(p.as_str(), "Custom" | "Default" | "None")) {
+            let resolved = redirects.get(&profile).unwrap_or(&profile);
+            if !profiles.contains(resolved) {
+                report.missing_profiles.push(MeshReference { mesh: path, reference: profile });
+            }
+        }
+    }
+    report
+}
+
+#[tauri::command]
+pub fn audit_physics_setup(manager: State<ScanManager>, project: String) -> CodexResult<PhysicsAudit> {
+    let index = manager.index(&project)?;
+    Ok(audit(&index))
+}
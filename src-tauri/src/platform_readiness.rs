@@ -0,0 +1,202 @@
+// Per-platform readiness: assets carrying per-platform overrides (LOD screen sizes, min LODs
+// and the like), platform variant content, and the meshes and textures a mobile or console
+// build should not ship with default settings.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use crate::scan::ContentRoot;
+use serde::Serialize;
+use std::path::Path;
+use tauri::State;
+use walkdir::WalkDir;
+
+// Meshes below this many triangles do fine without LODs on any platform.
+const LOD_TRIANGLE_THRESHOLD: u64 = 5_000;
+
+// Per-platform overrides may be keyed by platform group rather than platform.
+const PLATFORM_GROUPS: &[(&str, &[&str])] = &[
+    ("Mobile", &["Android", "IOS", "TVOS"]),
+    ("Console", &["PS4", "PS5", "XboxOne", "XSX", "XboxOneGDK", "WinGDK", "Switch"]),
+    ("Desktop", &["Windows", "Mac", "Linux"]),
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformOverride {
+    pub asset: String,
+    /// Property path, e.g. `SourceModels[1].ScreenSize`.
+    pub property: String,
+    /// Platform or group name the override is keyed by.
+    pub key: String,
+    pub value: String,
+    pub default: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformVariant {
+    pub path: String,
+    pub file_path: String,
+    /// The asset it replaces exists in the base content.
+    pub overrides_base: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlatformReadiness {
+    pub platform: String,
+    pub overrides: Vec<PlatformOverride>,
+    pub variants: Vec<PlatformVariant>,
+    /// High-poly static meshes with a single LOD and no LOD group.
+    pub meshes_without_lods: Vec<String>,
+    /// Textures left in the default World texture group.
+    pub textures_without_group: Vec<String>,
+    /// Meshes and textures whose properties couldn't be read, so neither list above covers them.
+    pub unreadable: Vec<String>,
+}
+
+fn applies_to(key: &str, platform: &str) -> bool {
+    key.eq_ignore_ascii_case(platform)
+        || PLATFORM_GROUPS.iter().any(|(group, members)| {
+            key.eq_ignore_ascii_case(group) && members.iter().any(|m| m.eq_ignore_ascii_case(platform))
+        })
+}
+
+fn collect_overrides(asset: &str, prefix: &str, props: &[Property], platform: &str, out: &mut Vec<PlatformOverride>) {
+    for prop in props {
+        let path = if prop.array_index == 0 {
+            format!("{}{}", prefix, prop.name)
+        } else {
+            format!("{}{}[{}]", prefix, prop.name, prop.array_index)
+        };
+        collect_value(asset, &path, &prop.value, platform, out);
+    }
+}
+
+fn collect_value(asset: &str, path: &str, value: &PropertyValue, platform: &str, out: &mut Vec<PlatformOverride>) {
+    match value {
+        PropertyValue::Struct { struct_type, properties } if struct_type.starts_with("PerPlatform") => {
+            let default = properties::find(properties, "Default").map(|v| v.display()).unwrap_or_default();
+            let Some(PropertyValue::Map(entries)) = properties::find(properties, "PerPlatform") else {
+                return;
+            };
+            for (key, value) in entries {
+                let key = key.display();
+                if applies_to(&key, platform) {
+                    out.push(PlatformOverride {
+                        asset: asset.to_string(),
+                        property: path.to_string(),
+                        key,
+                        value: value.display(),
+                        default: default.clone(),
+                    });
+                }
+            }
+        }
+        PropertyValue::Struct { properties, .. } => {
+            collect_overrides(asset, &format!("{}.", path), properties, platform, out)
+        }
+        PropertyValue::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                collect_value(asset, &format!("{}[{}]", path, i), item, platform, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn main_properties(asset: &IndexedAsset) -> Option<Vec<Property>> {
+    let file_path = Path::new(&asset.asset.file_path);
+    let package = Package::read_file(file_path).ok()?;
+    properties::read_export_properties(file_path, &package, package.main_export()?).ok()
+}
+
+/// Packages under `<root>/Platforms/<Platform>/Content`, which the engine mounts over the
+/// base content when cooking for that platform, and assets kept in `_Platforms/<Platform>` folders.
+fn platform_variants(index: &AssetIndex, platform: &str) -> Vec<PlatformVariant> {
+    let mut variants = Vec::new();
+    for root in crate::scan::project_content_roots(&index.project) {
+        let Some(base) = root.path.parent() else {
+            continue;
+        };
+        let platforms = std::fs::read_dir(base.join("Platforms")).into_iter().flatten().filter_map(|e| e.ok());
+        for dir in platforms.filter(|e| e.file_name().to_string_lossy().eq_ignore_ascii_case(platform)) {
+            let variant_root = ContentRoot { path: dir.path().join("Content"), mount: root.mount.clone() };
+            let files = WalkDir::new(&variant_root.path).into_iter().filter_map(|e| e.ok());
+            for file in files.filter(|e| e.path().extension().is_some_and(|ext| ext == "uasset" || ext == "umap")) {
+                let Some(path) = variant_root.package_path(file.path()) else {
+                    continue;
+                };
+                variants.push(PlatformVariant {
+                    overrides_base: index.contains(&path),
+                    path,
+                    file_path: file.path().to_string_lossy().to_string(),
+                });
+            }
+        }
+    }
+
+    let marker = format!("/_Platforms/{}/", platform).to_ascii_lowercase();
+    for asset in &index.assets {
+        // ASCII lowering keeps every byte offset, so `start` is valid in the original path.
+        let Some(start) = asset.asset.path.to_ascii_lowercase().find(&marker) else {
+            continue;
+        };
+        let base = format!("{}/{}", &asset.asset.path[..start], &asset.asset.path[start + marker.len()..]);
+        variants.push(PlatformVariant {
+            path: asset.asset.path.clone(),
+            file_path: asset.asset.file_path.clone(),
+            overrides_base: index.contains(&base),
+        });
+    }
+    variants.sort_by(|a, b| a.path.cmp(&b.path));
+    variants
+}
+
+pub fn audit(index: &AssetIndex, platform: &str) -> PlatformReadiness {
+    let mut report = PlatformReadiness {
+        platform: platform.to_string(),
+        overrides: Vec::new(),
+        variants: platform_variants(index, platform),
+        meshes_without_lods: Vec::new(),
+        textures_without_group: Vec::new(),
+        unreadable: Vec::new(),
+    };
+    for asset in &index.assets {
+        let class = asset.class.as_deref().unwrap_or_default();
+        if !matches!(class, "StaticMesh" | "SkeletalMesh" | "Texture2D") {
+            continue;
+        }
+        let Some(props) = main_properties(asset) else {
+            report.unreadable.push(asset.asset.path.clone());
+            continue;
+        };
+        collect_overrides(&asset.asset.path, "", &props, platform, &mut report.overrides);
+        let lod_group = properties::find(&props, "LODGroup").and_then(|v| v.as_str());
+        if class == "StaticMesh" {
+            let lods = asset.tag("LODs").and_then(|v| v.parse::<u32>().ok()).unwrap_or(1);
+            let triangles = asset.tag("Triangles").and_then(|v| v.parse::<u64>().ok()).unwrap_or(0);
+            if lods <= 1 && triangles >= LOD_TRIANGLE_THRESHOLD && lod_group.is_none_or(|g| g == "None") {
+                report.meshes_without_lods.push(asset.asset.path.clone());
+            }
+        } else if class == "Texture2D" && lod_group.is_none_or(|g| g.ends_with("TEXTUREGROUP_World")) {
+            report.textures_without_group.push(asset.asset.path.clone());
+        }
+    }
+    report
+}
+
+/// `platform` is an ini platform name such as `Android`, `IOS`, `PS5` or `Switch`.
+#[tauri::command(async)]
+pub fn audit_platform_readiness(
+    manager: State<ScanManager>,
+    project: String,
+    platform: String,
+) -> CodexResult<PlatformReadiness> {
+    let platform = platform.trim();
+    if platform.is_empty() {
+        return Err(CodexError::message("empty_platform", &[]));
+    }
+    let index = manager.index(&project)?;
+    Ok(audit(&index, platform))
+}
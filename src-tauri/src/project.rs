@@ -0,0 +1,126 @@
+// The project descriptor (.uproject) and the engine install it points at.
+
+use crate::error::{CodexError, CodexResult};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UProject {
+    pub file_path: String,
+    /// `5.3` for launcher installs, a `{GUID}` for registered source builds, empty for in-tree projects.
+    pub engine_association: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct EngineRelease {
+    pub major: u16,
+    pub minor: u16,
+}
+
+impl std::fmt::Display for EngineRelease {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+pub fn find_uproject(project: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(project)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("uproject")))
+}
+
+impl UProject {
+    pub fn load(project: &Path) -> CodexResult<UProject> {
+        let path = find_uproject(project)
+            .ok_or_else(|| CodexError::NotAProject { path: project.to_string_lossy().to_string() })?;
+        let text = std::fs::read_to_string(&path).map_err(|e| CodexError::io(&path, &e))?;
+        let json: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| CodexError::parse(&path, e))?;
+        Ok(UProject {
+            file_path: path.to_string_lossy().to_string(),
+            engine_association: json
+                .get("EngineAssociation")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string(),
+        })
+    }
+
+    /// Engine release the project is associated with, resolving source-build GUIDs where possible.
+    pub fn engine_release(&self) -> Option<EngineRelease> {
+        if let Some(release) = parse_release(&self.engine_association) {
+            return Some(release);
+        }
+        let root = registered_engine_root(&self.engine_association)?;
+        build_version(&root)
+    }
+}
+
+fn parse_release(text: &str) -> Option<EngineRelease> {
+    let mut parts = text.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    Some(EngineRelease { major, minor })
+}
+
+// Source builds are registered in Install.ini on Linux/macOS; Windows keeps them in the
+// registry under HKCU\Software\Epic Games\Unreal Engine\Builds.
+#[cfg(not(windows))]
+fn registered_engine_root(association: &str) -> Option<PathBuf> {
+    if association.is_empty() {
+        return None;
+    }
+    let home = std::env::var_os("HOME")?;
+    let candidates = [
+        Path::new(&home).join(".config/Epic/UnrealEngine/Install.ini"),
+        Path::new(&home).join("Library/Application Support/Epic/UnrealEngine/Install.ini"),
+    ];
+    let text = candidates.iter().find_map(|p| std::fs::read_to_string(p).ok())?;
+    let ini = crate::config::IniFile::parse(&text);
+    ini.get_all("Installations", association)
+        .first()
+        .map(PathBuf::from)
+}
+
+#[cfg(windows)]
+fn registered_engine_root(association: &str) -> Option<PathBuf> {
+    use std::os::windows::ffi::{OsStrExt, OsStringExt};
+    use windows_sys::Win32::Foundation::ERROR_SUCCESS;
+    use windows_sys::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_SZ};
+    if association.is_empty() {
+        return None;
+    }
+    let wide = |s: &str| std::ffi::OsStr::new(s).encode_wide().chain(Some(0)).collect::<Vec<u16>>();
+    let key = wide(r"Software\Epic Games\Unreal Engine\Builds");
+    let value = wide(association);
+    let mut data = [0u16; 1024];
+    let mut size = std::mem::size_of_val(&data) as u32;
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            key.as_ptr(),
+            value.as_ptr(),
+            RRF_RT_REG_SZ,
+            std::ptr::null_mut(),
+            data.as_mut_ptr().cast(),
+            &mut size,
+        )
+    };
+    if status != ERROR_SUCCESS {
+        return None;
+    }
+    // `size` is in bytes and includes the terminating null.
+    let len = (size as usize / 2).saturating_sub(1);
+    Some(PathBuf::from(std::ffi::OsString::from_wide(&data[..len])))
+}
+
+pub fn build_version(engine_root: &Path) -> Option<EngineRelease> {
+    let text = std::fs::read_to_string(engine_root.join("Engine/Build/Build.version")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&text).ok()?;
+    Some(EngineRelease {
+        major: json.get("MajorVersion")?.as_u64()? as u16,
+        minor: json.get("MinorVersion")?.as_u64()? as u16,
+    })
+}
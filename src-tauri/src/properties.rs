@@ -0,0 +1,632 @@
+// Reader for the tagged (versioned) property stream that starts each export's
+// serialized data in editor-saved packages.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::*;
+use serde::Serialize;
+use std::io::{BufReader, Read, Seek};
+use std::path::Path;
+
+const MAX_ARRAY_LEN: i32 = 1 << 20;
+// Nesting limit for struct-in-struct chains in damaged data.
+const MAX_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Property {
+    pub name: String,
+    pub type_name: String,
+    #[serde(skip_serializing_if = "is_zero")]
+    pub array_index: i32,
+    pub value: PropertyValue,
+}
+
+fn is_zero(v: &i32) -> bool {
+    *v == 0
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Name(String),
+    Str(String),
+    Text(Option<String>),
+    Enum(String),
+    Object(Option<String>),
+    SoftObject(String),
+    Guid(String),
+    Numbers(Vec<f64>),
+    Struct {
+        struct_type: String,
+        properties: Vec<Property>,
+    },
+    Array(Vec<PropertyValue>),
+    Map(Vec<(PropertyValue, PropertyValue)>),
+    Raw {
+        size: usize,
+    },
+}
+
+impl PropertyValue {
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            PropertyValue::Int(v) => Some(*v),
+            PropertyValue::Float(v) => Some(*v as i64),
+            PropertyValue::Bool(v) => Some(*v as i64),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(v) => Some(*v),
+            PropertyValue::Int(v) => Some(*v != 0),
+            _ => None,
+        }
+    }
+
+    /// Name-like payloads (names, enums, strings, object paths) as text.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Name(s) | PropertyValue::Str(s) | PropertyValue::Enum(s) | PropertyValue::SoftObject(s) => {
+                Some(s.as_str())
+            }
+            PropertyValue::Object(Some(s)) | PropertyValue::Text(Some(s)) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&PropertyValue> {
+        match self {
+            PropertyValue::Struct { properties, .. } => find(properties, name),
+            _ => None,
+        }
+    }
+
+    /// Short exported-text style rendering, e.g. `(X=1, Y=2)` or `/Game/Foo.Foo`.
+    pub fn display(&self) -> String {
+        match self {
+            PropertyValue::Bool(v) => if *v { "True" } else { "False" }.to_string(),
+            PropertyValue::Int(v) => v.to_string(),
+            PropertyValue::Float(v) => v.to_string(),
+            PropertyValue::Name(s)
+            | PropertyValue::Str(s)
+            | PropertyValue::Enum(s)
+            | PropertyValue::SoftObject(s)
+            | PropertyValue::Guid(s) => s.clone(),
+            PropertyValue::Text(Some(s)) => format!("\"{}\"", s),
+            PropertyValue::Text(None) | PropertyValue::Object(None) => "None".to_string(),
+            PropertyValue::Object(Some(s)) => s.clone(),
+            PropertyValue::Numbers(v) => {
+                format!("({})", v.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", "))
+            }
+            PropertyValue::Struct { properties, .. } => format!(
+                "({})",
+                properties
+                    .iter()
+                    .map(|p| format!("{}={}", p.name, p.value.display()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            PropertyValue::Array(items) => {
+                format!("[{}]", items.iter().map(|v| v.display()).collect::<Vec<_>>().join(", "))
+            }
+            PropertyValue::Map(entries) => format!(
+                "{{{}}}",
+                entries
+                    .iter()
+                    .map(|(k, v)| format!("{}: {}", k.display(), v.display()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            PropertyValue::Raw { size } => format!("<{} bytes>", size),
+        }
+    }
+}
+
+/// First property with `name` in a property list.
+pub fn find<'a>(properties: &'a [Property], name: &str) -> Option<&'a PropertyValue> {
+    properties.iter().find(|p| p.name == name).map(|p| &p.value)
+}
+
+/// Property type as written in the tag, e.g. `ArrayProperty(StructProperty(Vector))`.
+#[derive(Debug, Clone, Default)]
+struct TypeInfo {
+    name: String,
+    params: Vec<TypeInfo>,
+}
+
+impl TypeInfo {
+    fn simple(name: String) -> Self {
+        TypeInfo { name, params: Vec::new() }
+    }
+
+    fn param(&self, i: usize) -> Option<&str> {
+        self.params
+            .get(i)
+            .map(|p| p.name.as_str())
+            .filter(|n| !n.is_empty() && *n != "None")
+    }
+
+    fn display(&self) -> String {
+        if self.params.is_empty() {
+            self.name.clone()
+        } else {
+            let inner: Vec<String> = self.params.iter().map(|p| p.display()).collect();
+            format!("{}({})", self.name, inner.join(","))
+        }
+    }
+}
+
+struct Tag {
+    name: String,
+    ty: TypeInfo,
+    size: i32,
+    array_index: i32,
+    bool_value: bool,
+}
+
+/// Reads the tagged properties of `export` from the package file at `path`.
+pub fn read_export_properties(path: &Path, package: &Package, export: &ObjectExport) -> CodexResult<Vec<Property>> {
+    let file = crate::file_lock::open(path).map_err(|e| CodexError::io(path, &e))?;
+    read_properties(&mut BufReader::new(file), package, export).map_err(|e| CodexError::parse(path, e))
+}
+
+pub fn read_properties<R: Read + Seek>(
+    reader: &mut R,
+    package: &Package,
+    export: &ObjectExport,
+) -> Result<Vec<Property>, String> {
+    if package.summary.package_flags & PKG_UNVERSIONED_PROPERTIES != 0 {
+        return Err("Unversioned properties (cooked data) are not supported".to_string());
+    }
+    if export.serial_size <= 0 {
+        return Ok(Vec::new());
+    }
+    let mut ar = Archive::new(reader);
+    ar.file_version_ue4 = package.summary.file_version_ue4;
+    ar.file_version_ue5 = package.summary.file_version_ue5;
+    ar.seek((export.serial_offset + export.script_serialization_start.max(0)) as u64)?;
+
+    if ar.file_version_ue5 >= VER_UE5_PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION {
+        let control = ar.read_u8()?;
+        if control & 0x02 != 0 {
+            ar.read_u8()?; // overridden property operation
+        }
+    }
+    let mut reader = PropertyReader { ar, package };
+    reader.read_tagged(0)
+}
+
+struct PropertyReader<'a, 'r, R: Read + Seek> {
+    ar: Archive<'r, R>,
+    package: &'a Package,
+}
+
+impl<R: Read + Seek> PropertyReader<'_, '_, R> {
+    fn complete_type_names(&self) -> bool {
+        self.ar.file_version_ue5 >= VER_UE5_PROPERTY_TAG_COMPLETE_TYPE_NAME
+    }
+
+    fn read_tagged(&mut self, depth: usize) -> Result<Vec<Property>, String> {
+        if depth > MAX_DEPTH {
+            return Err("Property nesting too deep".to_string());
+        }
+        let mut properties = Vec::new();
+        while let Some(tag) = self.read_tag()? {
+            let start = self.ar.position()?;
+            let value = match self.read_value(&tag.ty, tag.size.max(0) as usize, tag.bool_value, depth) {
+                Ok(value) => value,
+                Err(_) => PropertyValue::Raw {
+                    size: tag.size.max(0) as usize,
+                },
+            };
+            // Always resync on the tag size so one odd value doesn't derail the rest.
+            self.ar.seek(start + tag.size.max(0) as u64)?;
+            properties.push(Property {
+                name: tag.name,
+                type_name: tag.ty.display(),
+                array_index: tag.array_index,
+                value,
+            });
+        }
+        Ok(properties)
+    }
+
+    fn read_type_name(&mut self) -> Result<TypeInfo, String> {
+        // Flattened tree of (name, inner count) nodes, depth first.
+        let name = self.ar.read_name(self.package)?;
+        let inner = self.ar.read_i32()?;
+        if !(0..=8).contains(&inner) {
+            return Err("Invalid property type name".to_string());
+        }
+        let mut ty = TypeInfo::simple(name);
+        for _ in 0..inner {
+            let param = self.read_type_name()?;
+            ty.params.push(param);
+        }
+        Ok(ty)
+    }
+
+    fn read_tag(&mut self) -> Result<Option<Tag>, String> {
+        let name = self.ar.read_name(self.package)?;
+        if name == "None" {
+            return Ok(None);
+        }
+
+        if self.complete_type_names() {
+            let ty = self.read_type_name()?;
+            let size = self.ar.read_i32()?;
+            let flags = self.ar.read_u8()?;
+            let array_index = if flags & 0x01 != 0 { self.ar.read_i32()? } else { 0 };
+            if flags & 0x02 != 0 {
+                self.ar.read_guid()?;
+            }
+            if flags & 0x04 != 0 {
+                self.read_tag_extensions()?;
+            }
+            return Ok(Some(Tag {
+                name,
+                ty,
+                size,
+                array_index,
+                bool_value: flags & 0x10 != 0,
+            }));
+        }
+
+        let type_name = self.ar.read_name(self.package)?;
+        let size = self.ar.read_i32()?;
+        let array_index = self.ar.read_i32()?;
+        let mut ty = TypeInfo::simple(type_name);
+        let mut bool_value = false;
+        match ty.name.as_str() {
+            "StructProperty" => {
+                let struct_name = self.ar.read_name(self.package)?;
+                ty.params.push(TypeInfo::simple(struct_name));
+                if self.ar.file_version_ue4 >= VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG {
+                    self.ar.read_guid()?;
+                }
+            }
+            "BoolProperty" => bool_value = self.ar.read_u8()? != 0,
+            "ByteProperty" | "EnumProperty" => {
+                let enum_name = self.ar.read_name(self.package)?;
+                ty.params.push(TypeInfo::simple(enum_name));
+            }
+            "ArrayProperty" if self.ar.file_version_ue4 >= VER_UE4_ARRAY_PROPERTY_INNER_TAGS => {
+                let inner = self.ar.read_name(self.package)?;
+                ty.params.push(TypeInfo::simple(inner));
+            }
+            "SetProperty" if self.ar.file_version_ue4 >= VER_UE4_PROPERTY_TAG_SET_MAP_SUPPORT => {
+                let inner = self.ar.read_name(self.package)?;
+                ty.params.push(TypeInfo::simple(inner));
+            }
+            "MapProperty" if self.ar.file_version_ue4 >= VER_UE4_PROPERTY_TAG_SET_MAP_SUPPORT => {
+                let key = self.ar.read_name(self.package)?;
+                let value = self.ar.read_name(self.package)?;
+                ty.params.push(TypeInfo::simple(key));
+                ty.params.push(TypeInfo::simple(value));
+            }
+            _ => {}
+        }
+        if self.ar.file_version_ue4 >= VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG && self.ar.read_u8()? != 0 {
+            self.ar.read_guid()?;
+        }
+        if self.ar.file_version_ue5 >= VER_UE5_PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION {
+            self.read_tag_extensions()?;
+        }
+        Ok(Some(Tag {
+            name,
+            ty,
+            size,
+            array_index,
+            bool_value,
+        }))
+    }
+
+    fn read_tag_extensions(&mut self) -> Result<(), String> {
+        let extensions = self.ar.read_u8()?;
+        if extensions & 0x02 != 0 {
+            self.ar.read_u8()?; // OverridableOperation
+            self.ar.read_bool32()?; // bExperimentalOverridableLogic
+        }
+        Ok(())
+    }
+
+    fn read_object_ref(&mut self) -> Result<PropertyValue, String> {
+        let index = self.ar.read_i32()?;
+        Ok(PropertyValue::Object(self.package.object_path(index)))
+    }
+
+    fn read_soft_object(&mut self) -> Result<String, String> {
+        if !self.package.soft_object_paths.is_empty() {
+            let index = self.ar.read_i32()?;
+            return usize::try_from(index)
+                .ok()
+                .and_then(|i| self.package.soft_object_paths.get(i))
+                .cloned()
+                .ok_or_else(|| format!("Soft object path index {} out of range", index));
+        }
+        self.ar.read_soft_object_path_inline(self.package)
+    }
+
+    fn read_text(&mut self) -> Result<Option<String>, String> {
+        self.ar.read_u32()?; // flags
+        let history = self.ar.read_u8()? as i8;
+        match history {
+            -1 => {
+                if self.ar.read_bool32()? {
+                    Ok(Some(self.ar.read_fstring()?))
+                } else {
+                    Ok(None)
+                }
+            }
+            0 => {
+                self.ar.read_fstring()?; // namespace
+                self.ar.read_fstring()?; // key
+                Ok(Some(self.ar.read_fstring()?))
+            }
+            11 => {
+                let table = self.ar.read_name(self.package)?;
+                let key = self.ar.read_fstring()?;
+                Ok(Some(format!("{}:{}", table, key)))
+            }
+            _ => Err(format!("Unsupported text history {}", history)),
+        }
+    }
+
+    fn read_floats(&mut self, count: usize, wide: bool) -> Result<PropertyValue, String> {
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count {
+            values.push(if wide {
+                self.ar.read_f64()?
+            } else {
+                self.ar.read_f32()? as f64
+            });
+        }
+        Ok(PropertyValue::Numbers(values))
+    }
+
+    fn read_ints(&mut self, count: usize) -> Result<PropertyValue, String> {
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count {
+            values.push(self.ar.read_i32()? as f64);
+        }
+        Ok(PropertyValue::Numbers(values))
+    }
+
+    fn read_per_platform_value(&mut self, struct_type: &str) -> Result<PropertyValue, String> {
+        Ok(match struct_type {
+            "PerPlatformFloat" => PropertyValue::Float(self.ar.read_f32()? as f64),
+            "PerPlatformBool" => PropertyValue::Bool(self.ar.read_bool32()?),
+            "PerPlatformFrameRate" => self.read_ints(2)?,
+            _ => PropertyValue::Int(self.ar.read_i32()? as i64),
+        })
+    }
+
+    /// Structs with native binary serializers; `None` means "use tagged properties".
+    fn read_native_struct(&mut self, struct_type: &str, size: Option<usize>) -> Result<Option<PropertyValue>, String> {
+        let lwc = self.ar.file_version_ue5 >= VER_UE5_LARGE_WORLD_COORDINATES;
+        let value = match struct_type {
+            "Vector" | "Vector3d" | "Rotator" => self.read_floats(3, lwc || struct_type == "Vector3d")?,
+            "Vector3f" | "Rotator3f" => self.read_floats(3, false)?,
+            "Vector2D" => self.read_floats(2, lwc)?,
+            "Vector2f" => self.read_floats(2, false)?,
+            "Vector4" | "Quat" | "Plane" => self.read_floats(4, lwc)?,
+            "Vector4f" | "Quat4f" | "LinearColor" => self.read_floats(4, false)?,
+            "IntPoint" => self.read_ints(2)?,
+            "IntVector" => self.read_ints(3)?,
+            "Color" => {
+                let bgra = self.ar.read_bytes(4)?;
+                PropertyValue::Numbers(vec![bgra[2] as f64, bgra[1] as f64, bgra[0] as f64, bgra[3] as f64])
+            }
+            "Box" => {
+                let mut values = match self.read_floats(6, lwc)? {
+                    PropertyValue::Numbers(v) => v,
+                    _ => unreachable!(),
+                };
+                values.push(self.ar.read_u8()? as f64);
+                PropertyValue::Numbers(values)
+            }
+            "Guid" => PropertyValue::Guid(self.ar.read_guid()?),
+            "DateTime" | "Timespan" => PropertyValue::Int(self.ar.read_i64()?),
+            "FrameNumber" => PropertyValue::Int(self.ar.read_i32()? as i64),
+            "SoftObjectPath" | "SoftClassPath" | "StringAssetReference" | "StringClassReference" => {
+                PropertyValue::SoftObject(self.read_soft_object()?)
+            }
+            "GameplayTagContainer" => {
+                let count = self.ar.read_i32()?;
+                if !(0..=MAX_ARRAY_LEN).contains(&count) {
+                    return Err("Invalid tag count".to_string());
+                }
+                let mut tags = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    tags.push(PropertyValue::Name(self.ar.read_name(self.package)?));
+                }
+                PropertyValue::Array(tags)
+            }
+            "RichCurveKey" => {
+                let interp = self.ar.read_u8()?;
+                self.ar.read_u16()?; // tangent mode + tangent weight mode
+                let mut values = vec![interp as f64];
+                if let PropertyValue::Numbers(rest) = self.read_floats(6, false)? {
+                    values.extend(rest);
+                }
+                // [interp, time, value, arrive, arrive weight, leave, leave weight]
+                PropertyValue::Numbers(values)
+            }
+            // Structs below are only recognised when the tag size matches the native layout.
+            "FrameRate" if size == Some(8) => self.read_ints(2)?,
+            // MovieSceneFrameRange serializes as the TRange<FFrameNumber> it wraps.
+            "FrameNumberRange" | "MovieSceneFrameRange" if size == Some(10) => {
+                let lower_type = self.ar.read_u8()?;
+                let lower = self.ar.read_i32()?;
+                let upper_type = self.ar.read_u8()?;
+                let upper = self.ar.read_i32()?;
+                PropertyValue::Numbers(vec![lower_type as f64, lower as f64, upper_type as f64, upper as f64])
+            }
+            "PerPlatformFloat" | "PerPlatformInt" | "PerPlatformBool" | "PerPlatformFrameRate" => {
+                let cooked = self.ar.read_bool32()?;
+                let default = self.read_per_platform_value(struct_type)?;
+                // Editor data keeps the overrides per platform (group) name; cooked data only
+                // has the value for the platform it was cooked for.
+                let mut overrides = Vec::new();
+                if !cooked {
+                    for _ in 0..self.read_count()? {
+                        let platform = PropertyValue::Name(self.ar.read_name(self.package)?);
+                        overrides.push((platform, self.read_per_platform_value(struct_type)?));
+                    }
+                }
+                let field = |name: &str, type_name: &str, value| Property {
+                    name: name.to_string(),
+                    type_name: type_name.to_string(),
+                    array_index: 0,
+                    value,
+                };
+                PropertyValue::Struct {
+                    struct_type: struct_type.to_string(),
+                    properties: vec![
+                        field("Default", struct_type.trim_start_matches("PerPlatform"), default),
+                        field("PerPlatform", "MapProperty", PropertyValue::Map(overrides)),
+                    ],
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(value))
+    }
+
+    fn read_struct(&mut self, struct_type: &str, size: Option<usize>, depth: usize) -> Result<PropertyValue, String> {
+        if let Some(value) = self.read_native_struct(struct_type, size)? {
+            return Ok(value);
+        }
+        Ok(PropertyValue::Struct {
+            struct_type: struct_type.to_string(),
+            properties: self.read_tagged(depth + 1)?,
+        })
+    }
+
+    fn read_value(&mut self, ty: &TypeInfo, size: usize, bool_value: bool, depth: usize) -> Result<PropertyValue, String> {
+        let value = match ty.name.as_str() {
+            "BoolProperty" => PropertyValue::Bool(bool_value),
+            "ByteProperty" if size == 8 || (size != 1 && ty.param(0).is_some()) => {
+                PropertyValue::Enum(self.ar.read_name(self.package)?)
+            }
+            "EnumProperty" => PropertyValue::Enum(self.ar.read_name(self.package)?),
+            "ArrayProperty" => self.read_array(ty, size, depth)?,
+            "SetProperty" => self.read_set(ty, depth)?,
+            "MapProperty" => self.read_map(ty, depth)?,
+            "StructProperty" => {
+                let struct_type = ty.param(0).unwrap_or("Unknown").to_string();
+                self.read_struct(&struct_type, Some(size), depth)?
+            }
+            _ => self.read_element(ty, Some(size), depth)?,
+        };
+        Ok(value)
+    }
+
+    /// Values that serialize identically at top level and inside containers.
+    fn read_element(&mut self, ty: &TypeInfo, size: Option<usize>, depth: usize) -> Result<PropertyValue, String> {
+        let value = match ty.name.as_str() {
+            "BoolProperty" | "ByteProperty" | "Int8Property" => {
+                let byte = self.ar.read_u8()?;
+                if ty.name == "BoolProperty" {
+                    PropertyValue::Bool(byte != 0)
+                } else if ty.name == "Int8Property" {
+                    PropertyValue::Int(byte as i8 as i64)
+                } else {
+                    PropertyValue::Int(byte as i64)
+                }
+            }
+            "Int16Property" => PropertyValue::Int(self.ar.read_i16()? as i64),
+            "UInt16Property" => PropertyValue::Int(self.ar.read_u16()? as i64),
+            "IntProperty" => PropertyValue::Int(self.ar.read_i32()? as i64),
+            "UInt32Property" => PropertyValue::Int(self.ar.read_u32()? as i64),
+            "Int64Property" => PropertyValue::Int(self.ar.read_i64()?),
+            "UInt64Property" => PropertyValue::Int(self.ar.read_u64()? as i64),
+            "FloatProperty" => PropertyValue::Float(self.ar.read_f32()? as f64),
+            "DoubleProperty" => PropertyValue::Float(self.ar.read_f64()?),
+            "NameProperty" => PropertyValue::Name(self.ar.read_name(self.package)?),
+            "EnumProperty" => PropertyValue::Enum(self.ar.read_name(self.package)?),
+            "StrProperty" => PropertyValue::Str(self.ar.read_fstring()?),
+            "TextProperty" => PropertyValue::Text(self.read_text()?),
+            "ObjectProperty" | "ClassProperty" | "WeakObjectProperty" | "InterfaceProperty" => self.read_object_ref()?,
+            "LazyObjectProperty" => PropertyValue::Guid(self.ar.read_guid()?),
+            "SoftObjectProperty" | "SoftClassProperty" | "AssetObjectProperty" => {
+                PropertyValue::SoftObject(self.read_soft_object()?)
+            }
+            "StructProperty" => {
+                let struct_type = ty.param(0).unwrap_or("Unknown").to_string();
+                self.read_struct(&struct_type, size, depth)?
+            }
+            _ => match size {
+                Some(size) => PropertyValue::Raw { size },
+                None => return Err(format!("Cannot read {} inside a container", ty.name)),
+            },
+        };
+        Ok(value)
+    }
+
+    fn read_count(&mut self) -> Result<usize, String> {
+        let count = self.ar.read_i32()?;
+        if !(0..=MAX_ARRAY_LEN).contains(&count) {
+            return Err(format!("Invalid element count {}", count));
+        }
+        Ok(count as usize)
+    }
+
+    fn read_array(&mut self, ty: &TypeInfo, size: usize, depth: usize) -> Result<PropertyValue, String> {
+        let count = self.read_count()?;
+        let mut inner = ty.params.first().cloned().unwrap_or_default();
+        if inner.name == "StructProperty"
+            && !self.complete_type_names()
+            && self.ar.file_version_ue4 >= VER_UE4_INNER_ARRAY_TAG_INFO
+        {
+            // Arrays of structs carry one extra tag describing the element struct.
+            match self.read_tag()? {
+                Some(tag) => inner = tag.ty,
+                None => return Ok(PropertyValue::Array(Vec::new())),
+            }
+        }
+        if inner.name == "ByteProperty" && count > 0 && size.saturating_sub(4) / count == 8 {
+            inner = TypeInfo::simple("NameProperty".to_string());
+        }
+        let mut items = Vec::with_capacity(count);
+        for _ in 0..count {
+            items.push(self.read_element(&inner, None, depth)?);
+        }
+        Ok(PropertyValue::Array(items))
+    }
+
+    fn read_set(&mut self, ty: &TypeInfo, depth: usize) -> Result<PropertyValue, String> {
+        let inner = ty.params.first().cloned().unwrap_or_default();
+        let removed = self.read_count()?;
+        for _ in 0..removed {
+            self.read_element(&inner, None, depth)?;
+        }
+        let count = self.read_count()?;
+        let mut items = Vec::with_capacity(count);
+        for _ in 0..count {
+            items.push(self.read_element(&inner, None, depth)?);
+        }
+        Ok(PropertyValue::Array(items))
+    }
+
+    fn read_map(&mut self, ty: &TypeInfo, depth: usize) -> Result<PropertyValue, String> {
+        let key = ty.params.first().cloned().unwrap_or_default();
+        let value = ty.params.get(1).cloned().unwrap_or_default();
+        let removed = self.read_count()?;
+        for _ in 0..removed {
+            self.read_element(&key, None, depth)?;
+        }
+        let count = self.read_count()?;
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            let k = self.read_element(&key, None, depth)?;
+            let v = self.read_element(&value, None, depth)?;
+            entries.push((k, v));
+        }
+        Ok(PropertyValue::Map(entries))
+    }
+}
@@ -0,0 +1,140 @@
+// "Grep for binary assets": find the packages that mention a literal, such as a material
+// parameter name or a string table key. Names, soft references and registry tags come from
+// the header; string property values only exist in the export data, which is searched raw.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::scan::UnrealAsset;
+use serde::Serialize;
+use std::path::Path;
+use tauri::State;
+
+const MAX_NAMES_PER_ASSET: usize = 10;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PropertyMatch {
+    pub path: String,
+    pub file_path: String,
+    pub asset_type: String,
+    /// `name_table`, `soft_references`, `registry_tags` and/or `data`.
+    pub found_in: Vec<String>,
+    /// Matching entries from the name table, soft references and tags.
+    pub matches: Vec<String>,
+}
+
+struct Query {
+    text: String,
+    lower: String,
+    exact: bool,
+}
+
+impl Query {
+    fn matches(&self, candidate: &str) -> bool {
+        if self.exact {
+            candidate.eq_ignore_ascii_case(&self.text)
+        } else {
+            candidate.to_lowercase().contains(&self.lower)
+        }
+    }
+}
+
+// ASCII needles match case-insensitively, both as 8-bit and as UTF-16LE strings (FStrings
+// with non-ASCII characters are saved as UTF-16).
+fn contains_bytes(haystack: &[u8], needle: &str) -> bool {
+    if needle.is_empty() {
+        return false;
+    }
+    let needle = needle.as_bytes();
+    if !needle.is_ascii() {
+        let wide: Vec<u8> = String::from_utf8_lossy(needle).encode_utf16().flat_map(|u| u.to_le_bytes()).collect();
+        return haystack.windows(needle.len()).any(|w| w == needle) || haystack.windows(wide.len()).any(|w| w == wide);
+    }
+    let narrow = haystack.windows(needle.len()).any(|w| w.eq_ignore_ascii_case(needle));
+    narrow
+        || haystack
+            .windows(needle.len() * 2)
+            .any(|w| w.chunks(2).zip(needle).all(|(pair, &c)| pair[1] == 0 && pair[0].eq_ignore_ascii_case(&c)))
+}
+
+fn search_asset(asset: &UnrealAsset, query: &Query, search_data: bool) -> Option<PropertyMatch> {
+    let file_path = Path::new(&asset.file_path);
+    let package = Package::read_file(file_path).ok()?;
+    let mut found_in = Vec::new();
+    let mut matches = Vec::new();
+    let mut collect = |source: &str, values: &mut dyn Iterator<Item = &String>| {
+        let hits: Vec<String> = values.filter(|v| query.matches(v)).cloned().collect();
+        if !hits.is_empty() {
+            found_in.push(source.to_string());
+            matches.extend(hits);
+        }
+    };
+    collect("name_table", &mut package.names.iter());
+    collect("soft_references", &mut package.soft_package_references.iter().chain(&package.soft_object_paths));
+    collect("registry_tags", &mut package.asset_registry.iter().flat_map(|o| o.tags.values()));
+
+    // Exact matches must be whole names, which a raw byte search can't tell apart.
+    if search_data && !query.exact {
+        let in_data = [file_path.to_path_buf(), file_path.with_extension("uexp")]
+            .iter()
+            .filter_map(|p| std::fs::read(p).ok())
+            .any(|bytes| contains_bytes(&bytes, &query.text));
+        if in_data {
+            found_in.push("data".to_string());
+        }
+    }
+    if found_in.is_empty() {
+        return None;
+    }
+    matches.sort();
+    matches.dedup();
+    matches.truncate(MAX_NAMES_PER_ASSET);
+    Some(PropertyMatch {
+        path: asset.path.clone(),
+        file_path: asset.file_path.clone(),
+        asset_type: asset.asset_type.clone(),
+        found_in,
+        matches,
+    })
+}
+
+pub fn search(assets: &[UnrealAsset], text: &str, exact: bool, search_data: bool) -> Vec<PropertyMatch> {
+    let query = Query { text: text.to_string(), lower: text.to_lowercase(), exact };
+    let packages: Vec<&UnrealAsset> = assets.iter().filter(|a| a.is_package() && !a.locked).collect();
+    let threads = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    let chunk_size = packages.len().div_ceil(threads).max(1);
+    let mut found = Vec::new();
+    std::thread::scope(|scope| {
+        let query = &query;
+        let handles: Vec<_> = packages
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope
+                    .spawn(move || chunk.iter().filter_map(|a| search_asset(a, query, search_data)).collect::<Vec<_>>())
+            })
+            .collect();
+        for handle in handles {
+            found.extend(handle.join().unwrap_or_default());
+        }
+    });
+    found.sort_by(|a, b| a.path.cmp(&b.path));
+    found
+}
+
+/// `exact` matches whole names only; `search_data` (default on) also looks through export
+/// data for string property values.
+#[tauri::command(async)]
+pub fn search_asset_properties(
+    manager: State<ScanManager>,
+    project: String,
+    query: String,
+    exact: Option<bool>,
+    search_data: Option<bool>,
+) -> CodexResult<Vec<PropertyMatch>> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(CodexError::message("empty_search", &[]));
+    }
+    let assets = manager.assets(&project)?;
+    Ok(search(&assets, query, exact.unwrap_or(false), search_data.unwrap_or(true)))
+}
@@ -0,0 +1,149 @@
+// Word-start index over asset names for the CTRL+P opener: a sorted list of name suffixes,
+// searched by binary search, with the best matches of every short prefix precomputed so the
+// broad one- and two-letter queries don't scan half the project.
+
+use crate::manager::ScanManager;
+use crate::scan::UnrealAsset;
+use std::collections::HashMap;
+use tauri::State;
+
+// Matches cached per short prefix; larger limits fall back to scanning the range.
+const CACHED_MATCHES: usize = 32;
+// Queries up to this many characters are answered from the cache.
+const CACHED_PREFIX_CHARS: usize = 2;
+
+/// Holds indices into the asset list it was built from, so it has to be rebuilt whenever that
+/// list changes and searched against the same list.
+#[derive(Default)]
+pub struct QuickOpenIndex {
+    // Lowercased names in rank order: shorter names first, so the tightest matches lead.
+    names: Vec<String>,
+    // Asset index of each rank.
+    assets: Vec<u32>,
+    // (rank, byte offset of a word start in its name), sorted by the suffix from there on.
+    keys: Vec<(u32, u32)>,
+    top: HashMap<String, Vec<u32>>,
+}
+
+impl QuickOpenIndex {
+    pub fn build(assets: &[UnrealAsset]) -> QuickOpenIndex {
+        let mut order: Vec<u32> = (0..assets.len() as u32).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&assets[a as usize].name, &assets[b as usize].name);
+            a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+        });
+        let mut index = QuickOpenIndex { assets: order, ..Default::default() };
+        for (rank, &id) in index.assets.iter().enumerate() {
+            let (name, starts) = word_starts(&assets[id as usize].name);
+            index.keys.extend(starts.into_iter().map(|start| (rank as u32, start)));
+            index.names.push(name);
+        }
+        let names = &index.names;
+        index.keys.sort_unstable_by(|&a, &b| suffix(names, a).cmp(suffix(names, b)).then(a.cmp(&b)));
+
+        // Keys are visited best rank first, so each prefix keeps its tightest matches.
+        let mut by_rank = index.keys.clone();
+        by_rank.sort_unstable();
+        for key in by_rank {
+            let mut prefix = String::new();
+            for c in suffix(&index.names, key).chars().take(CACHED_PREFIX_CHARS) {
+                prefix.push(c);
+                let top = index.top.entry(prefix.clone()).or_default();
+                if top.len() < CACHED_MATCHES && top.last() != Some(&key.0) {
+                    top.push(key.0);
+                }
+            }
+        }
+        index
+    }
+
+    /// Assets with a word starting with `query`, best first. `assets` must be the list the
+    /// index was built from.
+    pub fn lookup<'a>(&self, assets: &'a [UnrealAsset], query: &str, limit: usize) -> Vec<&'a UnrealAsset> {
+        let query = query.to_lowercase();
+        let ranks = match self.top.get(&query) {
+            // A full cache may have been cut short; below that it holds every match.
+            Some(top) if top.len() >= limit || top.len() < CACHED_MATCHES => top.iter().copied().take(limit).collect(),
+            _ => {
+                let start = self.keys.partition_point(|&k| suffix(&self.names, k) < query.as_str());
+                let len = self.keys[start..].partition_point(|&k| suffix(&self.names, k).starts_with(&query));
+                let mut ranks: Vec<u32> = self.keys[start..start + len].iter().map(|&(rank, _)| rank).collect();
+                ranks.sort_unstable();
+                ranks.dedup();
+                ranks.truncate(limit);
+                ranks
+            }
+        };
+        ranks.into_iter().filter_map(|rank| assets.get(self.assets[rank as usize] as usize)).collect()
+    }
+}
+
+fn suffix(names: &[String], (rank, start): (u32, u32)) -> &str {
+    &names[rank as usize][start as usize..]
+}
+
+// `BP_PlayerCharacter` is reachable as `bp_playercharacter`, `playercharacter` and `character`:
+// every word start (after `_` or a lower-to-upper case change) begins another key. Returns the
+// lowercased name and the byte offsets of its word starts.
+fn word_starts(name: &str) -> (String, Vec<u32>) {
+    let chars: Vec<char> = name.chars().collect();
+    let mut lower = String::with_capacity(name.len());
+    let mut starts = Vec::new();
+    for i in 0..chars.len() {
+        let word_start = i == 0
+            || (chars[i - 1] == '_' && chars[i] != '_')
+            || (chars[i - 1].is_lowercase() && chars[i].is_uppercase());
+        if word_start {
+            starts.push(lower.len() as u32);
+        }
+        lower.extend(chars[i].to_lowercase());
+    }
+    (lower, starts)
+}
+
+#[tauri::command]
+pub fn quick_open(manager: State<ScanManager>, query: String, limit: Option<usize>) -> Vec<UnrealAsset> {
+    manager.quick_open(query.trim(), limit.unwrap_or(20))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(name: &str) -> UnrealAsset {
+        UnrealAsset {
+            name: name.to_string(),
+            path: format!("/Game/{}", name),
+            file_path: format!("/project/Content/{}.uasset", name),
+            asset_type: "Asset".to_string(),
+            size: 0,
+            modified: 0,
+            is_large: false,
+            mount: "/Game".to_string(),
+            owner: None,
+            locked: false,
+            is_starter_content: false,
+        }
+    }
+
+    fn names(found: Vec<&UnrealAsset>) -> Vec<&str> {
+        found.into_iter().map(|a| a.name.as_str()).collect()
+    }
+
+    #[test]
+    fn matches_word_starts_shortest_first() {
+        let assets: Vec<_> = ["BP_PlayerCharacter", "SM_Chair", "BP_Character", "T_Charcoal_D", "M_Rich"]
+            .into_iter()
+            .map(asset)
+            .collect();
+        let index = QuickOpenIndex::build(&assets);
+
+        assert_eq!(names(index.lookup(&assets, "char", 10)), ["BP_Character", "T_Charcoal_D", "BP_PlayerCharacter"]);
+        assert_eq!(names(index.lookup(&assets, "Character", 10)), ["BP_Character", "BP_PlayerCharacter"]);
+        assert_eq!(names(index.lookup(&assets, "bp_p", 10)), ["BP_PlayerCharacter"]);
+        // Only word starts: "ich" sits inside "Rich".
+        assert!(index.lookup(&assets, "ich", 10).is_empty());
+        assert_eq!(names(index.lookup(&assets, "c", 2)), ["SM_Chair", "BP_Character"]);
+        assert!(index.lookup(&assets, "zz", 10).is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+// Folder READMEs: a generated `README.md` next to the content it describes, so the repo
+// documents itself. Only the block between the markers is ours; anything else in an
+// existing README is left alone.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use tauri::State;
+
+const BEGIN_MARKER: &str = "<!-- codex:begin (generated by Blueprint Codex, edits inside are replaced) -->";
+const END_MARKER: &str = "<!-- codex:end -->";
+const NOTABLE_DEPENDENCIES: usize = 10;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderReadme {
+    pub folder: String,
+    pub file_path: String,
+    pub asset_count: usize,
+    /// False when an existing README was updated in place.
+    pub created: bool,
+}
+
+fn format_size(bytes: u64) -> String {
+    let mb = bytes as f64 / crate::scan::MB as f64;
+    if mb >= 1.0 {
+        format!("{:.1} MB", mb)
+    } else {
+        format!("{:.0} KB", bytes as f64 / 1024.0)
+    }
+}
+
+fn type_of(asset: &IndexedAsset) -> &str {
+    asset.class.as_deref().unwrap_or(&asset.asset.asset_type)
+}
+
+pub fn render(index: &AssetIndex, folder: &str, assets: &[&IndexedAsset]) -> String {
+    let mut out = format!("# {}\n\n", folder);
+    let size: u64 = assets.iter().map(|a| a.size).sum();
+    out.push_str(&format!("{} assets, {} on disk.\n", assets.len(), format_size(size)));
+
+    let owners: BTreeSet<&str> = assets.iter().filter_map(|a| a.asset.owner.as_deref()).collect();
+    if !owners.is_empty() {
+        out.push_str(&format!("\nOwners: {}\n", owners.into_iter().collect::<Vec<_>>().join(", ")));
+    }
+
+    let mut types: BTreeMap<&str, usize> = BTreeMap::new();
+    for asset in assets {
+        *types.entry(type_of(asset)).or_default() += 1;
+    }
+    out.push_str("\n## Types\n\n| Type | Count |\n| --- | ---: |\n");
+    for (asset_type, count) in &types {
+        out.push_str(&format!("| {} | {} |\n", asset_type, count));
+    }
+
+    out.push_str("\n## Assets\n\n| Asset | Type | Size | Owner |\n| --- | --- | ---: | --- |\n");
+    for asset in assets {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            asset.asset.name,
+            type_of(asset),
+            format_size(asset.size),
+            asset.asset.owner.as_deref().unwrap_or("")
+        ));
+    }
+
+    // What this folder leans on from elsewhere in the project, most shared first.
+    let mut external: BTreeMap<String, usize> = BTreeMap::new();
+    for asset in assets {
+        let deps: BTreeSet<&str> =
+            asset.dependencies.iter().map(|d| crate::package::package_of_object_path(d)).collect();
+        for dep in deps {
+            if let Some(target) = index.get(dep).filter(|t| t.folder() != folder) {
+                *external.entry(target.asset.path.clone()).or_default() += 1;
+            }
+        }
+    }
+    if !external.is_empty() {
+        let mut ranked: Vec<(String, usize)> = external.into_iter().collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        out.push_str("\n## Notable dependencies\n\n");
+        for (path, count) in ranked.into_iter().take(NOTABLE_DEPENDENCIES) {
+            out.push_str(&format!("- `{}` (used by {} here)\n", path, count));
+        }
+    }
+    out
+}
+
+/// Replaces the generated block in `existing`, or appends one if there is none yet.
+fn merge(existing: &str, generated: &str) -> String {
+    let block = format!("{}\n{}{}\n", BEGIN_MARKER, generated, END_MARKER);
+    if let (Some(start), Some(end)) = (existing.find(BEGIN_MARKER), existing.find(END_MARKER)) {
+        if start < end {
+            let after = &existing[end + END_MARKER.len()..];
+            return format!("{}{}{}", &existing[..start], block.trim_end(), after);
+        }
+    }
+    let mut out = existing.trim_end().to_string();
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+    out.push_str(&block);
+    out
+}
+
+pub fn write_readmes(index: &AssetIndex) -> CodexResult<Vec<FolderReadme>> {
+    // Grouped by folder on disk; one-file-per-actor folders are machine-managed, skip them.
+    let mut folders: BTreeMap<PathBuf, (String, Vec<&IndexedAsset>)> = BTreeMap::new();
+    for asset in index.assets.iter().filter(|a| a.asset.is_package() && !a.folder().contains("/__External")) {
+        let Some(dir) = Path::new(&asset.asset.file_path).parent() else {
+            continue;
+        };
+        folders.entry(dir.to_path_buf()).or_insert_with(|| (asset.folder().to_string(), Vec::new())).1.push(asset);
+    }
+
+    let mut written = Vec::new();
+    for (dir, (folder, mut assets)) in folders {
+        assets.sort_by_key(|a| a.asset.name.to_lowercase());
+        let path = dir.join("README.md");
+        let existing = match std::fs::read_to_string(&path) {
+            Ok(text) => Some(text),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(CodexError::io(&path, &e)),
+        };
+        let text = merge(existing.as_deref().unwrap_or(""), &render(index, &folder, &assets));
+        std::fs::write(&path, text).map_err(|e| CodexError::io(&path, &e))?;
+        written.push(FolderReadme {
+            folder,
+            file_path: path.to_string_lossy().to_string(),
+            asset_count: assets.len(),
+            created: existing.is_none(),
+        });
+    }
+    Ok(written)
+}
+
+#[tauri::command]
+pub fn generate_folder_readmes(manager: State<ScanManager>, project: String) -> CodexResult<Vec<FolderReadme>> {
+    let index = manager.index(&project)?;
+    write_readmes(&index)
+}
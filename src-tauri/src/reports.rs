@@ -0,0 +1,412 @@
+// Nightly reports: at a configured local time, every project with a schedule runs the audits
+// and writes a timestamped JSON and/or HTML report into a folder of its choosing (a network
+// share, say), whether or not anyone has the window open. Tray mode keeps the app, and
+// therefore the schedule, running after the window is closed; the scheduled projects are
+// remembered in the app config folder, so a restart picks them up without a scan.
+
+use crate::broken_refs::BrokenReferenceReport;
+use crate::build_state::LevelBuildState;
+use crate::codex_db::{CodexDb, ReportSchedule};
+use crate::error::{CodexError, CodexResult};
+use crate::integrity::CorruptAsset;
+use crate::manager::ScanManager;
+use crate::source_art::MissingSource;
+use crate::ue5_features::Ue5FeatureAudit;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager, Runtime, State};
+
+const FORMATS: &[&str] = &["json", "html"];
+const SCHEDULED_PROJECTS_FILE: &str = "scheduled_reports.json";
+
+// Well under a minute, so a scheduled minute is never skipped.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LocalTime {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+}
+
+impl LocalTime {
+    fn date(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    fn stamp(&self) -> String {
+        format!("{:04}{:02}{:02}-{:02}{:02}", self.year, self.month, self.day, self.hour, self.minute)
+    }
+}
+
+#[cfg(unix)]
+fn local_now() -> LocalTime {
+    unsafe {
+        let now = libc::time(std::ptr::null_mut());
+        let mut tm: libc::tm = std::mem::zeroed();
+        libc::localtime_r(&now, &mut tm);
+        LocalTime {
+            year: tm.tm_year + 1900,
+            month: tm.tm_mon as u32 + 1,
+            day: tm.tm_mday as u32,
+            hour: tm.tm_hour as u32,
+            minute: tm.tm_min as u32,
+        }
+    }
+}
+
+#[cfg(windows)]
+fn local_now() -> LocalTime {
+    use windows_sys::Win32::Foundation::SYSTEMTIME;
+    use windows_sys::Win32::System::SystemInformation::GetLocalTime;
+    let mut time = SYSTEMTIME::default();
+    unsafe { GetLocalTime(&mut time) };
+    LocalTime {
+        year: time.wYear as i32,
+        month: time.wMonth as u32,
+        day: time.wDay as u32,
+        hour: time.wHour as u32,
+        minute: time.wMinute as u32,
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LargeAssetEntry {
+    pub path: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditReport {
+    pub project: String,
+    pub name: String,
+    /// Local time, `YYYY-MM-DD HH:MM`.
+    pub generated_at: String,
+    pub timestamp: u64,
+    pub asset_count: usize,
+    pub total_size: u64,
+    pub broken_references: BrokenReferenceReport,
+    pub corrupt_assets: Vec<CorruptAsset>,
+    /// Packages the damage check skipped: held open by the editor, or cooked/newer/compressed.
+    pub unchecked_packages: usize,
+    /// Largest first.
+    pub large_assets: Vec<LargeAssetEntry>,
+    pub missing_sources: Vec<MissingSource>,
+    pub stale_builds: Vec<LevelBuildState>,
+    pub ue5_features: Ue5FeatureAudit,
+    pub ungrouped_textures: Vec<String>,
+}
+
+/// `HH:MM`, 24-hour.
+fn parse_time(time: &str) -> CodexResult<(u32, u32)> {
+    time.trim()
+        .split_once(':')
+        .and_then(|(h, m)| Some((h.parse().ok()?, m.parse().ok()?)))
+        .filter(|&(h, m)| h < 24 && m < 60)
+        .ok_or_else(|| CodexError::message("invalid_report_time", &[("time", &time)]))
+}
+
+pub fn project_name(project: &str) -> String {
+    crate::project::find_uproject(Path::new(project))
+        .or_else(|| crate::uefn::find_uefnproject(Path::new(project)))
+        .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "Project".to_string())
+}
+
+pub fn build(manager: &ScanManager, project: &str) -> CodexResult<AuditReport> {
+    let index = manager.index(project)?;
+    let assets = manager.assets(project)?;
+    let now = local_now();
+    let mut large_assets: Vec<LargeAssetEntry> =
+        assets.iter().filter(|a| a.is_large).map(|a| LargeAssetEntry { path: a.path.clone(), size: a.size }).collect();
+    large_assets.sort_by_key(|a| std::cmp::Reverse(a.size));
+    let asset_count = assets.len();
+    let total_size = assets.iter().map(|a| a.size).sum();
+    let integrity = crate::integrity::check(assets);
+    let stale_builds = crate::build_state::build_state(&index)
+        .into_iter()
+        .filter(|l| l.lighting.as_ref().is_some_and(|b| b.stale) || l.hlod.as_ref().is_some_and(|b| b.stale))
+        .collect();
+    Ok(AuditReport {
+        project: project.to_string(),
+        name: project_name(project),
+        generated_at: format!("{} {:02}:{:02}", now.date(), now.hour, now.minute),
+        timestamp: crate::scan::unix_seconds(SystemTime::now()),
+        asset_count,
+        total_size,
+        broken_references: crate::broken_refs::find(&index),
+        corrupt_assets: integrity.corrupt,
+        unchecked_packages: integrity.locked.len() + integrity.unsupported.len() + integrity.unreadable.len(),
+        large_assets,
+        missing_sources: crate::source_art::find_missing_sources(&index),
+        stale_builds,
+        ue5_features: crate::ue5_features::audit(&index),
+        ungrouped_textures: crate::texture_settings::report(&index).ungrouped,
+    })
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn megabytes(bytes: u64) -> String {
+    format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+fn table(html: &mut String, title: &str, headers: &[&str], rows: Vec<Vec<String>>) {
+    html.push_str(&format!("<h2>{} ({})</h2>\n", escape(title), rows.len()));
+    if rows.is_empty() {
+        html.push_str("<p class=\"none\">None.</p>\n");
+        return;
+    }
+    html.push_str("<table>\n<tr>");
+    for header in headers {
+        html.push_str(&format!("<th>{}</th>", escape(header)));
+    }
+    html.push_str("</tr>\n");
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            html.push_str(&format!("<td>{}</td>", escape(&cell)));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+pub fn render_html(report: &AuditReport) -> String {
+    let mut html = String::new();
+    html.push_str(&format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>{} audit {}</title>\n<style>\
+         body{{font-family:sans-serif;margin:2em}}table{{border-collapse:collapse}}\
+         td,th{{border:1px solid #ccc;padding:4px 8px;text-align:left}}.none{{color:#888}}</style></head><body>\n",
+        escape(&report.name),
+        escape(&report.generated_at)
+    ));
+    html.push_str(&format!("<h1>{} audit</h1>\n", escape(&report.name)));
+    html.push_str(&format!(
+        "<p>{} &middot; {} assets &middot; {}</p>\n",
+        escape(&report.generated_at),
+        report.asset_count,
+        megabytes(report.total_size)
+    ));
+    let broken = &report.broken_references.broken;
+    let rows = broken
+        .iter()
+        .map(|b| vec![b.referencer.clone(), b.missing.clone(), if b.soft { "soft" } else { "hard" }.to_string()])
+        .collect();
+    table(&mut html, "Broken references", &["Referencer", "Missing", "Kind"], rows);
+    let rows = report
+        .corrupt_assets
+        .iter()
+        .map(|c| vec![c.path.clone(), serde_json::to_string(&c.problem).unwrap_or_default()])
+        .collect();
+    table(&mut html, "Damaged packages", &["Asset", "Problem"], rows);
+    if report.unchecked_packages > 0 {
+        html.push_str(&format!(
+            "<p class=\"none\">{} packages were locked by the editor or in a format the check can't read.</p>\n",
+            report.unchecked_packages
+        ));
+    }
+    let rows = report.large_assets.iter().map(|a| vec![a.path.clone(), megabytes(a.size)]).collect();
+    table(&mut html, "Large assets", &["Asset", "Size"], rows);
+    let rows = report
+        .missing_sources
+        .iter()
+        .map(|m| {
+            vec![m.asset.clone(), m.missing.iter().map(|s| s.relative_filename.clone()).collect::<Vec<_>>().join(", ")]
+        })
+        .collect();
+    table(&mut html, "Missing source files", &["Asset", "Source files"], rows);
+    let rows = report
+        .stale_builds
+        .iter()
+        .map(|l| {
+            let stale = |b: &Option<crate::build_state::BuildArtifact>| b.as_ref().is_some_and(|b| b.stale);
+            let what = [("lighting", stale(&l.lighting)), ("HLOD", stale(&l.hlod))];
+            vec![l.level.clone(), what.iter().filter(|w| w.1).map(|w| w.0).collect::<Vec<_>>().join(", ")]
+        })
+        .collect();
+    table(&mut html, "Stale builds", &["Level", "Out of date"], rows);
+    // Non-opaque materials only matter here when a Nanite mesh actually uses them.
+    let rows = report
+        .ue5_features
+        .unsupported_blend_modes
+        .iter()
+        .filter(|i| !i.used_by_nanite_meshes.is_empty())
+        .map(|i| vec![i.material.clone(), i.blend_mode.clone(), i.used_by_nanite_meshes.join(", ")])
+        .collect();
+    table(&mut html, "Nanite meshes with unsupported materials", &["Material", "Blend mode", "Nanite meshes"], rows);
+    let rows = report.ungrouped_textures.iter().map(|t| vec![t.clone()]).collect();
+    table(&mut html, "Textures without a LOD group", &["Texture"], rows);
+    html.push_str("</body></html>\n");
+    html
+}
+
+/// Writes the report in each format; returns the files written.
+pub fn write(report: &AuditReport, folder: &Path, formats: &[String], stamp: &str) -> CodexResult<Vec<PathBuf>> {
+    std::fs::create_dir_all(folder).map_err(|e| CodexError::io(folder, &e))?;
+    let mut written = Vec::new();
+    for format in formats {
+        let bytes = match format.as_str() {
+            "json" => serde_json::to_vec_pretty(report).map_err(|e| e.to_string())?,
+            "html" => render_html(report).into_bytes(),
+            _ => return Err(CodexError::message("unknown_output_format", &[("format", format)])),
+        };
+        let path = folder.join(format!("{}-audit-{}.{}", report.name, stamp, format));
+        std::fs::write(&path, bytes).map_err(|e| CodexError::io(&path, &e))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn run_report(manager: &ScanManager, project: &str, schedule: &ReportSchedule) -> CodexResult<Vec<PathBuf>> {
+    let report = build(manager, project)?;
+    write(&report, Path::new(&schedule.folder), &schedule.formats, &local_now().stamp())
+}
+
+fn scheduled_projects_file<R: Runtime>(app: &AppHandle<R>) -> Option<PathBuf> {
+    app.path_resolver().app_config_dir().map(|dir| dir.join(SCHEDULED_PROJECTS_FILE))
+}
+
+fn scheduled_projects<R: Runtime>(app: &AppHandle<R>) -> Vec<String> {
+    let Some(text) = scheduled_projects_file(app).and_then(|file| std::fs::read_to_string(file).ok()) else {
+        return Vec::new();
+    };
+    serde_json::from_str(&text).unwrap_or_default()
+}
+
+/// Adds `project` to the remembered scheduled projects, or removes it.
+fn remember_schedule<R: Runtime>(app: &AppHandle<R>, project: &str, scheduled: bool) -> CodexResult<()> {
+    let Some(file) = scheduled_projects_file(app) else {
+        return Ok(());
+    };
+    let mut projects = scheduled_projects(app);
+    if projects.iter().any(|p| p == project) == scheduled {
+        return Ok(());
+    }
+    projects.retain(|p| p != project);
+    if scheduled {
+        projects.push(project.to_string());
+    }
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| CodexError::io(dir, &e))?;
+    }
+    let json = serde_json::to_vec_pretty(&projects).map_err(|e| e.to_string())?;
+    std::fs::write(&file, json).map_err(|e| CodexError::io(&file, &e))
+}
+
+/// Starts the thread that checks the schedules of remembered and watched projects. Remembered
+/// projects are scanned and watched first, so their reports don't wait for someone to open them.
+pub fn start<R: Runtime>(app: AppHandle<R>) {
+    std::thread::Builder::new()
+        .name("nightly-reports".to_string())
+        .spawn(move || {
+            let manager = app.state::<ScanManager>();
+            for project in scheduled_projects(&app) {
+                // One that can't be scanned now (an unplugged drive) is scanned when its report is due.
+                let _ = manager.watch(&project, &manager.options(&project));
+            }
+            loop {
+                std::thread::sleep(CHECK_INTERVAL);
+                check_schedules(&app, &manager);
+            }
+        })
+        .expect("failed to start nightly report thread");
+}
+
+fn check_schedules<R: Runtime>(app: &AppHandle<R>, manager: &ScanManager) {
+    let now = local_now();
+    let mut projects = scheduled_projects(app);
+    for watched in manager.scheduler.status().projects {
+        if !projects.contains(&watched.project) {
+            projects.push(watched.project);
+        }
+    }
+    for project in projects {
+        let schedule = CodexDb::read(Path::new(&project)).map(|db| db.report_schedule);
+        // Also picks up schedules set before they were remembered, and drops removed ones; a
+        // project that's missing right now (an unplugged drive) keeps its place.
+        if let Ok(schedule) = &schedule {
+            if schedule.is_some() || Path::new(&project).is_dir() {
+                let _ = remember_schedule(app, &project, schedule.is_some());
+            }
+        }
+        let Ok(Some(schedule)) = schedule else {
+            continue;
+        };
+        let due = parse_time(&schedule.time).is_ok_and(|at| (now.hour, now.minute) >= at)
+            && schedule.last_run.as_deref() != Some(now.date().as_str());
+        if !due {
+            continue;
+        }
+        // Recorded first, so a failing report isn't retried every check for the rest of the day.
+        let _ = CodexDb::update(Path::new(&project), |db| {
+            if let Some(schedule) = db.report_schedule.as_mut() {
+                schedule.last_run = Some(now.date());
+            }
+            Ok(())
+        });
+        match run_report(manager, &project, &schedule) {
+            Ok(files) => {
+                let files: Vec<String> = files.iter().map(|f| f.to_string_lossy().to_string()).collect();
+                let _ = app.emit_all("report-written", (&project, files));
+            }
+            Err(error) => {
+                let _ = app.emit_all("report-failed", (&project, error));
+            }
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_report_schedule(project: String) -> CodexResult<Option<ReportSchedule>> {
+    Ok(CodexDb::read(Path::new(&project))?.report_schedule)
+}
+
+/// `time` is local `HH:MM`; an empty `folder` removes the schedule. `formats` defaults to
+/// both JSON and HTML.
+#[tauri::command]
+pub fn set_report_schedule(
+    app: AppHandle,
+    project: String,
+    time: String,
+    folder: String,
+    formats: Option<Vec<String>>,
+) -> CodexResult<Option<ReportSchedule>> {
+    let folder = folder.trim().to_string();
+    let formats = formats.unwrap_or_else(|| FORMATS.iter().map(|f| f.to_string()).collect());
+    if let Some(format) = formats.iter().find(|f| !FORMATS.contains(&f.as_str())) {
+        return Err(CodexError::message("unknown_output_format", &[("format", format)]));
+    }
+    if !folder.is_empty() {
+        parse_time(&time)?;
+    }
+    CodexDb::update(Path::new(&project), |db| {
+        db.report_schedule = Some(folder).filter(|f| !f.is_empty()).map(|folder| ReportSchedule {
+            time: time.trim().to_string(),
+            folder,
+            formats,
+            // Keep today's run, if there was one, from happening twice.
+            last_run: db.report_schedule.take().and_then(|s| s.last_run),
+        });
+        Ok(db.report_schedule.clone())
+    })
+    .and_then(|schedule| {
+        remember_schedule(&app, &project, schedule.is_some())?;
+        Ok(schedule)
+    })
+}
+
+/// Writes a report now with the stored schedule's folder and formats.
+#[tauri::command(async)]
+pub fn run_report_now(manager: State<ScanManager>, project: String) -> CodexResult<Vec<String>> {
+    let schedule = CodexDb::read(Path::new(&project))?
+        .report_schedule
+        .ok_or_else(|| CodexError::message("no_report_schedule", &[]))?;
+    let files = run_report(&manager, &project, &schedule)?;
+    Ok(files.iter().map(|f| f.to_string_lossy().to_string()).collect())
+}
@@ -0,0 +1,95 @@
+// Content review workflow: each asset is pending, approved or rejected, with who decided
+// and when. Assets never put up for review have no record.
+
+use crate::codex_db::{CodexDb, ReviewEvent, ReviewRecord, ReviewState};
+use crate::error::{CodexError, CodexResult};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::SystemTime;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingReview {
+    pub path: String,
+    pub requested_by: String,
+    pub since: u64,
+    pub note: Option<String>,
+}
+
+/// The key `path` is recorded under. Package paths are case-insensitive, so a record made as
+/// `/Game/Hero` is found again as `/game/hero`; a new record keeps the case it was given.
+fn record_key(reviews: &BTreeMap<String, ReviewRecord>, path: &str) -> String {
+    reviews.keys().find(|k| k.eq_ignore_ascii_case(path)).cloned().unwrap_or_else(|| path.to_string())
+}
+
+pub fn set_state(
+    project: &Path,
+    ue_path: &str,
+    state: ReviewState,
+    reviewer: &str,
+    note: Option<String>,
+) -> CodexResult<ReviewRecord> {
+    let path = crate::package::package_of_object_path(ue_path).to_string();
+    let event = ReviewEvent {
+        state,
+        reviewer: reviewer.to_string(),
+        timestamp: crate::scan::unix_seconds(SystemTime::now()),
+        note: note.clone(),
+    };
+    CodexDb::update(project, |db| {
+        let path = record_key(&db.reviews, &path);
+        // An asset without a record counts as pending, so it can be approved directly.
+        let current = db.reviews.get(&path).map(|r| r.state).unwrap_or(ReviewState::NeedsReview);
+        if db.reviews.contains_key(&path) && !current.can_move_to(state) {
+            return Err(CodexError::message(
+                "invalid_review_transition",
+                &[("path", &path), ("from", &current.as_str()), ("to", &state.as_str())],
+            ));
+        }
+        let mut history = db.reviews.remove(&path).map(|r| r.history).unwrap_or_default();
+        history.push(event.clone());
+        let record =
+            ReviewRecord { state, reviewer: event.reviewer, timestamp: event.timestamp, note: event.note, history };
+        db.reviews.insert(path, record.clone());
+        Ok(record)
+    })
+}
+
+/// Assets waiting for a verdict, longest-waiting first.
+pub fn pending(project: &Path) -> CodexResult<Vec<PendingReview>> {
+    let db = CodexDb::read(project)?;
+    let mut pending: Vec<PendingReview> = db
+        .reviews
+        .into_iter()
+        .filter(|(_, r)| r.state == ReviewState::NeedsReview)
+        .map(|(path, r)| PendingReview { path, requested_by: r.reviewer, since: r.timestamp, note: r.note })
+        .collect();
+    pending.sort_by(|a, b| a.since.cmp(&b.since).then_with(|| a.path.cmp(&b.path)));
+    Ok(pending)
+}
+
+#[tauri::command]
+pub fn set_review_state(
+    project: String,
+    path: String,
+    state: String,
+    reviewer: String,
+    note: Option<String>,
+) -> CodexResult<ReviewRecord> {
+    if reviewer.trim().is_empty() {
+        return Err(CodexError::message("reviewer_required", &[]));
+    }
+    set_state(Path::new(&project), &path, ReviewState::parse(&state)?, reviewer.trim(), note)
+}
+
+#[tauri::command]
+pub fn get_review_state(project: String, path: String) -> CodexResult<Option<ReviewRecord>> {
+    let db = CodexDb::read(Path::new(&project))?;
+    let key = record_key(&db.reviews, crate::package::package_of_object_path(&path));
+    Ok(db.reviews.get(&key).cloned())
+}
+
+#[tauri::command]
+pub fn list_pending_reviews(project: String) -> CodexResult<Vec<PendingReview>> {
+    pending(Path::new(&project))
+}
@@ -0,0 +1,239 @@
+// Walking a project's content roots and classifying what's in them.
+
+use crate::error::{CodexError, CodexResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnrealAsset {
+    pub name: String,
+    pub path: String,      // Relative path e.g. /Game/Folder/Asset
+    pub file_path: String, // Absolute file path
+    pub asset_type: String,
+    #[serde(default)]
+    pub size: u64,
+    /// Last write time, seconds since the Unix epoch.
+    #[serde(default)]
+    pub modified: u64,
+    #[serde(default)]
+    pub is_large: bool,
+    /// Mount point of the content root the asset was found in (`/Game`, `/MyPlugin`).
+    #[serde(default)]
+    pub mount: String,
+    /// Owning team(s) from the project's `.codexowners` file.
+    #[serde(default)]
+    pub owner: Option<String>,
+    /// Held open exclusively (usually by the editor) when scanned; header details are missing.
+    #[serde(default)]
+    pub locked: bool,
+    /// Inside an Epic Starter Content or project template folder.
+    #[serde(default)]
+    pub is_starter_content: bool,
+}
+
+impl UnrealAsset {
+    /// False for Verse sources, which are indexed alongside packages but have no header.
+    pub fn is_package(&self) -> bool {
+        self.file_path.ends_with(".uasset") || self.file_path.ends_with(".umap")
+    }
+}
+
+pub const MB: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ScanOptions {
+    pub large_asset_threshold_mb: u64,
+    // Maps get their own limit; big persistent levels are normal, big props are not.
+    pub large_map_threshold_mb: u64,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        ScanOptions {
+            large_asset_threshold_mb: 100,
+            large_map_threshold_mb: 500,
+        }
+    }
+}
+
+impl ScanOptions {
+    pub fn threshold_for(&self, asset_type: &str) -> u64 {
+        if asset_type == "Level" {
+            self.large_map_threshold_mb * MB
+        } else {
+            self.large_asset_threshold_mb * MB
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LargeAsset {
+    pub path: String,
+    pub file_path: String,
+    pub size: u64,
+    pub threshold: u64,
+}
+
+/// A folder of packages and the mount point its paths resolve under (`/Game` for Content).
+#[derive(Debug, Clone)]
+pub struct ContentRoot {
+    pub path: PathBuf,
+    pub mount: String,
+}
+
+impl ContentRoot {
+    /// `/Game/Folder/Asset` for `<root>/Folder/Asset.uasset`.
+    pub fn package_path(&self, file: &Path) -> Option<String> {
+        let relative = file.strip_prefix(&self.path).ok()?.to_string_lossy().replace('\\', "/");
+        let relative = relative.rsplit_once('.').map(|(a, _)| a).unwrap_or(&relative);
+        Some(format!("{}/{}", self.mount, relative))
+    }
+}
+
+// UEFN projects keep their content in plugins; a regular project's Content folder is /Game.
+pub fn project_content_roots(project: &Path) -> Vec<ContentRoot> {
+    let mut roots = Vec::new();
+    let content = project.join("Content");
+    if content.is_dir() {
+        roots.push(ContentRoot {
+            path: content,
+            mount: "/Game".to_string(),
+        });
+    }
+    if crate::uefn::find_uefnproject(project).is_some() {
+        roots.extend(
+            crate::uefn::plugin_content_roots(project)
+                .into_iter()
+                .map(|(path, mount)| ContentRoot { path, mount }),
+        );
+    }
+    roots
+}
+
+/// `roots` are the project's own roots plus anything mounted into it, see
+/// `ScanManager::content_roots`.
+pub fn scan_project(path: &str, roots: &[ContentRoot], options: &ScanOptions) -> CodexResult<Vec<UnrealAsset>> {
+    if roots.is_empty() {
+        return Err(CodexError::missing_content(Path::new(path)));
+    }
+
+    let mut assets = Vec::new();
+
+    for root in roots {
+        for entry in WalkDir::new(&root.path).into_iter().filter_map(|e| e.ok()) {
+            if let Some(asset) = asset_from_path(root, entry.path(), options) {
+                assets.push(asset);
+            }
+        }
+    }
+    crate::ownership::assign_owners(Path::new(path), &mut assets);
+
+    Ok(assets)
+}
+
+pub fn asset_from_path(root: &ContentRoot, path: &Path, options: &ScanOptions) -> Option<UnrealAsset> {
+    classify(root, path, options, true)
+}
+
+/// Like `asset_from_path` but from the path alone: the file isn't opened, so names without a
+/// telling prefix stay "Asset" and `locked` is always false.
+pub fn asset_from_name(root: &ContentRoot, path: &Path, options: &ScanOptions) -> Option<UnrealAsset> {
+    classify(root, path, options, false)
+}
+
+fn classify(root: &ContentRoot, path: &Path, options: &ScanOptions, read_header: bool) -> Option<UnrealAsset> {
+    if !path.is_file() {
+        return None;
+    }
+    let ext = path.extension()?;
+    let is_verse = crate::uefn::is_verse_source(path);
+    if ext != "uasset" && ext != "umap" && !is_verse {
+        return None;
+    }
+    let file_name = path.file_stem()?.to_string_lossy().to_string();
+    // One open, without the lock retries: it tells whether the editor holds the file and feeds
+    // the header read below. A locked file is picked up again by the next background rescan.
+    let file = read_header.then(|| std::fs::File::open(path));
+    let locked = matches!(&file, Some(Err(e)) if crate::file_lock::is_lock_error(e));
+
+    // Determine type based on prefix (heuristic)
+    let asset_type = if is_verse {
+        crate::uefn::verse_asset_type(path)
+    } else if ext == "umap" {
+        "Level".to_string()
+    } else if file_name.starts_with("BP_") {
+        "Blueprint".to_string()
+    } else if file_name.starts_with("M_") {
+        "Material".to_string()
+    } else if file_name.starts_with("SM_") {
+        "StaticMesh".to_string()
+    } else if file_name.starts_with("T_") {
+        "Texture".to_string()
+    } else if file_name.starts_with("NS_") {
+        "NiagaraSystem".to_string()
+    } else if file_name.starts_with("NE_") {
+        "NiagaraEmitter".to_string()
+    } else if file_name.starts_with("BT_") {
+        "BehaviorTree".to_string()
+    } else if file_name.starts_with("BB_") {
+        "BlackboardData".to_string()
+    } else if file_name.starts_with("GC_") {
+        "GeometryCollection".to_string()
+    } else {
+        // No telling prefix: read the header for the classes worth telling apart.
+        file.and_then(Result::ok).and_then(header_asset_type).unwrap_or_else(|| "Asset".to_string())
+    };
+
+    // UE reference path without extension (e.g. /Game/...)
+    let ue_path_no_ext = root.package_path(path)?;
+    let is_starter_content = crate::starter::is_starter_content(&ue_path_no_ext);
+
+    let metadata = path.metadata().ok();
+    let size = metadata.as_ref().map(|m| m.len()).unwrap_or(0);
+    let modified = metadata.and_then(|m| m.modified().ok()).map(unix_seconds).unwrap_or(0);
+    let is_large = size > options.threshold_for(&asset_type);
+
+    Some(UnrealAsset {
+        name: file_name,
+        path: ue_path_no_ext,
+        file_path: path.to_string_lossy().to_string(),
+        asset_type,
+        size,
+        modified,
+        is_large,
+        mount: root.mount.clone(),
+        owner: None,
+        locked,
+        is_starter_content,
+    })
+}
+
+// Classes worth reporting as their own asset_type when the name gives no hint.
+const HEADER_CLASSES: &[&str] = &[
+    "NiagaraSystem",
+    "NiagaraEmitter",
+    "ParticleSystem",
+    "MetaSoundSource",
+    "MetaSoundPatch",
+    "BehaviorTree",
+    "BlackboardData",
+    "CurveFloat",
+    "CurveVector",
+    "CurveLinearColor",
+    "PhysicalMaterial",
+    "GeometryCollection",
+    "GeometryCollectionCache",
+    "ChaosCacheCollection",
+];
+
+fn header_asset_type(file: std::fs::File) -> Option<String> {
+    // Only the object tables: the soft references and registry tags of a full read aren't needed.
+    let class = crate::package::Package::read_main_class(&mut std::io::BufReader::new(file)).ok()??;
+    HEADER_CLASSES.contains(&class.as_str()).then_some(class)
+}
+
+pub fn unix_seconds(time: std::time::SystemTime) -> u64 {
+    time.duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
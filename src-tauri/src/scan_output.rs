@@ -0,0 +1,79 @@
+// Scan results for very large projects: instead of a JSON reply over IPC, the assets are
+// written to a temp file (MessagePack by default) and only a handle crosses the bridge.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::scan::{ScanOptions, UnrealAsset};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::State;
+
+const FILE_PREFIX: &str = "blueprint-codex-scan-";
+
+static NEXT_FILE: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanFileHandle {
+    pub file_path: String,
+    /// `msgpack` (field names kept, so it decodes to the same objects as the JSON reply) or `json`.
+    pub format: String,
+    pub asset_count: usize,
+    pub bytes: usize,
+}
+
+pub fn encode(assets: &[UnrealAsset], format: &str) -> CodexResult<Vec<u8>> {
+    match format {
+        "msgpack" => rmp_serde::to_vec_named(assets).map_err(|e| e.to_string().into()),
+        "json" => serde_json::to_vec(assets).map_err(|e| e.to_string().into()),
+        _ => Err(CodexError::message("unknown_output_format", &[("format", &format)])),
+    }
+}
+
+fn output_path(format: &str) -> PathBuf {
+    let n = NEXT_FILE.fetch_add(1, Ordering::Relaxed);
+    std::env::temp_dir().join(format!("{}{}-{}.{}", FILE_PREFIX, std::process::id(), n, format))
+}
+
+pub fn write(assets: &[UnrealAsset], format: &str) -> CodexResult<ScanFileHandle> {
+    let bytes = encode(assets, format)?;
+    let path = output_path(format);
+    std::fs::write(&path, &bytes).map_err(|e| CodexError::io(&path, &e))?;
+    Ok(ScanFileHandle {
+        file_path: path.to_string_lossy().to_string(),
+        format: format.to_string(),
+        asset_count: assets.len(),
+        bytes: bytes.len(),
+    })
+}
+
+/// Only files this module wrote may be removed through the command.
+fn is_scan_file(path: &Path) -> bool {
+    path.parent() == Some(std::env::temp_dir().as_path())
+        && path.file_name().is_some_and(|n| n.to_string_lossy().starts_with(FILE_PREFIX))
+}
+
+#[tauri::command]
+pub fn scan_unreal_project_to_file(
+    window: tauri::Window,
+    manager: State<ScanManager>,
+    path: String,
+    options: Option<ScanOptions>,
+    format: Option<String>,
+) -> CodexResult<ScanFileHandle> {
+    let format = format.unwrap_or_else(|| "msgpack".to_string());
+    // Reject a bad format before spending a full scan on it.
+    encode(&[], &format)?;
+    let assets = manager.scan(&window, &path, options.unwrap_or_default())?;
+    write(&assets, &format)
+}
+
+/// Deletes a file returned by `scan_unreal_project_to_file` once the frontend has read it.
+#[tauri::command]
+pub fn release_scan_file(file_path: String) -> CodexResult<()> {
+    let path = Path::new(&file_path);
+    if !is_scan_file(path) {
+        return Err(CodexError::message("not_scan_output", &[("path", &file_path)]));
+    }
+    std::fs::remove_file(path).map_err(|e| CodexError::io(path, &e))
+}
@@ -0,0 +1,409 @@
+// Background rescans: a single low-priority thread periodically re-walks every watched
+// project and reports what changed, so the index keeps up without a manual rescan.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::scan::{ContentRoot, ScanOptions, UnrealAsset};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager, Runtime, State};
+use walkdir::WalkDir;
+
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScanDelta {
+    pub project: String,
+    pub added: Vec<UnrealAsset>,
+    pub modified: Vec<UnrealAsset>,
+    /// Package paths (`/Game/...`) that no longer exist.
+    pub removed: Vec<String>,
+}
+
+impl ScanDelta {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SchedulerStatus {
+    pub interval_secs: u64,
+    pub paused: bool,
+    pub projects: Vec<WatchedProject>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchedProject {
+    pub project: String,
+    /// Event this project's deltas are emitted on, in addition to `scan-updated`.
+    pub event: String,
+    pub paused: bool,
+    pub tracked_files: usize,
+    /// When the last rescan finished, seconds since the Unix epoch (0 = not yet).
+    pub last_rescan: u64,
+}
+
+struct Tracked {
+    // Changes whenever a full scan replaces the baseline, so a rescan that raced it is dropped.
+    id: u64,
+    channel: u64,
+    options: ScanOptions,
+    paused: bool,
+    last_rescan: u64,
+    // file_path -> (modified, size, package path)
+    known: HashMap<String, (u64, u64, String)>,
+    // Files that were locked when last looked at; re-checked on every pass.
+    locked: HashSet<String>,
+}
+
+struct SchedulerState {
+    interval_secs: u64,
+    paused: bool,
+    projects: BTreeMap<String, Tracked>,
+    next_id: u64,
+    // Bumped on every settings change so a sleeping thread knows to restart its wait.
+    generation: u64,
+    // Set by `rescan_now`; the next pass runs immediately and includes paused projects.
+    rescan_requested: bool,
+}
+
+impl SchedulerState {
+    fn status(&self) -> SchedulerStatus {
+        SchedulerStatus {
+            interval_secs: self.interval_secs,
+            paused: self.paused,
+            projects: self
+                .projects
+                .iter()
+                .map(|(project, t)| WatchedProject {
+                    project: project.clone(),
+                    event: channel_event(t.channel),
+                    paused: t.paused,
+                    tracked_files: t.known.len(),
+                    last_rescan: t.last_rescan,
+                })
+                .collect(),
+        }
+    }
+}
+
+fn channel_event(channel: u64) -> String {
+    format!("scan-updated:{}", channel)
+}
+
+#[derive(Clone)]
+pub struct RescanScheduler {
+    shared: Arc<(Mutex<SchedulerState>, Condvar)>,
+}
+
+impl Default for RescanScheduler {
+    fn default() -> Self {
+        RescanScheduler {
+            shared: Arc::new((
+                Mutex::new(SchedulerState {
+                    interval_secs: DEFAULT_INTERVAL_SECS,
+                    paused: false,
+                    projects: BTreeMap::new(),
+                    next_id: 0,
+                    generation: 0,
+                    rescan_requested: false,
+                }),
+                Condvar::new(),
+            )),
+        }
+    }
+}
+
+impl RescanScheduler {
+    pub fn start<R: Runtime>(&self, app: AppHandle<R>) {
+        let scheduler = self.clone();
+        std::thread::Builder::new()
+            .name("background-rescan".to_string())
+            .spawn(move || {
+                lower_thread_priority();
+                scheduler.run(app);
+            })
+            .expect("failed to start background rescan thread");
+    }
+
+    /// Remembers the result of a full scan as the baseline for incremental rescans, and
+    /// starts watching the project if it wasn't already.
+    pub fn track(&self, project: &str, options: &ScanOptions, assets: &[UnrealAsset]) -> WatchedProject {
+        let (lock, wake) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        state.next_id += 1;
+        let id = state.next_id;
+        let previous = state.projects.remove(project);
+        let tracked = Tracked {
+            id,
+            // A project keeps its channel across rescans so listeners stay subscribed.
+            channel: previous.as_ref().map(|p| p.channel).unwrap_or(id),
+            options: options.clone(),
+            paused: previous.as_ref().is_some_and(|p| p.paused),
+            last_rescan: previous.as_ref().map(|p| p.last_rescan).unwrap_or(0),
+            known: assets.iter().map(|a| (a.file_path.clone(), (a.modified, a.size, a.path.clone()))).collect(),
+            locked: assets.iter().filter(|a| a.locked).map(|a| a.file_path.clone()).collect(),
+        };
+        state.projects.insert(project.to_string(), tracked);
+        state.generation += 1;
+        wake.notify_all();
+        state.status().projects.into_iter().find(|p| p.project == project).expect("project was just inserted")
+    }
+
+    pub fn untrack(&self, project: &str) -> SchedulerStatus {
+        let (lock, wake) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        state.projects.remove(project);
+        state.generation += 1;
+        wake.notify_all();
+        state.status()
+    }
+
+    /// Settings for every project: the rescan interval and the global pause.
+    pub fn configure(&self, interval_secs: Option<u64>, paused: Option<bool>) -> SchedulerStatus {
+        let (lock, wake) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        if let Some(interval_secs) = interval_secs {
+            state.interval_secs = interval_secs;
+        }
+        if let Some(paused) = paused {
+            state.paused = paused;
+        }
+        state.generation += 1;
+        wake.notify_all();
+        state.status()
+    }
+
+    /// Pauses or resumes one watched project, keyed by the path it was tracked under.
+    pub fn pause_project(&self, project: &str, paused: bool) -> CodexResult<SchedulerStatus> {
+        let (lock, wake) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        let Some(tracked) = state.projects.get_mut(project) else {
+            return Err(CodexError::message("project_not_watched", &[("project", &project)]));
+        };
+        tracked.paused = paused;
+        state.generation += 1;
+        wake.notify_all();
+        Ok(state.status())
+    }
+
+    pub fn status(&self) -> SchedulerStatus {
+        self.shared.0.lock().unwrap().status()
+    }
+
+    /// Rescans every watched project now rather than at the next interval.
+    pub fn rescan_now(&self) {
+        let (lock, wake) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        state.rescan_requested = true;
+        state.generation += 1;
+        wake.notify_all();
+    }
+
+    fn run<R: Runtime>(&self, app: AppHandle<R>) {
+        let (lock, wake) = &*self.shared;
+        loop {
+            let mut state = lock.lock().unwrap();
+            let generation = state.generation;
+            let forced = std::mem::take(&mut state.rescan_requested);
+            if !forced {
+                let active = state.interval_secs > 0 && !state.paused && state.projects.values().any(|t| !t.paused);
+                if !active {
+                    drop(wake.wait_while(state, |s| s.generation == generation).unwrap());
+                    continue;
+                }
+                let interval = Duration::from_secs(state.interval_secs);
+                let (guard, timeout) =
+                    wake.wait_timeout_while(state, interval, |s| s.generation == generation).unwrap();
+                state = guard;
+                if !timeout.timed_out() {
+                    continue;
+                }
+            }
+
+            // Projects are rescanned one after another, each without holding the lock; a
+            // result is kept only if no full scan replaced that project's baseline meanwhile.
+            let due: Vec<(String, u64)> =
+                state.projects.iter().filter(|(_, t)| forced || !t.paused).map(|(p, t)| (p.clone(), t.id)).collect();
+            drop(state);
+            for (project, id) in due {
+                let snapshot = {
+                    let state = lock.lock().unwrap();
+                    match state.projects.get(&project) {
+                        Some(t) if t.id == id => (t.options.clone(), t.known.clone(), t.locked.clone(), t.channel),
+                        _ => continue,
+                    }
+                };
+                let (options, known, locked, channel) = snapshot;
+                let roots = app.state::<ScanManager>().content_roots(Path::new(&project));
+                let result = rescan(&project, &roots, &options, &known, &locked);
+
+                let mut state = lock.lock().unwrap();
+                if let Some(tracked) = state.projects.get_mut(&project).filter(|t| t.id == id) {
+                    tracked.known = result.known;
+                    tracked.locked = result.locked;
+                    tracked.last_rescan = crate::scan::unix_seconds(SystemTime::now());
+                }
+                drop(state);
+                app.state::<ScanManager>().apply(&result.delta, &result.unlocked);
+                if !result.delta.is_empty() {
+                    let _ = app.emit_all(&channel_event(channel), result.delta.clone());
+                    let _ = app.emit_all("scan-updated", result.delta);
+                    // Something changed, so the audits may have new findings worth posting.
+                    let _ = crate::notifier::after_rescan(&app, &project);
+                }
+                if !result.unlocked.is_empty() {
+                    let _ = app.emit_all("asset-unlocked", result.unlocked);
+                }
+            }
+        }
+    }
+}
+
+struct Rescan {
+    delta: ScanDelta,
+    known: HashMap<String, (u64, u64, String)>,
+    locked: HashSet<String>,
+    /// Previously locked assets that can be read again, now with full details.
+    unlocked: Vec<UnrealAsset>,
+}
+
+fn rescan(
+    project: &str,
+    roots: &[ContentRoot],
+    options: &ScanOptions,
+    previous: &HashMap<String, (u64, u64, String)>,
+    previously_locked: &HashSet<String>,
+) -> Rescan {
+    let mut delta =
+        ScanDelta { project: project.to_string(), added: Vec::new(), modified: Vec::new(), removed: Vec::new() };
+    let mut known = HashMap::new();
+    let mut locked = HashSet::new();
+    let mut unlocked = Vec::new();
+    let entries = roots
+        .iter()
+        .flat_map(|root| WalkDir::new(&root.path).into_iter().filter_map(|e| e.ok()).map(move |e| (root, e)));
+    for (root, entry) in entries {
+        let file_path = entry.path().to_string_lossy().to_string();
+        // Cheap metadata check first; only changed files go through classification.
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let modified = metadata.modified().map(crate::scan::unix_seconds).unwrap_or(0);
+        let was_locked = previously_locked.contains(&file_path);
+        if let Some((m, s, path)) = previous.get(&file_path).filter(|_| !was_locked) {
+            if *m == modified && *s == metadata.len() {
+                known.insert(file_path, (*m, *s, path.clone()));
+                continue;
+            }
+        }
+        let Some(asset) = crate::scan::asset_from_path(root, entry.path(), options) else {
+            continue;
+        };
+        known.insert(file_path.clone(), (asset.modified, asset.size, asset.path.clone()));
+        if asset.locked {
+            locked.insert(file_path.clone());
+        }
+        let unchanged = previous.get(&file_path).is_some_and(|(m, s, _)| *m == asset.modified && *s == asset.size);
+        if was_locked && !asset.locked {
+            unlocked.push(asset.clone());
+        }
+        if unchanged {
+            continue;
+        }
+        if previous.contains_key(&file_path) {
+            delta.modified.push(asset);
+        } else {
+            delta.added.push(asset);
+        }
+    }
+    crate::ownership::assign_owners(Path::new(project), &mut delta.added);
+    crate::ownership::assign_owners(Path::new(project), &mut delta.modified);
+    crate::ownership::assign_owners(Path::new(project), &mut unlocked);
+    delta.removed = previous
+        .iter()
+        .filter(|(file_path, _)| !known.contains_key(*file_path))
+        .map(|(_, (_, _, path))| path.clone())
+        .collect();
+    Rescan { delta, known, locked, unlocked }
+}
+
+/// Drops the calling thread to background priority so rescans don't compete with the editor.
+#[cfg(target_os = "linux")]
+fn lower_thread_priority() {
+    const IOPRIO_WHO_PROCESS: libc::c_long = 1;
+    const IOPRIO_CLASS_IDLE: libc::c_long = 3;
+    const IOPRIO_CLASS_SHIFT: libc::c_long = 13;
+    unsafe {
+        // On Linux both apply to the calling thread only, not the whole process.
+        let tid = libc::syscall(libc::SYS_gettid) as libc::id_t;
+        libc::setpriority(libc::PRIO_PROCESS, tid, 19);
+        libc::syscall(libc::SYS_ioprio_set, IOPRIO_WHO_PROCESS, 0, IOPRIO_CLASS_IDLE << IOPRIO_CLASS_SHIFT);
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn lower_thread_priority() {
+    unsafe {
+        // Background band: lowest CPU priority plus throttled disk and network I/O.
+        libc::setpriority(libc::PRIO_DARWIN_THREAD, 0, libc::PRIO_DARWIN_BG);
+    }
+}
+
+#[cfg(windows)]
+fn lower_thread_priority() {
+    use windows_sys::Win32::System::Threading::{GetCurrentThread, SetThreadPriority, THREAD_MODE_BACKGROUND_BEGIN};
+    unsafe {
+        // Background mode lowers CPU, I/O and memory priority together.
+        SetThreadPriority(GetCurrentThread(), THREAD_MODE_BACKGROUND_BEGIN);
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn lower_thread_priority() {}
+
+#[tauri::command]
+pub fn set_rescan_interval(manager: State<ScanManager>, seconds: u64) -> SchedulerStatus {
+    manager.scheduler.configure(Some(seconds), None)
+}
+
+/// Pauses every project, or just `project` when given; a project that isn't watched is an error.
+#[tauri::command]
+pub fn pause_background_scans(
+    manager: State<ScanManager>,
+    paused: bool,
+    project: Option<String>,
+) -> CodexResult<SchedulerStatus> {
+    match project {
+        Some(project) => manager.scheduler.pause_project(&project, paused),
+        None => Ok(manager.scheduler.configure(None, Some(paused))),
+    }
+}
+
+/// Scans `project` and keeps watching it alongside any other watched projects.
+#[tauri::command]
+pub fn watch_project(
+    manager: State<ScanManager>,
+    project: String,
+    options: Option<ScanOptions>,
+) -> CodexResult<WatchedProject> {
+    let options = options.unwrap_or_else(|| manager.options(&project));
+    manager.watch(&project, &options)
+}
+
+/// Stops watching `project` and drops its stored results; the next command that needs
+/// them scans it again.
+#[tauri::command]
+pub fn unwatch_project(manager: State<ScanManager>, project: String) -> SchedulerStatus {
+    manager.forget(&project);
+    manager.scheduler.status()
+}
+
+#[tauri::command]
+pub fn list_watched_projects(manager: State<ScanManager>) -> SchedulerStatus {
+    manager.scheduler.status()
+}
@@ -0,0 +1,161 @@
+// Level Sequences: what a cinematic binds to (possessed level actors and spawned ones, with
+// their classes), which tracks it animates and how long it runs. Everything is read from the
+// sequence's MovieScene export and the track objects saved inside it.
+
+use crate::error::{CodexError, CodexResult};
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+const SEQUENCE_CLASSES: &[&str] = &["LevelSequence", "TemplateSequence"];
+
+// TRangeBound type of an open (unbounded) end.
+const OPEN_BOUND: f64 = 2.0;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SequenceBinding {
+    pub name: String,
+    pub guid: String,
+    /// `possessable` (an actor in the level) or `spawnable` (created by the sequence).
+    pub kind: String,
+    pub class: Option<String>,
+    /// Track classes, e.g. `MovieScene3DTransformTrack`.
+    pub tracks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SequenceInfo {
+    pub class: String,
+    /// Frames per second the sequence is edited at.
+    pub display_rate: Option<f64>,
+    /// Playback range in display frames.
+    pub start_frame: Option<i64>,
+    pub end_frame: Option<i64>,
+    pub duration_seconds: Option<f64>,
+    pub bindings: Vec<SequenceBinding>,
+    /// Tracks not bound to an object (camera cuts, audio, fades, ...).
+    pub root_tracks: Vec<String>,
+    /// Track class -> count, bound and root tracks together.
+    pub track_counts: BTreeMap<String, usize>,
+}
+
+/// `[numerator, denominator]` as frames per second.
+fn frame_rate(value: Option<&PropertyValue>) -> Option<f64> {
+    match value? {
+        PropertyValue::Numbers(n) if n.len() == 2 && n[0] > 0.0 && n[1] > 0.0 => Some(n[0] / n[1]),
+        _ => None,
+    }
+}
+
+fn short_class(path: &str) -> String {
+    path.rsplit(['.', '/']).next().unwrap_or(path).to_string()
+}
+
+fn object_class(package: &Package, value: Option<&PropertyValue>) -> Option<String> {
+    let path = value?.as_str()?;
+    package.export_by_path(path).map(|e| package.export_class(e))
+}
+
+fn object_list(props: &[Property], name: &str) -> Vec<String> {
+    match properties::find(props, name) {
+        Some(PropertyValue::Array(items)) => items.iter().filter_map(|i| i.as_str()).map(str::to_string).collect(),
+        Some(value) => value.as_str().map(str::to_string).into_iter().collect(),
+        None => Vec::new(),
+    }
+}
+
+fn entries<'a>(props: &'a [Property], name: &str) -> &'a [PropertyValue] {
+    match properties::find(props, name) {
+        Some(PropertyValue::Array(items)) => items,
+        _ => &[],
+    }
+}
+
+fn field_str(value: &PropertyValue, name: &str) -> String {
+    value.field(name).and_then(|v| v.as_str()).unwrap_or_default().to_string()
+}
+
+fn field_guid(value: &PropertyValue, name: &str) -> String {
+    match value.field(name) {
+        Some(PropertyValue::Guid(guid)) => guid.clone(),
+        _ => String::new(),
+    }
+}
+
+pub fn inspect(file_path: &Path) -> CodexResult<SequenceInfo> {
+    let package = Package::read_file(file_path)?;
+    let class = package.main_class().unwrap_or_default();
+    if !SEQUENCE_CLASSES.contains(&class.as_str()) {
+        return Err(CodexError::message("not_a_level_sequence", &[("class", &class)]));
+    }
+    let movie_scene = package
+        .exports
+        .iter()
+        .find(|e| package.export_class(e) == "MovieScene")
+        .ok_or_else(|| CodexError::message("no_main_export", &[]))?;
+    let props = properties::read_export_properties(file_path, &package, movie_scene)?;
+    let mut info = SequenceInfo { class, ..Default::default() };
+
+    // The playback range is in tick resolution units; the UI shows display frames.
+    let tick_rate = frame_rate(properties::find(&props, "TickResolution"));
+    info.display_rate = frame_rate(properties::find(&props, "DisplayRate"));
+    if let (Some(tick_rate), Some(PropertyValue::Numbers(range))) =
+        (tick_rate, properties::find(&props, "PlaybackRange"))
+    {
+        if range.len() == 4 && range[0] != OPEN_BOUND && range[2] != OPEN_BOUND {
+            let (start, end) = (range[1] / tick_rate, range[3] / tick_rate);
+            info.duration_seconds = Some(end - start);
+            if let Some(display_rate) = info.display_rate {
+                info.start_frame = Some((start * display_rate).round() as i64);
+                info.end_frame = Some((end * display_rate).round() as i64);
+            }
+        }
+    }
+
+    let track_class = |path: &str| package.export_by_path(path).map(|e| package.export_class(e));
+    let mut tracks_by_guid: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for binding in entries(&props, "ObjectBindings") {
+        let tracks = match binding.field("Tracks") {
+            Some(PropertyValue::Array(tracks)) => {
+                tracks.iter().filter_map(|t| t.as_str()).filter_map(track_class).collect()
+            }
+            _ => Vec::new(),
+        };
+        tracks_by_guid.insert(field_guid(binding, "ObjectGuid"), tracks);
+    }
+    for possessable in entries(&props, "Possessables") {
+        let guid = field_guid(possessable, "Guid");
+        info.bindings.push(SequenceBinding {
+            name: field_str(possessable, "Name"),
+            kind: "possessable".to_string(),
+            class: possessable.field("PossessedObjectClass").and_then(|c| c.as_str()).map(short_class),
+            tracks: tracks_by_guid.remove(&guid).unwrap_or_default(),
+            guid,
+        });
+    }
+    for spawnable in entries(&props, "Spawnables") {
+        let guid = field_guid(spawnable, "Guid");
+        info.bindings.push(SequenceBinding {
+            name: field_str(spawnable, "Name"),
+            kind: "spawnable".to_string(),
+            class: object_class(&package, spawnable.field("ObjectTemplate")),
+            tracks: tracks_by_guid.remove(&guid).unwrap_or_default(),
+            guid,
+        });
+    }
+
+    // UE4 calls the unbound tracks master tracks; the camera cut track is kept on its own.
+    let root = ["Tracks", "MasterTracks", "CameraCutTrack"].into_iter().flat_map(|name| object_list(&props, name));
+    info.root_tracks = root.filter_map(|path| track_class(&path)).collect();
+    for track in info.root_tracks.iter().chain(info.bindings.iter().flat_map(|b| &b.tracks)) {
+        *info.track_counts.entry(track.clone()).or_default() += 1;
+    }
+    Ok(info)
+}
+
+#[tauri::command]
+pub fn get_sequence_info(file_path: String) -> CodexResult<SequenceInfo> {
+    inspect(Path::new(&file_path))
+}
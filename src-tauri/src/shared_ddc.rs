@@ -0,0 +1,212 @@
+// Shared Derived Data Cache check: which shared caches the project's DDC graph (and this
+// machine's environment overrides) point at — network folders, Zen servers, Unreal Cloud DDC —
+// and whether they answer, how fast, and for folders whether anyone has written to them lately.
+// Only the project's DefaultEngine.ini is read; a graph left at the engine default is covered
+// by the environment overrides the default graph honours.
+
+use crate::config::{ConfigValue, IniFile};
+use crate::error::CodexResult;
+use crate::http::Request;
+use serde::Serialize;
+use std::path::Path;
+use std::time::{Instant, SystemTime};
+
+// Source builds use the first graph, installed (launcher) engines the second.
+const GRAPH_SECTIONS: &[&str] = &["DerivedDataBackendGraph", "InstalledDerivedDataBackendGraph"];
+const STORAGE_SERVERS_SECTION: &str = "StorageServers";
+
+// Overrides the engine's default graph reads, with the kind of cache each one points at.
+const ENV_OVERRIDES: &[(&str, &str)] = &[
+    ("UE-SharedDataCachePath", "filesystem"),
+    ("UE-ZenSharedDataCacheHost", "zen"),
+    ("UE-CloudDataCacheHost", "cloud"),
+];
+
+const ZEN_DEFAULT_PORT: i64 = 8558;
+
+// A shared folder nobody has written to in this long isn't being populated by anyone.
+const STALE_AFTER_SECS: u64 = 30 * 24 * 60 * 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedCache {
+    /// Graph entry name, or the environment variable.
+    pub name: String,
+    /// `filesystem`, `zen` or `cloud`.
+    pub kind: String,
+    pub location: String,
+    /// `DefaultEngine.ini` or the environment variable that set the location.
+    pub source: String,
+    pub read_only: bool,
+    pub reachable: bool,
+    pub latency_ms: Option<f64>,
+    /// Newest write seen in a shared folder.
+    pub last_write: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedDdcReport {
+    pub caches: Vec<SharedCache>,
+    /// `not_configured`, `unreachable`, `stale` or `ok`.
+    pub status: String,
+    /// A reachable shared cache this machine can read from, that someone is writing to.
+    pub benefitting: bool,
+}
+
+fn is_local_host(host: &str) -> bool {
+    let host = host.split("://").last().unwrap_or(host).to_ascii_lowercase();
+    ["localhost", "127.", "[::1]"].iter().any(|local| host.starts_with(local))
+}
+
+fn cache(name: &str, kind: &str, location: String, source: &str, read_only: bool) -> SharedCache {
+    SharedCache {
+        name: name.to_string(),
+        kind: kind.to_string(),
+        location,
+        source: source.to_string(),
+        read_only,
+        reachable: false,
+        latency_ms: None,
+        last_write: None,
+        error: None,
+    }
+}
+
+fn env(name: &str) -> Option<String> {
+    std::env::var(name).ok().map(|v| v.trim().to_string()).filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("None"))
+}
+
+/// Shared caches of one graph entry, e.g. `Shared=(Type=FileSystem, Path=\\nas\DDC, ...)`.
+/// Local folders, the pak cache and a local Zen server aren't shared and are skipped.
+fn graph_entry(ini: &IniFile, name: &str, value: &ConfigValue) -> Option<SharedCache> {
+    let read_only = value.get_bool("ReadOnly").unwrap_or(false);
+    let env_override = |key: &str| value.get_str(key).and_then(|var| Some((env(var)?, var.to_string())));
+    match value.get_str("Type")?.to_ascii_lowercase().as_str() {
+        "filesystem" => {
+            let (path, source) = env_override("EnvPathOverride")
+                .or_else(|| Some((value.get_str("Path")?.to_string(), "DefaultEngine.ini".to_string())))?;
+            // `?EpicDDC` and the like are placeholders; `%...%` paths are per-user local folders.
+            let is_network = path.starts_with("\\\\") || path.starts_with("//");
+            let is_shared = is_network || (name.to_ascii_lowercase().contains("shared") && !path.contains('%'));
+            (is_shared && !path.is_empty() && !path.starts_with('?'))
+                .then(|| cache(name, "filesystem", path, &source, read_only))
+        }
+        kind @ ("zen" | "cloud" | "http") => {
+            let server = value.get_str("ServerID").map(|id| {
+                ConfigValue::parse(ini.get_all(STORAGE_SERVERS_SECTION, id).last().copied().unwrap_or_default())
+            });
+            let configured = value.get_str("Host").or_else(|| server.as_ref().and_then(|s| s.get_str("Host")));
+            let (host, source) = env_override("EnvHostOverride")
+                .or_else(|| Some((configured?.to_string(), "DefaultEngine.ini".to_string())))?;
+            if is_local_host(&host) {
+                return None;
+            }
+            let kind = if kind == "zen" { "zen" } else { "cloud" };
+            let port = value.get_i64("Port").or((kind == "zen").then_some(ZEN_DEFAULT_PORT));
+            Some(cache(name, kind, base_url(&host, port), &source, read_only))
+        }
+        _ => None,
+    }
+}
+
+fn base_url(host: &str, port: Option<i64>) -> String {
+    let url = if host.contains("://") { host.to_string() } else { format!("http://{}", host) };
+    let authority = url.split("://").nth(1).unwrap_or_default().split('/').next().unwrap_or_default();
+    match port {
+        Some(port) if !authority.contains(':') => format!("{}:{}", url.trim_end_matches('/'), port),
+        _ => url.trim_end_matches('/').to_string(),
+    }
+}
+
+/// Shared caches from the project's graph sections and this machine's environment.
+pub fn configured_caches(project: &Path) -> Vec<SharedCache> {
+    let text = std::fs::read_to_string(project.join("Config").join("DefaultEngine.ini")).unwrap_or_default();
+    let ini = IniFile::parse(&text);
+    let mut caches: Vec<SharedCache> = Vec::new();
+    for section in GRAPH_SECTIONS {
+        for (name, value) in ini.entries(section) {
+            let Some(cache) = graph_entry(&ini, name, &ConfigValue::parse(value)) else {
+                continue;
+            };
+            if !caches.iter().any(|c| c.location.eq_ignore_ascii_case(&cache.location)) {
+                caches.push(cache);
+            }
+        }
+    }
+    for (var, kind) in ENV_OVERRIDES {
+        let Some(location) = env(var) else {
+            continue;
+        };
+        let location = if *kind == "filesystem" { location } else { base_url(&location, None) };
+        if !caches.iter().any(|c| c.location.eq_ignore_ascii_case(&location)) && !is_local_host(&location) {
+            caches.push(cache(var, kind, location, var, false));
+        }
+    }
+    caches
+}
+
+/// Newest modification time among a folder's entries and their children. DDC folders fan out
+/// by hash, so two levels see every bucket a recent write touched.
+fn last_write(path: &Path) -> Option<u64> {
+    let modified = |p: &Path| p.metadata().ok()?.modified().ok().map(crate::scan::unix_seconds);
+    let mut newest = None;
+    for entry in std::fs::read_dir(path).ok()?.flatten() {
+        newest = newest.max(modified(&entry.path()));
+        if let Ok(children) = std::fs::read_dir(entry.path()) {
+            for child in children.flatten() {
+                newest = newest.max(modified(&child.path()));
+            }
+        }
+    }
+    newest
+}
+
+fn probe(cache: &mut SharedCache) {
+    if cache.kind == "filesystem" {
+        let started = Instant::now();
+        match std::fs::read_dir(&cache.location) {
+            Ok(_) => {
+                cache.reachable = true;
+                cache.latency_ms = Some(started.elapsed().as_secs_f64() * 1000.0);
+                cache.last_write = last_write(Path::new(&cache.location));
+            }
+            Err(e) => cache.error = Some(e.to_string()),
+        }
+        return;
+    }
+    // Zen and Unreal Cloud DDC both serve a readiness endpoint; any HTTP answer means it's up.
+    // Latency is curl's own timing, which leaves out starting the curl process.
+    let url = format!("{}/health/ready", cache.location);
+    match Request::get(&url).send() {
+        Ok(response) => {
+            cache.reachable = response.status != 0;
+            cache.latency_ms = Some(response.time_total * 1000.0);
+        }
+        Err(e) => cache.error = Some(e.to_string()),
+    }
+}
+
+pub fn check(project: &Path) -> SharedDdcReport {
+    let mut caches = configured_caches(project);
+    caches.iter_mut().for_each(probe);
+    let now = crate::scan::unix_seconds(SystemTime::now());
+    // Servers can't be checked for activity from outside; a folder with no recent writes can.
+    let is_fresh = |c: &SharedCache| {
+        c.kind != "filesystem" || c.last_write.is_some_and(|t| now.saturating_sub(t) < STALE_AFTER_SECS)
+    };
+    let status = if caches.is_empty() {
+        "not_configured"
+    } else if !caches.iter().any(|c| c.reachable) {
+        "unreachable"
+    } else if !caches.iter().any(|c| c.reachable && is_fresh(c)) {
+        "stale"
+    } else {
+        "ok"
+    };
+    SharedDdcReport { benefitting: status == "ok", status: status.to_string(), caches }
+}
+
+#[tauri::command(async)]
+pub fn check_shared_ddc(project: String) -> CodexResult<SharedDdcReport> {
+    Ok(check(Path::new(&project)))
+}
@@ -0,0 +1,121 @@
+// Rig ecosystem per skeleton: meshes built on it, the physics assets of those meshes and
+// the animation assets targeting it. Links come from registry tags, then hard references.
+
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkeletalMeshLink {
+    pub path: String,
+    pub physics_assets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkeletonLinks {
+    pub skeleton: String,
+    pub meshes: Vec<SkeletalMeshLink>,
+    pub anim_blueprints: Vec<String>,
+    /// Sequences, montages, blend spaces and other animation assets.
+    pub animations: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkeletonMap {
+    pub skeletons: Vec<SkeletonLinks>,
+    /// Meshes and anim blueprints whose skeleton isn't in the project.
+    pub unresolved: Vec<String>,
+}
+
+const ANIMATION_CLASSES: &[&str] = &[
+    "AnimSequence",
+    "AnimMontage",
+    "AnimComposite",
+    "BlendSpace",
+    "BlendSpace1D",
+    "AimOffsetBlendSpace",
+    "AimOffsetBlendSpace1D",
+    "PoseAsset",
+];
+
+/// Package of the skeleton an asset is set up for, if it's in the project.
+fn skeleton_of(index: &AssetIndex, asset: &IndexedAsset) -> Option<String> {
+    let tagged = asset.tag("Skeleton").or_else(|| asset.tag("TargetSkeleton"));
+    if let Some(skeleton) = tagged.and_then(|t| index.get(t)) {
+        return Some(skeleton.asset.path.clone());
+    }
+    asset
+        .dependencies
+        .iter()
+        .filter_map(|d| index.get(d))
+        .find(|d| d.is_class("Skeleton"))
+        .map(|d| d.asset.path.clone())
+}
+
+pub fn build(index: &AssetIndex) -> SkeletonMap {
+    let mut skeletons: BTreeMap<String, SkeletonLinks> = index
+        .of_class("Skeleton")
+        .map(|s| {
+            let links = SkeletonLinks {
+                skeleton: s.asset.path.clone(),
+                meshes: Vec::new(),
+                anim_blueprints: Vec::new(),
+                animations: Vec::new(),
+            };
+            (s.asset.path.clone(), links)
+        })
+        .collect();
+
+    // A mesh references its physics asset, and a physics asset references its preview mesh.
+    let mut physics: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for asset in index.of_class("PhysicsAsset") {
+        for mesh in asset.dependencies.iter().filter_map(|d| index.get(d)).filter(|d| d.is_class("SkeletalMesh")) {
+            physics.entry(mesh.asset.path.clone()).or_default().push(asset.asset.path.clone());
+        }
+    }
+    for mesh in index.of_class("SkeletalMesh") {
+        let entry = physics.entry(mesh.asset.path.clone()).or_default();
+        for dep in mesh.dependencies.iter().filter_map(|d| index.get(d)).filter(|d| d.is_class("PhysicsAsset")) {
+            entry.push(dep.asset.path.clone());
+        }
+        entry.sort();
+        entry.dedup();
+    }
+
+    let mut unresolved = Vec::new();
+    for asset in &index.assets {
+        let class = asset.class.as_deref().unwrap_or_default();
+        let is_mesh = class == "SkeletalMesh";
+        let is_anim_bp = class == "AnimBlueprint";
+        if !is_mesh && !is_anim_bp && !ANIMATION_CLASSES.contains(&class) {
+            continue;
+        }
+        let Some(links) = skeleton_of(index, asset).and_then(|s| skeletons.get_mut(&s)) else {
+            if is_mesh || is_anim_bp {
+                unresolved.push(asset.asset.path.clone());
+            }
+            continue;
+        };
+        let path = asset.asset.path.clone();
+        if is_mesh {
+            links
+                .meshes
+                .push(SkeletalMeshLink { physics_assets: physics.get(&path).cloned().unwrap_or_default(), path });
+        } else if is_anim_bp {
+            links.anim_blueprints.push(path);
+        } else {
+            links.animations.push(path);
+        }
+    }
+
+    SkeletonMap { skeletons: skeletons.into_values().collect(), unresolved }
+}
+
+#[tauri::command]
+pub fn get_skeleton_map(manager: State<ScanManager>, project: String) -> CodexResult<SkeletonMap> {
+    let index = manager.index(&project)?;
+    Ok(build(&index))
+}
@@ -0,0 +1,355 @@
+// Links imported assets back to the files they were imported from (FBX, PNG, WAV...),
+// using the AssetImportData JSON the editor keeps in every imported package, and
+// indexes an optional raw-art root to find source files nobody imported.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::{Archive, Package, VER_UE4_ASSET_IMPORT_DATA_AS_JSON};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use walkdir::WalkDir;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceFile {
+    pub relative_filename: String,
+    pub resolved_path: String,
+    pub exists: bool,
+    pub timestamp: Option<String>,
+    pub md5: Option<String>,
+    pub display_label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingSource {
+    pub asset: String,
+    pub file_path: String,
+    pub missing: Vec<SourceFile>,
+}
+
+// One entry of FAssetImportInfo::SourceFiles as written by the editor.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ImportInfoEntry {
+    relative_filename: String,
+    timestamp: Option<String>,
+    #[serde(rename = "FileMD5")]
+    file_md5: Option<String>,
+    display_label_name: Option<String>,
+}
+
+/// Source files recorded in a package, resolved against the package folder like the editor
+/// does, or against `project_dir` for importers that store project-relative paths.
+pub fn source_files(file_path: &Path, project_dir: Option<&Path>) -> CodexResult<Vec<SourceFile>> {
+    let package = Package::read_file(file_path)?;
+    let json = match package.main_registry_object().and_then(|o| o.tags.get("AssetImportData")) {
+        Some(json) => json.clone(),
+        None => match read_import_data_json(file_path, &package)? {
+            Some(json) => json,
+            None => return Ok(Vec::new()),
+        },
+    };
+    resolve_import_data(&json, file_path, project_dir)
+}
+
+/// `source_files` from the registry tags the index already read. The package is only opened
+/// again when it has no tags at all, as packages from before the import data tag existed.
+fn indexed_source_files(asset: &IndexedAsset, project_dir: &Path) -> CodexResult<Vec<SourceFile>> {
+    let file_path = Path::new(&asset.asset.file_path);
+    match asset.tag("AssetImportData") {
+        Some(json) => resolve_import_data(json, file_path, Some(project_dir)),
+        None if asset.tags.is_empty() && asset.error.is_none() && asset.asset.is_package() => {
+            source_files(file_path, Some(project_dir))
+        }
+        None => Ok(Vec::new()),
+    }
+}
+
+fn resolve_import_data(json: &str, file_path: &Path, project_dir: Option<&Path>) -> CodexResult<Vec<SourceFile>> {
+    let entries: Vec<ImportInfoEntry> = serde_json::from_str(json)
+        .map_err(|e| CodexError::message("invalid_import_data", &[("path", &file_path.display()), ("message", &e)]))?;
+    let package_dir = file_path.parent().unwrap_or(Path::new(""));
+    Ok(entries
+        .into_iter()
+        .filter(|e| !e.relative_filename.is_empty())
+        .map(|e| {
+            let resolved = resolve_source(&e.relative_filename, package_dir, project_dir);
+            SourceFile {
+                exists: resolved.is_file(),
+                resolved_path: resolved.to_string_lossy().to_string(),
+                relative_filename: e.relative_filename,
+                timestamp: e.timestamp.filter(|t| !t.is_empty()),
+                md5: e.file_md5.filter(|m| !m.is_empty() && m.chars().any(|c| c != '0')),
+                display_label: e.display_label_name.filter(|l| !l.is_empty()),
+            }
+        })
+        .collect())
+}
+
+// Older packages have no registry tag; UAssetImportData writes the JSON ahead of its tagged properties.
+fn read_import_data_json(file_path: &Path, package: &Package) -> CodexResult<Option<String>> {
+    if package.summary.file_version_ue4 < VER_UE4_ASSET_IMPORT_DATA_AS_JSON || package.summary.is_filter_editor_only() {
+        return Ok(None);
+    }
+    let Some(export) = package
+        .exports
+        .iter()
+        .find(|e| e.serial_size > 4 && package.export_class(e).ends_with("ImportData"))
+    else {
+        return Ok(None);
+    };
+    let file = crate::file_lock::open(file_path).map_err(|e| CodexError::io(file_path, &e))?;
+    let mut reader = BufReader::new(file);
+    let mut ar = Archive::new(&mut reader);
+    ar.seek(export.serial_offset as u64)?;
+    let json = ar.read_fstring()?;
+    Ok(Some(json).filter(|j| j.trim_start().starts_with('[')))
+}
+
+fn resolve_source(relative: &str, package_dir: &Path, project_dir: Option<&Path>) -> PathBuf {
+    let relative = relative.replace('\\', "/");
+    let path = Path::new(&relative);
+    if path.is_absolute() || relative.chars().nth(1) == Some(':') {
+        return path.to_path_buf();
+    }
+    let candidate = normalize(&package_dir.join(path));
+    if candidate.exists() {
+        return candidate;
+    }
+    // Some importers store paths relative to the project instead of the package.
+    project_dir
+        .map(|p| normalize(&p.join(path)))
+        .filter(|p| p.exists())
+        .unwrap_or(candidate)
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            std::path::Component::CurDir => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn project_root_of(file_path: &Path) -> Option<PathBuf> {
+    file_path
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_dir(dir)
+                .map(|entries| {
+                    entries
+                        .filter_map(|e| e.ok())
+                        .any(|e| e.path().extension().is_some_and(|ext| ext == "uproject"))
+                })
+                .unwrap_or(false)
+        })
+        .map(Path::to_path_buf)
+}
+
+pub fn find_missing_sources(index: &AssetIndex) -> Vec<MissingSource> {
+    index
+        .assets
+        .iter()
+        .filter_map(|a| {
+            let missing: Vec<SourceFile> = indexed_source_files(a, &index.project)
+                .ok()?
+                .into_iter()
+                .filter(|s| !s.exists)
+                .collect();
+            (!missing.is_empty()).then(|| MissingSource {
+                asset: a.asset.path.clone(),
+                file_path: a.asset.file_path.clone(),
+                missing,
+            })
+        })
+        .collect()
+}
+
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "fbx", "obj", "abc", "gltf", "glb", "usd", "usda", "usdc", "psd", "png", "tga", "jpg", "jpeg", "exr", "hdr",
+    "tif", "tiff", "bmp", "wav", "ogg", "flac", "mp3", "aif", "aiff",
+];
+
+// Conventional places for raw art next to the project when no root is given.
+const DEFAULT_SOURCE_ROOTS: &[&str] = &["RawContent", "SourceArt", "Art", "Source_Art"];
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceLink {
+    /// An asset's import data points at this file.
+    ImportData,
+    /// Only the file name matches an asset (e.g. `Rock.fbx` and `SM_Rock`).
+    Name,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceArtFile {
+    pub path: String,
+    pub relative_path: String,
+    pub extension: String,
+    pub size: u64,
+    pub assets: Vec<String>,
+    pub link: Option<SourceLink>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanedLink {
+    pub asset: String,
+    pub relative_filename: String,
+    pub resolved_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SourceArtReport {
+    pub source_root: String,
+    pub files: Vec<SourceArtFile>,
+    /// Source files no asset was imported from.
+    pub unimported: Vec<String>,
+    /// Import data pointing into the source root at files that are no longer there.
+    pub orphaned: Vec<OrphanedLink>,
+}
+
+pub fn default_source_root(project: &Path) -> Option<PathBuf> {
+    DEFAULT_SOURCE_ROOTS
+        .iter()
+        .map(|d| project.join(d))
+        .find(|p| p.is_dir())
+}
+
+pub fn scan_source_root(index: &AssetIndex, source_root: &Path) -> SourceArtReport {
+    let mut files: Vec<SourceArtFile> = WalkDir::new(source_root)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let extension = e.path().extension()?.to_string_lossy().to_lowercase();
+            if !SOURCE_EXTENSIONS.contains(&extension.as_str()) {
+                return None;
+            }
+            Some(SourceArtFile {
+                path: e.path().to_string_lossy().to_string(),
+                relative_path: e
+                    .path()
+                    .strip_prefix(source_root)
+                    .unwrap_or(e.path())
+                    .to_string_lossy()
+                    .replace('\\', "/"),
+                extension,
+                size: e.metadata().map(|m| m.len()).unwrap_or(0),
+                assets: Vec::new(),
+                link: None,
+            })
+        })
+        .collect();
+
+    let by_path: HashMap<String, usize> = files
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (path_key(Path::new(&f.path)), i))
+        .collect();
+    let root_key = path_key(&normalize(source_root));
+
+    let mut orphaned = Vec::new();
+    for asset in &index.assets {
+        let Ok(sources) = indexed_source_files(asset, &index.project) else {
+            continue;
+        };
+        for source in sources {
+            let key = path_key(Path::new(&source.resolved_path));
+            if let Some(&i) = by_path.get(&key) {
+                files[i].assets.push(asset.asset.path.clone());
+                files[i].link = Some(SourceLink::ImportData);
+            } else if !source.exists && key.starts_with(&root_key) {
+                orphaned.push(OrphanedLink {
+                    asset: asset.asset.path.clone(),
+                    relative_filename: source.relative_filename,
+                    resolved_path: source.resolved_path,
+                });
+            }
+        }
+    }
+
+    // Fall back to names for files no import data claimed.
+    let mut by_name: HashMap<String, Vec<&str>> = HashMap::new();
+    for asset in &index.assets {
+        let name = asset.asset.name.to_lowercase();
+        let stripped = strip_asset_prefix(&name).to_string();
+        by_name.entry(name).or_default().push(&asset.asset.path);
+        if stripped != asset.asset.name.to_lowercase() {
+            by_name.entry(stripped).or_default().push(&asset.asset.path);
+        }
+    }
+    for file in files.iter_mut().filter(|f| f.link.is_none()) {
+        let stem = Path::new(&file.path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if let Some(assets) = by_name.get(&stem).or_else(|| by_name.get(strip_asset_prefix(&stem))) {
+            file.assets = assets.iter().map(|a| a.to_string()).collect();
+            file.link = Some(SourceLink::Name);
+        }
+    }
+    for file in &mut files {
+        file.assets.sort();
+        file.assets.dedup();
+    }
+
+    SourceArtReport {
+        source_root: source_root.to_string_lossy().to_string(),
+        unimported: files.iter().filter(|f| f.link.is_none()).map(|f| f.path.clone()).collect(),
+        files,
+        orphaned,
+    }
+}
+
+fn path_key(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/").to_lowercase()
+}
+
+// `SM_Rock` -> `rock`, following the usual UE naming prefixes.
+fn strip_asset_prefix(name: &str) -> &str {
+    match name.split_once('_') {
+        Some((prefix, rest)) if !rest.is_empty() && prefix.len() <= 3 => rest,
+        _ => name,
+    }
+}
+
+#[tauri::command]
+pub fn get_source_file(file_path: String) -> CodexResult<Vec<SourceFile>> {
+    let file_path = Path::new(&file_path);
+    source_files(file_path, project_root_of(file_path).as_deref())
+}
+
+#[tauri::command]
+pub fn get_missing_source_report(manager: State<ScanManager>, project: String) -> CodexResult<Vec<MissingSource>> {
+    let index = manager.index(&project)?;
+    Ok(find_missing_sources(&index))
+}
+
+#[tauri::command]
+pub fn scan_source_art(
+    manager: State<ScanManager>,
+    project: String,
+    source_root: Option<String>,
+) -> CodexResult<SourceArtReport> {
+    let index = manager.index(&project)?;
+    let root = match source_root {
+        // Relative roots are taken from the project folder, e.g. `RawContent`.
+        Some(root) => Path::new(&project).join(root),
+        None => default_source_root(Path::new(&project))
+            .ok_or_else(|| CodexError::message("no_source_art_folder", &[]))?,
+    };
+    if !root.is_dir() {
+        return Err(CodexError::message("source_art_folder_missing", &[("path", &root.display())]));
+    }
+    Ok(scan_source_root(&index, &root))
+}
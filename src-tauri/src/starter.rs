@@ -0,0 +1,59 @@
+// Epic boilerplate: Starter Content and the folders the engine's project templates copy
+// in. Audits use this to tell a project's own content apart from what came in the box.
+
+// Folders under /Game that templates and content packs create, compared case-insensitively.
+const STARTER_FOLDERS: &[&str] = &[
+    "/Game/StarterContent",
+    "/Game/ThirdPerson",
+    "/Game/ThirdPersonBP",
+    "/Game/ThirdPersonCPP",
+    "/Game/FirstPerson",
+    "/Game/FirstPersonBP",
+    "/Game/FirstPersonCPP",
+    "/Game/FPWeapon",
+    "/Game/TopDown",
+    "/Game/TopDownBP",
+    "/Game/TopDownCPP",
+    "/Game/SideScroller",
+    "/Game/SideScrollerBP",
+    "/Game/SideScrollerCPP",
+    "/Game/Vehicle",
+    "/Game/VehicleBP",
+    "/Game/VehicleCPP",
+    "/Game/VehicleTemplate",
+    "/Game/Puzzle",
+    "/Game/PuzzleBP",
+    "/Game/TwinStick",
+    "/Game/TwinStickBP",
+    "/Game/Flying",
+    "/Game/FlyingBP",
+    "/Game/Geometry",
+    "/Game/LevelPrototyping",
+    "/Game/Mannequin",
+    "/Game/AnimStarterPack",
+    "/Game/Characters/Mannequins",
+    "/Game/Characters/Mannequin_UE4",
+    "/Game/VirtualReality",
+    "/Game/VRTemplate",
+    "/Game/HandheldARBP",
+    "/Game/CollaborativeViewer",
+];
+
+/// True for package paths inside a starter-content or template folder.
+pub fn is_starter_content(package_path: &str) -> bool {
+    STARTER_FOLDERS.iter().any(|folder| crate::index::is_in_folder(package_path, folder))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_starter_content;
+
+    #[test]
+    fn classifies_without_panicking_on_non_ascii_folders() {
+        assert!(is_starter_content("/Game/StarterContent/Props/SM_Chair"));
+        assert!(is_starter_content("/game/startercontent/Props/SM_Chair"));
+        assert!(!is_starter_content("/Game/Персонажи/BP_Hero"));
+        assert!(!is_starter_content("/Game/ThirdPersonКарта"));
+        assert!(!is_starter_content("/Game/日本語/Maps/Arena"));
+    }
+}
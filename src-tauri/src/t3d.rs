@@ -0,0 +1,198 @@
+// T3D text, as produced by copying actors in the level editor (or nodes in a graph):
+// nested `Begin Actor` / `Begin Object` blocks with one `Key=Value` per line.
+
+use crate::error::CodexResult;
+use crate::manager::ScanManager;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use tauri::State;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct T3dObject {
+    /// `Actor` or `Object` (components, subobjects, graph nodes).
+    pub kind: String,
+    pub class: Option<String>,
+    pub name: Option<String>,
+    pub archetype: Option<String>,
+    pub label: Option<String>,
+    pub properties: Vec<(String, String)>,
+    pub children: Vec<T3dObject>,
+    pub referenced_assets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct T3dPaste {
+    pub actors: Vec<T3dObject>,
+    /// Top-level blocks that aren't actors, e.g. Blueprint graph nodes.
+    pub objects: Vec<T3dObject>,
+    pub referenced_assets: Vec<String>,
+    /// Referenced packages the project doesn't contain (only when a project was given).
+    pub missing_assets: Vec<String>,
+}
+
+pub fn parse(text: &str) -> T3dPaste {
+    let mut stack: Vec<T3dObject> = Vec::new();
+    let mut roots: Vec<T3dObject> = Vec::new();
+
+    for raw in text.lines() {
+        let line = raw.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix("Begin ") {
+            let (kind, rest) = header.split_once(' ').unwrap_or((header, ""));
+            // Map/Level/Surface wrappers carry nothing we need; their contents are kept.
+            if matches!(kind, "Map" | "Level" | "Surface" | "PolyList" | "Polygon" | "Brush") {
+                continue;
+            }
+            let attrs = header_attributes(rest);
+            let attr = |key: &str| attrs.iter().find(|(k, _)| k.eq_ignore_ascii_case(key)).map(|(_, v)| v.clone());
+            stack.push(T3dObject {
+                kind: kind.to_string(),
+                class: attr("Class"),
+                name: attr("Name"),
+                archetype: attr("Archetype"),
+                ..Default::default()
+            });
+            continue;
+        }
+        if let Some(kind) = line.strip_prefix("End ") {
+            if !matches!(kind.trim(), "Actor" | "Object") {
+                continue;
+            }
+            let Some(mut object) = stack.pop() else {
+                continue;
+            };
+            object.referenced_assets = references(&object);
+            match stack.last_mut() {
+                Some(parent) => attach(&mut parent.children, object),
+                None => attach(&mut roots, object),
+            }
+            continue;
+        }
+        let Some(current) = stack.last_mut() else {
+            continue;
+        };
+        if let Some((key, value)) = line.split_once('=') {
+            let value = value.trim().to_string();
+            if key.trim() == "ActorLabel" {
+                current.label = Some(value.trim_matches('"').to_string());
+            }
+            current.properties.push((key.trim().to_string(), value));
+        }
+    }
+
+    let (actors, objects): (Vec<_>, Vec<_>) = roots.into_iter().partition(|o| o.kind == "Actor");
+    let referenced_assets: BTreeSet<String> = actors
+        .iter()
+        .chain(objects.iter())
+        .flat_map(|o| o.referenced_assets.iter().cloned())
+        .collect();
+    T3dPaste {
+        actors,
+        objects,
+        referenced_assets: referenced_assets.into_iter().collect(),
+        missing_assets: Vec::new(),
+    }
+}
+
+// Subobjects are written twice: a `Begin Object Class=... Name=X` declaration, then a
+// `Begin Object Name=X` block with the values. Fold the second into the first.
+fn attach(siblings: &mut Vec<T3dObject>, object: T3dObject) {
+    if object.class.is_none() {
+        if let Some(existing) = siblings
+            .iter_mut()
+            .find(|s| s.kind == object.kind && s.name.is_some() && s.name == object.name)
+        {
+            existing.properties.extend(object.properties);
+            existing.children.extend(object.children);
+            let merged: BTreeSet<String> = existing
+                .referenced_assets
+                .drain(..)
+                .chain(object.referenced_assets)
+                .collect();
+            existing.referenced_assets = merged.into_iter().collect();
+            return;
+        }
+    }
+    siblings.push(object);
+}
+
+// `Class=/Script/Engine.StaticMeshActor Name="StaticMeshActor_0" Archetype=...`
+fn header_attributes(text: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = text.trim();
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_string();
+        let after = &rest[eq + 1..];
+        let (value, remaining) = if let Some(quoted) = after.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            (quoted[..end].to_string(), quoted.get(end + 1..).unwrap_or(""))
+        } else {
+            let end = after.find(char::is_whitespace).unwrap_or(after.len());
+            (after[..end].to_string(), &after[end..])
+        };
+        attrs.push((key, value));
+        rest = remaining.trim_start();
+    }
+    attrs
+}
+
+fn references(object: &T3dObject) -> Vec<String> {
+    let mut found = BTreeSet::new();
+    for (_, value) in &object.properties {
+        found.extend(asset_paths(value));
+    }
+    if let Some(archetype) = &object.archetype {
+        found.extend(asset_paths(archetype));
+    }
+    if let Some(class) = &object.class {
+        found.extend(asset_paths(class));
+    }
+    for child in &object.children {
+        found.extend(child.referenced_assets.iter().cloned());
+    }
+    found.into_iter().collect()
+}
+
+/// Package paths mentioned in a value, e.g. `StaticMesh'"/Game/Props/SM_Rock.SM_Rock"'`.
+/// Native classes (`/Script/...`) and transient objects aren't assets and are skipped.
+pub fn asset_paths(value: &str) -> Vec<String> {
+    let bytes = value.as_bytes();
+    let mut paths = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_path = bytes[i] == b'/'
+            && (i == 0 || matches!(bytes[i - 1], b'\'' | b'"' | b'=' | b'(' | b',' | b' '))
+            && bytes.get(i + 1).is_some_and(|b| b.is_ascii_alphabetic());
+        if !starts_path {
+            i += 1;
+            continue;
+        }
+        let end = value[i..]
+            .find(['\'', '"', ',', ')', ' '])
+            .map(|e| i + e)
+            .unwrap_or(value.len());
+        let path = &value[i..end];
+        if !path.starts_with("/Script/") && !path.starts_with("/Engine/Transient") && path[1..].contains('/') {
+            paths.push(crate::package::package_of_object_path(path).to_string());
+        }
+        i = end;
+    }
+    paths
+}
+
+#[tauri::command]
+pub fn parse_t3d(manager: State<ScanManager>, text: String, project: Option<String>) -> CodexResult<T3dPaste> {
+    let mut paste = parse(&text);
+    if let Some(project) = project {
+        let index = manager.index(&project)?;
+        paste.missing_assets = paste
+            .referenced_assets
+            .iter()
+            .filter(|p| p.starts_with("/Game/") && !index.contains(p))
+            .cloned()
+            .collect();
+    }
+    Ok(paste)
+}
@@ -0,0 +1,306 @@
+// Team sync: the shareable half of the codex database (reviews, issue links, asset claims,
+// the audit snapshot) is merged with a copy in a common location, a network folder or an S3-compatible
+// bucket, so notes and verdicts follow the project rather than the machine. Conflicts go to the
+// newer timestamp. Connection settings, credentials and schedules never leave the machine.
+
+use crate::codex_db::{AssetClaim, AuditSnapshot, CodexDb, IssueLink, ReviewEvent, ReviewRecord, TeamSync};
+use crate::error::{CodexError, CodexResult};
+use crate::http::Request;
+use crate::manager::ScanManager;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tauri::{AppHandle, Manager, Runtime};
+
+const SYNC_INTERVAL: Duration = Duration::from_secs(300);
+
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// What's stored at the shared location, one document per project.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SharedDb {
+    #[serde(default)]
+    pub reviews: BTreeMap<String, ReviewRecord>,
+    #[serde(default)]
+    pub issues: BTreeMap<String, Vec<IssueLink>>,
+    #[serde(default)]
+    pub issue_changes: BTreeMap<String, u64>,
+    #[serde(default)]
+    pub claims: BTreeMap<String, AssetClaim>,
+    #[serde(default)]
+    pub audit_snapshot: Option<AuditSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncResult {
+    /// Records taken from the shared copy.
+    pub pulled: usize,
+    pub pushed: bool,
+    pub timestamp: u64,
+}
+
+/// `TeamSync` without the secret key.
+#[derive(Debug, Clone, Serialize)]
+pub struct TeamSyncSettings {
+    pub location: String,
+    pub read_only: bool,
+    pub region: Option<String>,
+    pub access_key: Option<String>,
+    pub has_secret_key: bool,
+    pub last_sync: Option<u64>,
+}
+
+fn settings(sync: &TeamSync) -> TeamSyncSettings {
+    TeamSyncSettings {
+        location: sync.location.clone(),
+        read_only: sync.read_only,
+        region: sync.region.clone(),
+        access_key: sync.access_key.clone(),
+        has_secret_key: sync.secret_key.is_some(),
+        last_sync: sync.last_sync,
+    }
+}
+
+fn is_bucket(location: &str) -> bool {
+    location.starts_with("https://")
+}
+
+fn shared(db: &CodexDb) -> SharedDb {
+    SharedDb {
+        reviews: db.reviews.clone(),
+        issues: db.issues.clone(),
+        issue_changes: db.issue_changes.clone(),
+        claims: db.claims.clone(),
+        audit_snapshot: db.audit_snapshot.clone(),
+    }
+}
+
+/// Both sides' review histories, oldest first, without the events they have in common.
+fn merged_history(local: &ReviewRecord, remote: &ReviewRecord) -> Vec<ReviewEvent> {
+    let mut history = local.history.clone();
+    for event in &remote.history {
+        let seen = history
+            .iter()
+            .any(|e| e.timestamp == event.timestamp && e.state == event.state && e.reviewer == event.reviewer);
+        if !seen {
+            history.push(event.clone());
+        }
+    }
+    history.sort_by_key(|e| e.timestamp);
+    history
+}
+
+/// Merges `remote` into `local`; returns how many records came from `remote`. Ties keep the
+/// local side, so syncing the same state twice changes nothing.
+pub fn merge(local: &mut CodexDb, remote: SharedDb) -> usize {
+    let mut pulled = 0;
+    for (path, theirs) in remote.reviews {
+        match local.reviews.get_mut(&path) {
+            Some(ours) => {
+                let history = merged_history(ours, &theirs);
+                if theirs.timestamp > ours.timestamp {
+                    *ours = theirs;
+                    pulled += 1;
+                }
+                ours.history = history;
+            }
+            None => {
+                local.reviews.insert(path, theirs);
+                pulled += 1;
+            }
+        }
+    }
+    // Issue links go by the per-asset change time, so an unlink on one machine wins over the
+    // older link still present on another.
+    let paths: BTreeSet<String> = remote.issue_changes.keys().chain(remote.issues.keys()).cloned().collect();
+    for path in paths {
+        let ours = local.issue_changes.get(&path).copied().unwrap_or(0);
+        let theirs = remote.issue_changes.get(&path).copied().unwrap_or(0);
+        let links = remote.issues.get(&path).cloned().unwrap_or_default();
+        if theirs > ours {
+            if links.is_empty() {
+                local.issues.remove(&path);
+            } else {
+                local.issues.insert(path.clone(), links);
+            }
+            local.issue_changes.insert(path, theirs);
+            pulled += 1;
+        } else if theirs == ours {
+            // Links from before change times were kept: take the union.
+            let entry = local.issues.entry(path).or_default();
+            for link in links {
+                if !entry.iter().any(|l| l.key == link.key) {
+                    entry.push(link);
+                    pulled += 1;
+                }
+            }
+        }
+    }
+    local.issues.retain(|_, links| !links.is_empty());
+    for (path, theirs) in remote.claims {
+        if local.claims.get(&path).is_none_or(|ours| theirs.timestamp > ours.timestamp) {
+            local.claims.insert(path, theirs);
+            pulled += 1;
+        }
+    }
+    if let Some(theirs) = remote.audit_snapshot {
+        if local.audit_snapshot.as_ref().is_none_or(|ours| theirs.timestamp > ours.timestamp) {
+            local.audit_snapshot = Some(theirs);
+            pulled += 1;
+        }
+    }
+    pulled
+}
+
+fn file_name(project: &str) -> String {
+    format!("{}.codex.json", crate::reports::project_name(project))
+}
+
+fn object_url(sync: &TeamSync, project: &str) -> String {
+    format!("{}/{}", sync.location.trim_end_matches('/'), file_name(project))
+}
+
+fn signed<'a>(request: Request<'a>, sync: &TeamSync) -> Request<'a> {
+    match (&sync.access_key, &sync.secret_key) {
+        (Some(access_key), Some(secret_key)) => {
+            request.aws_sigv4(sync.region.as_deref().unwrap_or(DEFAULT_REGION), access_key, secret_key)
+        }
+        // Public or pre-authorised buckets.
+        _ => request,
+    }
+}
+
+/// The shared copy, or `None` if nobody has pushed one yet.
+pub fn fetch(sync: &TeamSync, project: &str) -> CodexResult<Option<SharedDb>> {
+    if is_bucket(&sync.location) {
+        let url = object_url(sync, project);
+        let response = signed(Request::get(&url), sync).send()?;
+        if response.status == 404 {
+            return Ok(None);
+        }
+        let response = response.ok(&url)?;
+        return serde_json::from_slice(&response.body).map(Some).map_err(|e| CodexError::parse(Path::new(&url), e));
+    }
+    let path = Path::new(&sync.location).join(file_name(project));
+    match std::fs::read(&path) {
+        Ok(bytes) => serde_json::from_slice(&bytes).map(Some).map_err(|e| CodexError::parse(&path, e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(CodexError::io(&path, &e)),
+    }
+}
+
+pub fn upload(sync: &TeamSync, project: &str, db: &SharedDb) -> CodexResult<()> {
+    let json = serde_json::to_vec_pretty(db).map_err(|e| e.to_string())?;
+    if is_bucket(&sync.location) {
+        let url = object_url(sync, project);
+        signed(Request::put(&url, json), sync).header("Content-Type", "application/json").send()?.ok(&url)?;
+        return Ok(());
+    }
+    let folder = Path::new(&sync.location);
+    std::fs::create_dir_all(folder).map_err(|e| CodexError::io(folder, &e))?;
+    // Same write-then-rename as the local database, so readers never see half a file.
+    let path = folder.join(file_name(project));
+    let tmp = path.with_extension(format!("json.{}.tmp", std::process::id()));
+    std::fs::write(&tmp, json).map_err(|e| CodexError::io(&tmp, &e))?;
+    std::fs::rename(&tmp, &path).map_err(|e| CodexError::io(&path, &e))
+}
+
+/// Pulls and merges the shared copy, then pushes the result back unless it already matches or
+/// the sync is read-only. The network round trips happen outside the database lock; two
+/// machines pushing in the same instant can still overwrite each other, and the loser's
+/// changes go out on its next sync.
+pub fn sync(project: &str) -> CodexResult<SyncResult> {
+    let target =
+        CodexDb::read(Path::new(project))?.team_sync.ok_or_else(|| CodexError::message("no_team_sync", &[]))?;
+    let remote = fetch(&target, project)?;
+    let remote_json = remote.as_ref().map(serde_json::to_value).transpose().map_err(|e| e.to_string())?;
+    let timestamp = crate::scan::unix_seconds(SystemTime::now());
+    let (pulled, merged) = CodexDb::update(Path::new(project), |db| {
+        let pulled = remote.map(|r| merge(db, r)).unwrap_or(0);
+        if let Some(sync) = db.team_sync.as_mut() {
+            sync.last_sync = Some(timestamp);
+        }
+        Ok((pulled, shared(db)))
+    })?;
+    let changed = remote_json != Some(serde_json::to_value(&merged).map_err(|e| e.to_string())?);
+    let pushed = !target.read_only && changed;
+    if pushed {
+        upload(&target, project, &merged)?;
+    }
+    Ok(SyncResult { pulled, pushed, timestamp })
+}
+
+/// Starts the thread that syncs watched projects with a team sync configured.
+pub fn start<R: Runtime>(app: AppHandle<R>) {
+    std::thread::Builder::new()
+        .name("team-sync".to_string())
+        .spawn(move || loop {
+            std::thread::sleep(SYNC_INTERVAL);
+            for watched in app.state::<ScanManager>().scheduler.status().projects {
+                let project = watched.project;
+                if !CodexDb::read(Path::new(&project)).is_ok_and(|db| db.team_sync.is_some()) {
+                    continue;
+                }
+                match sync(&project) {
+                    Ok(result) => {
+                        let _ = app.emit_all("codex-synced", (&project, result));
+                    }
+                    Err(error) => {
+                        let _ = app.emit_all("codex-sync-failed", (&project, error));
+                    }
+                }
+            }
+        })
+        .expect("failed to start team sync thread");
+}
+
+#[tauri::command]
+pub fn get_team_sync(project: String) -> CodexResult<Option<TeamSyncSettings>> {
+    Ok(CodexDb::read(Path::new(&project))?.team_sync.as_ref().map(settings))
+}
+
+/// An empty `location` turns sync off. A `None` secret key keeps the stored one.
+#[tauri::command]
+pub fn set_team_sync(
+    project: String,
+    location: String,
+    read_only: Option<bool>,
+    region: Option<String>,
+    access_key: Option<String>,
+    secret_key: Option<String>,
+) -> CodexResult<Option<TeamSyncSettings>> {
+    let location = location.trim().to_string();
+    if location.starts_with("http://") {
+        return Err(CodexError::message("invalid_url", &[("url", &location)]));
+    }
+    let non_empty = |value: Option<String>| value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    CodexDb::update(Path::new(&project), |db| {
+        if location.is_empty() {
+            db.team_sync = None;
+            return Ok(None);
+        }
+        let previous = db.team_sync.take();
+        let secret_key = match non_empty(secret_key) {
+            Some(key) => Some(key),
+            None => previous.as_ref().and_then(|s| s.secret_key.clone()),
+        };
+        let sync = TeamSync {
+            // A new location starts without a last sync.
+            last_sync: previous.filter(|s| s.location == location).and_then(|s| s.last_sync),
+            location,
+            read_only: read_only.unwrap_or(false),
+            region: non_empty(region),
+            access_key: non_empty(access_key),
+            secret_key,
+        };
+        let result = settings(&sync);
+        db.team_sync = Some(sync);
+        Ok(Some(result))
+    })
+}
+
+#[tauri::command(async)]
+pub fn sync_codex_db_now(project: String) -> CodexResult<SyncResult> {
+    sync(&project)
+}
@@ -0,0 +1,124 @@
+// Texture group and compression settings. The texture group decides which streaming pool
+// and LOD bias a texture gets, so textures left in the default group, or kept out of
+// streaming entirely, are the ones that blow memory budgets.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+use tauri::State;
+
+const TEXTURE_CLASSES: &[&str] = &["Texture2D", "TextureCube", "Texture2DArray", "TextureCubeArray", "VolumeTexture"];
+
+const DEFAULT_GROUP: &str = "TEXTUREGROUP_World";
+const DEFAULT_COMPRESSION: &str = "TC_Default";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TextureSettings {
+    pub path: String,
+    pub class: String,
+    /// `TEXTUREGROUP_World` unless set.
+    pub group: String,
+    /// `TC_Default` unless set.
+    pub compression: String,
+    /// `2048x2048`, from the registry tags.
+    pub dimensions: Option<String>,
+    pub size: u64,
+    pub never_stream: bool,
+    /// Why the properties couldn't be read; the group and compression are then only defaults.
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TextureGroupSummary {
+    pub group: String,
+    pub count: usize,
+    pub size: u64,
+    pub textures: Vec<TextureSettings>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TextureSettingsReport {
+    /// Largest groups first.
+    pub groups: Vec<TextureGroupSummary>,
+    /// Textures still in the default World group, largest first.
+    pub ungrouped: Vec<String>,
+    /// Textures with NeverStream set, which load every mip regardless of the pool.
+    pub never_streamed: Vec<String>,
+    /// Textures whose settings couldn't be read, left out of the groups and lists above.
+    pub unreadable: Vec<String>,
+}
+
+// Enum values come back qualified (`TextureGroup::TEXTUREGROUP_UI`) from newer packages.
+fn enum_value(value: &str) -> &str {
+    value.rsplit("::").next().unwrap_or(value)
+}
+
+pub fn texture_settings(texture: &IndexedAsset) -> TextureSettings {
+    let file_path = Path::new(&texture.asset.file_path);
+    let props = Package::read_file(file_path).and_then(|package| {
+        let export = package.main_export().ok_or_else(|| CodexError::message("no_main_export", &[]))?;
+        properties::read_export_properties(file_path, &package, export)
+    });
+    let (props, error) = match props {
+        Ok(props) => (props, None),
+        Err(error) => (Vec::new(), Some(error.to_string())),
+    };
+    let setting = |name: &str, default: &str| {
+        properties::find(&props, name).and_then(|v| v.as_str()).map(enum_value).unwrap_or(default).to_string()
+    };
+    TextureSettings {
+        path: texture.asset.path.clone(),
+        class: texture.class.clone().unwrap_or_default(),
+        group: setting("LODGroup", DEFAULT_GROUP),
+        compression: setting("CompressionSettings", DEFAULT_COMPRESSION),
+        dimensions: texture.tag("Dimensions").map(str::to_string),
+        size: texture.size,
+        never_stream: properties::find(&props, "NeverStream").and_then(|v| v.as_bool()).unwrap_or(false),
+        error,
+    }
+}
+
+pub fn report(index: &AssetIndex) -> TextureSettingsReport {
+    let textures = index.assets.iter().filter(|a| a.class.as_deref().is_some_and(|c| TEXTURE_CLASSES.contains(&c)));
+    let mut groups: BTreeMap<String, Vec<TextureSettings>> = BTreeMap::new();
+    let mut unreadable = Vec::new();
+    for texture in textures {
+        let settings = texture_settings(texture);
+        if settings.error.is_some() {
+            unreadable.push(settings.path);
+            continue;
+        }
+        groups.entry(settings.group.clone()).or_default().push(settings);
+    }
+
+    let mut never_streamed = Vec::new();
+    let mut summaries: Vec<TextureGroupSummary> = groups
+        .into_iter()
+        .map(|(group, mut textures)| {
+            textures.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+            never_streamed.extend(textures.iter().filter(|t| t.never_stream).map(|t| t.path.clone()));
+            TextureGroupSummary { count: textures.len(), size: textures.iter().map(|t| t.size).sum(), group, textures }
+        })
+        .collect();
+    summaries.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.group.cmp(&b.group)));
+    never_streamed.sort();
+    unreadable.sort();
+
+    let ungrouped = summaries
+        .iter()
+        .filter(|g| g.group == DEFAULT_GROUP)
+        .flat_map(|g| g.textures.iter().map(|t| t.path.clone()))
+        .collect();
+    TextureSettingsReport { groups: summaries, ungrouped, never_streamed, unreadable }
+}
+
+#[tauri::command(async)]
+pub fn get_texture_settings_report(manager: State<ScanManager>, project: String) -> CodexResult<TextureSettingsReport> {
+    let index = manager.index(&project)?;
+    Ok(report(&index))
+}
@@ -0,0 +1,81 @@
+// Tray mode: closing the main window hides it instead of quitting, so watched projects keep
+// being rescanned in the background and the window comes back with everything still loaded.
+// The tray menu opens the window, forces a rescan, pauses watching or quits.
+
+use crate::manager::ScanManager;
+use tauri::{
+    AppHandle, CustomMenuItem, GlobalWindowEvent, Manager, SystemTray, SystemTrayEvent, SystemTrayMenu,
+    SystemTrayMenuItem, WindowEvent,
+};
+
+const MAIN_WINDOW: &str = "main";
+
+fn pause_title(paused: bool) -> &'static str {
+    if paused {
+        "Resume watching"
+    } else {
+        "Pause watching"
+    }
+}
+
+pub fn system_tray() -> SystemTray {
+    let menu = SystemTrayMenu::new()
+        .add_item(CustomMenuItem::new("open", "Open Blueprint Codex"))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("rescan", "Rescan now"))
+        .add_item(CustomMenuItem::new("pause", pause_title(false)))
+        .add_native_item(SystemTrayMenuItem::Separator)
+        .add_item(CustomMenuItem::new("quit", "Quit"));
+    SystemTray::new().with_menu(menu).with_tooltip("Blueprint Codex")
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_window(MAIN_WINDOW) {
+        let _ = window.show();
+        let _ = window.unminimize();
+        let _ = window.set_focus();
+    }
+}
+
+// Builds the package index of every watched project while nobody is waiting on it, so the
+// first analysis after the window comes back doesn't have to.
+fn warm_indexes(app: &AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        let manager = app.state::<ScanManager>();
+        for watched in manager.scheduler.status().projects {
+            let _ = manager.index(&watched.project);
+        }
+    });
+}
+
+pub fn handle_tray_event(app: &AppHandle, event: SystemTrayEvent) {
+    match event {
+        SystemTrayEvent::LeftClick { .. } | SystemTrayEvent::DoubleClick { .. } => show_main_window(app),
+        SystemTrayEvent::MenuItemClick { id } => match id.as_str() {
+            "open" => show_main_window(app),
+            "rescan" => app.state::<ScanManager>().scheduler.rescan_now(),
+            "pause" => {
+                let scheduler = &app.state::<ScanManager>().scheduler;
+                let paused = !scheduler.status().paused;
+                let status = scheduler.configure(None, Some(paused));
+                let _ = app.tray_handle().get_item("pause").set_title(pause_title(status.paused));
+                let _ = app.emit_all("scheduler-status", status);
+            }
+            "quit" => app.exit(0),
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+/// Hides the main window instead of closing it; the app only exits from the tray menu.
+pub fn handle_window_event(event: GlobalWindowEvent) {
+    if let WindowEvent::CloseRequested { api } = event.event() {
+        if event.window().label() == MAIN_WINDOW {
+            api.prevent_close();
+            let _ = event.window().hide();
+            warm_indexes(&event.window().app_handle());
+        }
+    }
+}
@@ -0,0 +1,145 @@
+// UE5 rendering readiness: which static meshes have Nanite turned on, materials whose blend
+// mode Nanite can't render (those meshes fall back or draw wrong), and the renderer
+// settings that decide whether Lumen is in use.
+
+use crate::config::IniFile;
+use crate::error::CodexResult;
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, Property};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use tauri::State;
+
+const RENDERER_SECTION: &str = "/Script/Engine.RendererSettings";
+
+// Renderer settings that matter for a Lumen/Nanite migration.
+const RENDERER_KEYS: &[&str] = &[
+    "r.DynamicGlobalIlluminationMethod",
+    "r.ReflectionMethod",
+    "r.Lumen.HardwareRayTracing",
+    "r.Lumen.TraceMeshSDFs",
+    "r.GenerateMeshDistanceFields",
+    "r.Shadow.Virtual.Enable",
+    "r.AllowStaticLighting",
+    "r.RayTracing",
+    "r.Nanite.ProjectEnabled",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MeshNanite {
+    pub path: String,
+    pub triangles: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NaniteBlendIssue {
+    pub material: String,
+    /// `BLEND_Translucent`, `BLEND_Additive`, ...
+    pub blend_mode: String,
+    /// Nanite-enabled meshes that reference the material directly.
+    pub used_by_nanite_meshes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Ue5FeatureAudit {
+    pub nanite_meshes: Vec<MeshNanite>,
+    /// Largest first: the best candidates for turning Nanite on.
+    pub non_nanite_meshes: Vec<MeshNanite>,
+    pub unsupported_blend_modes: Vec<NaniteBlendIssue>,
+    /// Migration-relevant keys DefaultEngine.ini sets; anything missing uses the engine default.
+    pub renderer_settings: BTreeMap<String, String>,
+    pub lumen_global_illumination: bool,
+    pub lumen_reflections: bool,
+}
+
+fn main_properties(file_path: &Path) -> Option<Vec<Property>> {
+    let package = Package::read_file(file_path).ok()?;
+    let main = package.main_export()?;
+    properties::read_export_properties(file_path, &package, main).ok()
+}
+
+fn nanite_enabled(mesh: &IndexedAsset) -> bool {
+    if let Some(tag) = mesh.tag("NaniteEnabled") {
+        return crate::config::parse_bool(tag);
+    }
+    main_properties(Path::new(&mesh.asset.file_path))
+        .and_then(|props| properties::find(&props, "NaniteSettings")?.field("bEnabled")?.as_bool())
+        .unwrap_or(false)
+}
+
+/// The material's own blend mode, or an instance's override of it.
+fn blend_mode(material: &IndexedAsset) -> Option<String> {
+    let props = main_properties(Path::new(&material.asset.file_path))?;
+    let mode = if material.is_class("Material") {
+        properties::find(&props, "BlendMode")
+    } else {
+        let overrides = properties::find(&props, "BasePropertyOverrides")?;
+        overrides.field("bOverride_BlendMode")?.as_bool().filter(|&o| o)?;
+        overrides.field("BlendMode")
+    };
+    // Unset means the default, opaque.
+    let mode = mode.and_then(|m| m.as_str()).unwrap_or("BLEND_Opaque");
+    Some(mode.rsplit("::").next().unwrap_or(mode).to_string())
+}
+
+pub fn renderer_settings(project: &Path) -> BTreeMap<String, String> {
+    let text = std::fs::read_to_string(project.join("Config").join("DefaultEngine.ini")).unwrap_or_default();
+    let ini = IniFile::parse(&text);
+    RENDERER_KEYS
+        .iter()
+        .filter_map(|key| Some((key.to_string(), ini.get_all(RENDERER_SECTION, key).last()?.to_string())))
+        .collect()
+}
+
+pub fn audit(index: &AssetIndex) -> Ue5FeatureAudit {
+    let mut nanite_meshes = Vec::new();
+    let mut non_nanite_meshes = Vec::new();
+    // Material path (lowercase) -> Nanite meshes using it.
+    let mut nanite_users: HashMap<String, Vec<String>> = HashMap::new();
+    for mesh in index.of_class("StaticMesh") {
+        let entry =
+            MeshNanite { path: mesh.asset.path.clone(), triangles: mesh.tag("Triangles").and_then(|t| t.parse().ok()) };
+        if nanite_enabled(mesh) {
+            for dep in &mesh.dependencies {
+                nanite_users.entry(dep.to_lowercase()).or_default().push(mesh.asset.path.clone());
+            }
+            nanite_meshes.push(entry);
+        } else {
+            non_nanite_meshes.push(entry);
+        }
+    }
+    non_nanite_meshes.sort_by(|a, b| b.triangles.cmp(&a.triangles).then_with(|| a.path.cmp(&b.path)));
+
+    let materials = index.assets.iter().filter(|a| a.is_class("Material") || a.is_class("MaterialInstanceConstant"));
+    let mut unsupported_blend_modes = Vec::new();
+    for material in materials {
+        let Some(mode) = blend_mode(material).filter(|m| m != "BLEND_Opaque" && m != "BLEND_Masked") else {
+            continue;
+        };
+        unsupported_blend_modes.push(NaniteBlendIssue {
+            material: material.asset.path.clone(),
+            blend_mode: mode,
+            used_by_nanite_meshes: nanite_users.remove(&material.asset.path.to_lowercase()).unwrap_or_default(),
+        });
+    }
+
+    let renderer_settings = renderer_settings(&index.project);
+    let is_lumen = |key: &str| renderer_settings.get(key).is_some_and(|v| v.trim() == "1");
+    Ue5FeatureAudit {
+        lumen_global_illumination: is_lumen("r.DynamicGlobalIlluminationMethod"),
+        lumen_reflections: is_lumen("r.ReflectionMethod"),
+        nanite_meshes,
+        non_nanite_meshes,
+        unsupported_blend_modes,
+        renderer_settings,
+    }
+}
+
+#[tauri::command(async)]
+pub fn audit_ue5_features(manager: State<ScanManager>, project: String) -> CodexResult<Ue5FeatureAudit> {
+    let index = manager.index(&project)?;
+    Ok(audit(&index))
+}
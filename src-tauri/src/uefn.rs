@@ -0,0 +1,165 @@
+// UEFN (Fortnite Creative) projects: a `.uefnproject` at the root, content in a plugin
+// mounted under the project name, and gameplay written in Verse next to the assets.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::scan::ContentRoot;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use tauri::State;
+use walkdir::WalkDir;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VerseDevice {
+    pub name: String,
+    /// Package-style path of the defining file, e.g. `/MyIsland/Devices/scoreboard`.
+    pub path: String,
+    pub file_path: String,
+    pub parents: Vec<String>,
+    /// Fields marked `@editable`, i.e. what shows up in the device's details panel.
+    pub editables: Vec<String>,
+}
+
+pub fn find_uefnproject(project: &Path) -> Option<PathBuf> {
+    std::fs::read_dir(project)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.extension().is_some_and(|e| e == "uefnproject"))
+}
+
+/// Content folders of a UEFN project's plugins with their mount points (`/<Plugin>`).
+pub fn plugin_content_roots(project: &Path) -> Vec<(PathBuf, String)> {
+    let Ok(entries) = std::fs::read_dir(project.join("Plugins")) else {
+        return Vec::new();
+    };
+    let mut roots: Vec<_> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.join("Content").is_dir())
+        .filter_map(|p| {
+            let name = p.file_name()?.to_string_lossy().to_string();
+            Some((p.join("Content"), format!("/{}", name)))
+        })
+        .collect();
+    roots.sort();
+    roots
+}
+
+/// `*.digest.verse` files are generated API listings, not project code.
+pub fn is_verse_source(path: &Path) -> bool {
+    path.extension().is_some_and(|e| e == "verse") && !path.to_string_lossy().ends_with(".digest.verse")
+}
+
+pub fn verse_asset_type(path: &Path) -> String {
+    let source = std::fs::read_to_string(path).unwrap_or_default();
+    if parse_devices(&source).is_empty() {
+        "Verse".to_string()
+    } else {
+        "VerseDevice".to_string()
+    }
+}
+
+/// Classes deriving from `creative_device`, with their editable fields.
+// Declarations look like `scoreboard<public> := class<concrete>(creative_device, tagged):`.
+pub fn parse_devices(source: &str) -> Vec<(String, Vec<String>, Vec<String>)> {
+    let mut devices: Vec<(String, Vec<String>, Vec<String>)> = Vec::new();
+    let mut class_indent = 0;
+    let mut in_device = false;
+    let mut editable_pending = false;
+    for raw in source.lines() {
+        let line = raw.split('#').next().unwrap_or("").trim_end();
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+        if let Some((name, parents)) = class_declaration(trimmed) {
+            if parents.iter().any(|p| p == "creative_device") {
+                devices.push((name, parents, Vec::new()));
+                class_indent = indent;
+                in_device = true;
+            } else if indent <= class_indent {
+                in_device = false;
+            }
+            editable_pending = false;
+            continue;
+        }
+        if indent <= class_indent {
+            in_device = false;
+        }
+        let Some(current) = devices.last_mut().filter(|_| in_device) else {
+            continue;
+        };
+        let rest = match trimmed.strip_prefix("@editable") {
+            Some(rest) => {
+                editable_pending = true;
+                rest.trim_start()
+            }
+            None => trimmed,
+        };
+        if editable_pending && !rest.is_empty() {
+            editable_pending = false;
+            if let Some((field, _)) = rest.split_once(':') {
+                current.2.push(strip_specifiers(field.trim()).to_string());
+            }
+        }
+    }
+    devices
+}
+
+fn class_declaration(line: &str) -> Option<(String, Vec<String>)> {
+    let (name, rest) = line.split_once(":=")?;
+    let rest = rest.trim_start().strip_prefix("class")?;
+    let rest = match rest.strip_prefix('<') {
+        Some(specifiers) => &specifiers[specifiers.find('>')? + 1..],
+        None => rest,
+    };
+    let parents = match rest.strip_prefix('(') {
+        Some(list) => list[..list.find(')')?]
+            .split(',')
+            .map(|p| p.trim().to_string())
+            .filter(|p| !p.is_empty())
+            .collect(),
+        None => Vec::new(),
+    };
+    Some((strip_specifiers(name.trim()).to_string(), parents))
+}
+
+fn strip_specifiers(identifier: &str) -> &str {
+    identifier.split('<').next().unwrap_or(identifier).trim()
+}
+
+pub fn find_devices(project: &Path, roots: &[ContentRoot]) -> CodexResult<Vec<VerseDevice>> {
+    if roots.is_empty() {
+        return Err(CodexError::missing_content(project));
+    }
+    let mut devices = Vec::new();
+    for root in roots {
+        for entry in WalkDir::new(&root.path).into_iter().filter_map(|e| e.ok()) {
+            if !is_verse_source(entry.path()) {
+                continue;
+            }
+            let Ok(source) = std::fs::read_to_string(entry.path()) else {
+                continue;
+            };
+            let path = root.package_path(entry.path()).unwrap_or_default();
+            for (name, parents, editables) in parse_devices(&source) {
+                devices.push(VerseDevice {
+                    name,
+                    path: path.clone(),
+                    file_path: entry.path().to_string_lossy().to_string(),
+                    parents,
+                    editables,
+                });
+            }
+        }
+    }
+    Ok(devices)
+}
+
+#[tauri::command]
+pub fn get_verse_devices(manager: State<ScanManager>, project: String) -> CodexResult<Vec<VerseDevice>> {
+    let project = Path::new(&project);
+    find_devices(project, &manager.content_roots(project))
+}
@@ -0,0 +1,82 @@
+// How widely each asset is used: who references it directly, and how many levels pull it
+// in through any chain of references. Feeds the "most critical assets" view.
+
+use crate::error::CodexResult;
+use crate::index::AssetIndex;
+use crate::manager::ScanManager;
+use serde::Serialize;
+use tauri::State;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetUsage {
+    pub path: String,
+    pub asset_type: String,
+    pub referencers: usize,
+    /// Levels that load the asset, directly or through other assets.
+    pub levels: usize,
+    pub score: usize,
+    /// `score` relative to the most used asset, 0.0 to 1.0.
+    pub heat: f64,
+}
+
+pub fn heatmap(index: &AssetIndex) -> Vec<AssetUsage> {
+    let referencers = index.referencers();
+
+    // Walk forward from every level; each asset reached counts that level once.
+    let mut levels = vec![0usize; index.assets.len()];
+    let mut forward = vec![Vec::new(); index.assets.len()];
+    for (target, sources) in referencers.iter().enumerate() {
+        for &source in sources {
+            forward[source].push(target);
+        }
+    }
+    for (level, _) in index.assets.iter().enumerate().filter(|(_, a)| a.asset.asset_type == "Level") {
+        let mut seen = vec![false; index.assets.len()];
+        seen[level] = true;
+        let mut stack = vec![level];
+        while let Some(current) = stack.pop() {
+            for &next in &forward[current] {
+                if !seen[next] {
+                    seen[next] = true;
+                    levels[next] += 1;
+                    stack.push(next);
+                }
+            }
+        }
+    }
+
+    // A level reaching an asset says more than one more referencer does.
+    let mut usage: Vec<AssetUsage> = index
+        .assets
+        .iter()
+        .enumerate()
+        .map(|(i, a)| AssetUsage {
+            path: a.asset.path.clone(),
+            asset_type: a.asset.asset_type.clone(),
+            referencers: referencers[i].len(),
+            levels: levels[i],
+            score: referencers[i].len() + levels[i] * 2,
+            heat: 0.0,
+        })
+        .collect();
+    let max = usage.iter().map(|u| u.score).max().unwrap_or(0).max(1) as f64;
+    for u in &mut usage {
+        u.heat = u.score as f64 / max;
+    }
+    usage.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.path.cmp(&b.path)));
+    usage
+}
+
+#[tauri::command]
+pub fn get_usage_heatmap(
+    manager: State<ScanManager>,
+    project: String,
+    limit: Option<usize>,
+) -> CodexResult<Vec<AssetUsage>> {
+    let index = manager.index(&project)?;
+    let mut usage = heatmap(&index);
+    if let Some(limit) = limit {
+        usage.truncate(limit);
+    }
+    Ok(usage)
+}
@@ -0,0 +1,237 @@
+// Version-control hygiene for Unreal projects: LFS pointer checks and ignore/attribute files.
+
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::scan::ContentRoot;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::Path;
+use tauri::State;
+use walkdir::WalkDir;
+
+const LFS_POINTER_HEADER: &str = "version https://git-lfs.github.com/spec/v1";
+// Pointer files are tiny by spec; anything bigger is real content.
+const LFS_POINTER_MAX_SIZE: u64 = 1024;
+const PACKAGE_EXTENSIONS: &[&str] = &["uasset", "umap", "uexp", "ubulk", "uptnl"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LfsPointer {
+    pub file_path: String,
+    pub oid: Option<String>,
+    /// Size of the real object the pointer stands in for.
+    pub object_size: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LfsIntegrityReport {
+    pub checked_files: usize,
+    pub pointers: Vec<LfsPointer>,
+}
+
+pub fn read_lfs_pointer(path: &Path) -> Option<LfsPointer> {
+    let size = std::fs::metadata(path).ok()?.len();
+    if size > LFS_POINTER_MAX_SIZE {
+        return None;
+    }
+    let mut bytes = Vec::new();
+    crate::file_lock::open(path).ok()?.read_to_end(&mut bytes).ok()?;
+    parse_lfs_pointer(&path.to_string_lossy(), &bytes)
+}
+
+/// Parses pointer text, whether it came from the working tree or a git blob.
+pub fn parse_lfs_pointer(file_path: &str, bytes: &[u8]) -> Option<LfsPointer> {
+    if bytes.len() as u64 > LFS_POINTER_MAX_SIZE || !bytes.starts_with(LFS_POINTER_HEADER.as_bytes()) {
+        return None;
+    }
+    let text = std::str::from_utf8(bytes).ok()?;
+    let mut pointer = LfsPointer {
+        file_path: file_path.to_string(),
+        oid: None,
+        object_size: None,
+    };
+    for line in text.lines() {
+        if let Some(oid) = line.strip_prefix("oid ") {
+            pointer.oid = Some(oid.trim().to_string());
+        } else if let Some(size) = line.strip_prefix("size ") {
+            pointer.object_size = size.trim().parse().ok();
+        }
+    }
+    Some(pointer)
+}
+
+/// Checks every content root, plugin and mounted ones included, not just `Content/`.
+pub fn find_lfs_pointers(project: &Path, roots: &[ContentRoot]) -> CodexResult<LfsIntegrityReport> {
+    if roots.is_empty() {
+        return Err(CodexError::missing_content(project));
+    }
+    let mut report = LfsIntegrityReport {
+        checked_files: 0,
+        pointers: Vec::new(),
+    };
+    let entries = roots.iter().flat_map(|root| WalkDir::new(&root.path).into_iter().filter_map(|e| e.ok()));
+    for entry in entries {
+        let path = entry.path();
+        let is_package = path
+            .extension()
+            .is_some_and(|ext| PACKAGE_EXTENSIONS.iter().any(|p| ext.eq_ignore_ascii_case(p)));
+        if !entry.file_type().is_file() || !is_package {
+            continue;
+        }
+        report.checked_files += 1;
+        if let Some(pointer) = read_lfs_pointer(path) {
+            report.pointers.push(pointer);
+        }
+    }
+    Ok(report)
+}
+
+#[tauri::command]
+pub fn check_lfs_integrity(manager: State<ScanManager>, project: String) -> CodexResult<LfsIntegrityReport> {
+    let project = Path::new(&project);
+    find_lfs_pointers(project, &manager.content_roots(project))
+}
+
+const GITIGNORE: &str = "\
+# Unreal Engine generated folders
+Binaries/
+DerivedDataCache/
+Intermediate/
+Saved/
+Plugins/**/Binaries/
+Plugins/**/Intermediate/
+
+# IDE and project files regenerated from the .uproject
+.vs/
+.vscode/
+.idea/
+*.sln
+*.suo
+*.opensdf
+*.sdf
+*.VC.db
+*.VC.opendb
+*.xcworkspace
+*.xcodeproj
+
+# Build and packaging output
+*.pdb
+*.pak
+*.ipa
+*.apk
+";
+
+const GITATTRIBUTES: &str = "\
+# Unreal packages are binary; keep them in Git LFS
+*.uasset filter=lfs diff=lfs merge=lfs -text
+*.umap filter=lfs diff=lfs merge=lfs -text
+*.ubulk filter=lfs diff=lfs merge=lfs -text
+*.uexp filter=lfs diff=lfs merge=lfs -text
+
+# Source art
+*.fbx filter=lfs diff=lfs merge=lfs -text
+*.obj filter=lfs diff=lfs merge=lfs -text
+*.abc filter=lfs diff=lfs merge=lfs -text
+*.psd filter=lfs diff=lfs merge=lfs -text
+*.png filter=lfs diff=lfs merge=lfs -text
+*.tga filter=lfs diff=lfs merge=lfs -text
+*.exr filter=lfs diff=lfs merge=lfs -text
+*.wav filter=lfs diff=lfs merge=lfs -text
+*.mp4 filter=lfs diff=lfs merge=lfs -text
+
+# Text files Unreal reads on every platform
+*.ini text eol=crlf
+*.uproject text eol=crlf
+*.uplugin text eol=crlf
+";
+
+const P4IGNORE: &str = "\
+# Unreal Engine generated folders
+Binaries/...
+DerivedDataCache/...
+Intermediate/...
+Saved/...
+Plugins/.../Binaries/...
+Plugins/.../Intermediate/...
+
+# IDE and project files regenerated from the .uproject
+.vs/...
+.vscode/...
+.idea/...
+*.sln
+*.suo
+*.VC.db
+*.VC.opendb
+
+# Build and packaging output
+*.pdb
+*.pak
+";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VcsConfigFile {
+    pub path: String,
+    pub added_lines: usize,
+    /// Lines that were already in the file and kept as-is (merge mode only).
+    pub preserved_lines: usize,
+}
+
+fn vcs_templates(vcs: &str) -> CodexResult<Vec<(&'static str, &'static str)>> {
+    match vcs.to_ascii_lowercase().as_str() {
+        "git" => Ok(vec![(".gitignore", GITIGNORE), (".gitattributes", GITATTRIBUTES)]),
+        "p4" | "perforce" => Ok(vec![(".p4ignore", P4IGNORE)]),
+        other => Err(CodexError::message("unsupported_vcs", &[("vcs", &other)])),
+    }
+}
+
+/// Template lines missing from `existing`, appended after the existing content.
+fn merge_lines(existing: &str, template: &str) -> (String, usize, usize) {
+    let present: HashSet<&str> = existing.lines().map(str::trim).collect();
+    let missing: Vec<&str> = template
+        .lines()
+        .filter(|l| !l.trim().is_empty() && !l.starts_with('#') && !present.contains(l.trim()))
+        .collect();
+    let preserved = existing.lines().filter(|l| !l.trim().is_empty()).count();
+    if missing.is_empty() {
+        return (existing.to_string(), 0, preserved);
+    }
+    let mut out = existing.trim_end().to_string();
+    if !out.is_empty() {
+        out.push_str("\n\n");
+    }
+    out.push_str("# Added by Blueprint Codex\n");
+    for line in &missing {
+        out.push_str(line);
+        out.push('\n');
+    }
+    (out, missing.len(), preserved)
+}
+
+pub fn write_vcs_config(project: &Path, vcs: &str, merge: bool) -> CodexResult<Vec<VcsConfigFile>> {
+    if !project.is_dir() {
+        return Err(CodexError::NotAProject { path: project.to_string_lossy().to_string() });
+    }
+    let mut written = Vec::new();
+    for (name, template) in vcs_templates(vcs)? {
+        let path = project.join(name);
+        let existing = std::fs::read_to_string(&path).unwrap_or_default();
+        let (text, added_lines, preserved_lines) = if merge && !existing.is_empty() {
+            merge_lines(&existing, template)
+        } else {
+            let added = template.lines().filter(|l| !l.trim().is_empty() && !l.starts_with('#')).count();
+            (template.to_string(), added, 0)
+        };
+        std::fs::write(&path, text).map_err(|e| CodexError::io(&path, &e))?;
+        written.push(VcsConfigFile {
+            path: path.to_string_lossy().to_string(),
+            added_lines,
+            preserved_lines,
+        });
+    }
+    Ok(written)
+}
+
+#[tauri::command]
+pub fn generate_vcs_config(project: String, vcs: String, merge: Option<bool>) -> CodexResult<Vec<VcsConfigFile>> {
+    write_vcs_config(Path::new(&project), &vcs, merge.unwrap_or(true))
+}
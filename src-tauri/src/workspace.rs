@@ -0,0 +1,243 @@
+// Content roots mounted from outside the project, e.g. shared plugin repos checked out
+// elsewhere on disk. The scan manager keeps them for the session and every scan merges them in.
+// The saved workspace (the UI state in the codex database) brings them back next session, and
+// the app's config folder remembers which project that was.
+
+use crate::codex_db::{CodexDb, WorkspaceState};
+use crate::error::{CodexError, CodexResult};
+use crate::manager::ScanManager;
+use crate::scan::ContentRoot;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tauri::{AppHandle, State};
+
+const LAST_WORKSPACE_FILE: &str = "last_workspace.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MountedRoot {
+    pub path: String,
+    /// Mount point its package paths resolve under, e.g. `/SharedUI`.
+    pub mount: String,
+}
+
+/// The project whose workspace was saved or loaded last, so a new session knows what to open.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastWorkspace {
+    pub project: String,
+    pub opened_at: u64,
+}
+
+/// A plugin folder mounts its `Content` under the plugin name; any other folder is taken
+/// as a content folder itself.
+fn resolve_root(path: &Path) -> CodexResult<(PathBuf, String)> {
+    if !path.is_dir() {
+        return Err(CodexError::message("not_a_folder", &[("path", &path.display())]));
+    }
+    let folder_name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let plugin_name = std::fs::read_dir(path)
+        .map_err(|e| CodexError::io(path, &e))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .find(|p| p.extension().is_some_and(|e| e == "uplugin"))
+        .and_then(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()));
+    match plugin_name {
+        Some(name) if path.join("Content").is_dir() => Ok((path.join("Content"), name)),
+        Some(_) => Err(CodexError::ContentMissing { path: path.to_string_lossy().to_string() }),
+        None => Ok((path.to_path_buf(), folder_name)),
+    }
+}
+
+/// Adds `root`, replacing an earlier mount of the same folder. `roots` is left as it was when
+/// the mount point is taken by a built-in root or another mounted folder.
+fn add_root(roots: &mut Vec<MountedRoot>, builtin: &[ContentRoot], root: MountedRoot) -> CodexResult<()> {
+    let others = roots.iter().filter(|r| r.path != root.path);
+    if builtin.iter().map(|r| &r.mount).chain(others.map(|r| &r.mount)).any(|m| m.eq_ignore_ascii_case(&root.mount)) {
+        return Err(CodexError::message("already_mounted", &[("mount", &root.mount)]));
+    }
+    roots.retain(|r| r.path != root.path);
+    roots.push(root);
+    Ok(())
+}
+
+pub fn mount(
+    manager: &ScanManager,
+    project: &str,
+    path: &Path,
+    mount: Option<String>,
+) -> CodexResult<Vec<MountedRoot>> {
+    let (content, default_mount) = resolve_root(path)?;
+    let mount = format!("/{}", mount.unwrap_or(default_mount).trim_matches('/'));
+    if mount == "/" {
+        return Err(CodexError::message("empty_mount_point", &[]));
+    }
+    let content = std::fs::canonicalize(&content).unwrap_or(content).to_string_lossy().to_string();
+
+    let builtin = crate::scan::project_content_roots(Path::new(project));
+    manager.update_mounts(project, |roots| add_root(roots, &builtin, MountedRoot { path: content, mount }))
+}
+
+pub fn unmount(manager: &ScanManager, project: &str, mount: &str) -> CodexResult<Vec<MountedRoot>> {
+    manager.update_mounts(project, |roots| {
+        roots.retain(|r| !r.mount.eq_ignore_ascii_case(mount));
+        Ok(())
+    })
+}
+
+pub fn save(manager: &ScanManager, project: &Path, mut state: WorkspaceState) -> CodexResult<WorkspaceState> {
+    state.mounted_roots = manager.mounted_roots(project);
+    state.saved_at = crate::scan::unix_seconds(SystemTime::now());
+    CodexDb::update(project, |db| {
+        db.workspace = Some(state.clone());
+        Ok(state)
+    })
+}
+
+/// The saved workspace, with its content roots mounted again in a single change so the
+/// project is rescanned once. Roots that moved, were deleted or clash with a mount made since
+/// are left out; the returned state lists what's mounted now.
+pub fn load(manager: &ScanManager, project: &str) -> CodexResult<Option<WorkspaceState>> {
+    let Some(mut state) = CodexDb::read(Path::new(project))?.workspace else {
+        return Ok(None);
+    };
+    let mounted = manager.mounted_roots(Path::new(project));
+    let missing: Vec<MountedRoot> = std::mem::take(&mut state.mounted_roots)
+        .into_iter()
+        .filter(|root| Path::new(&root.path).is_dir() && !mounted.iter().any(|m| m.path == root.path))
+        .collect();
+    state.mounted_roots = if missing.is_empty() {
+        mounted
+    } else {
+        let builtin = crate::scan::project_content_roots(Path::new(project));
+        manager.update_mounts(project, |roots| {
+            for root in missing {
+                // A clash only costs that root; the others still mount.
+                let _ = add_root(roots, &builtin, root);
+            }
+            Ok(())
+        })?
+    };
+    Ok(Some(state))
+}
+
+fn last_workspace_file(app: &AppHandle) -> Option<PathBuf> {
+    app.path_resolver().app_config_dir().map(|dir| dir.join(LAST_WORKSPACE_FILE))
+}
+
+fn remember(app: &AppHandle, project: &str) -> CodexResult<()> {
+    let Some(file) = last_workspace_file(app) else {
+        return Ok(());
+    };
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| CodexError::io(dir, &e))?;
+    }
+    let last = LastWorkspace { project: project.to_string(), opened_at: crate::scan::unix_seconds(SystemTime::now()) };
+    let json = serde_json::to_vec_pretty(&last).map_err(|e| e.to_string())?;
+    std::fs::write(&file, json).map_err(|e| CodexError::io(&file, &e))
+}
+
+/// The last project whose workspace was saved or loaded, unless it has since gone away.
+pub fn last_workspace(app: &AppHandle) -> Option<LastWorkspace> {
+    let text = std::fs::read_to_string(last_workspace_file(app)?).ok()?;
+    let last: LastWorkspace = serde_json::from_str(&text).ok()?;
+    Path::new(&last.project).is_dir().then_some(last)
+}
+
+#[tauri::command]
+pub fn mount_content_root(
+    manager: State<ScanManager>,
+    project: String,
+    path: String,
+    mount: Option<String>,
+) -> CodexResult<Vec<MountedRoot>> {
+    self::mount(&manager, &project, Path::new(&path), mount)
+}
+
+#[tauri::command]
+pub fn unmount_content_root(
+    manager: State<ScanManager>,
+    project: String,
+    mount: String,
+) -> CodexResult<Vec<MountedRoot>> {
+    unmount(&manager, &project, &mount)
+}
+
+/// Every root a scan of `project` covers, built-in ones first.
+#[tauri::command]
+pub fn list_content_roots(manager: State<ScanManager>, project: String) -> Vec<MountedRoot> {
+    manager
+        .content_roots(Path::new(&project))
+        .into_iter()
+        .map(|r| MountedRoot {
+            path: r.path.to_string_lossy().to_string(),
+            mount: r.mount,
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn save_workspace(
+    app: AppHandle,
+    manager: State<ScanManager>,
+    project: String,
+    state: WorkspaceState,
+) -> CodexResult<WorkspaceState> {
+    let state = save(&manager, Path::new(&project), state)?;
+    remember(&app, &project)?;
+    Ok(state)
+}
+
+#[tauri::command]
+pub fn load_workspace(
+    app: AppHandle,
+    manager: State<ScanManager>,
+    project: String,
+) -> CodexResult<Option<WorkspaceState>> {
+    let state = load(&manager, &project)?;
+    if state.is_some() {
+        remember(&app, &project)?;
+    }
+    Ok(state)
+}
+
+#[tauri::command]
+pub fn get_last_workspace(app: AppHandle) -> Option<LastWorkspace> {
+    last_workspace(&app)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(path: &str, mount: &str) -> MountedRoot {
+        MountedRoot { path: path.to_string(), mount: mount.to_string() }
+    }
+
+    fn paths(roots: &[MountedRoot]) -> Vec<(&str, &str)> {
+        roots.iter().map(|r| (r.path.as_str(), r.mount.as_str())).collect()
+    }
+
+    #[test]
+    fn rejected_mount_keeps_existing_roots() {
+        let builtin = [ContentRoot { path: PathBuf::from("/project/Content"), mount: "/Game".to_string() }];
+        let mut roots = vec![root("/shared/ui", "/SharedUI"), root("/shared/fx", "/SharedFX")];
+
+        // Remounting a folder where another folder or a built-in root already is.
+        assert!(add_root(&mut roots, &builtin, root("/shared/ui", "/sharedfx")).is_err());
+        assert!(add_root(&mut roots, &builtin, root("/shared/ui", "/Game")).is_err());
+        assert_eq!(paths(&roots), [("/shared/ui", "/SharedUI"), ("/shared/fx", "/SharedFX")]);
+
+        // A new folder can't take a used mount point either.
+        assert!(add_root(&mut roots, &builtin, root("/other", "/SharedUI")).is_err());
+        assert_eq!(roots.len(), 2);
+    }
+
+    #[test]
+    fn remounting_a_folder_replaces_it() {
+        let mut roots = vec![root("/shared/ui", "/SharedUI")];
+        add_root(&mut roots, &[], root("/shared/ui", "/UI")).unwrap();
+        assert_eq!(paths(&roots), [("/shared/ui", "/UI")]);
+        add_root(&mut roots, &[], root("/shared/ui", "/ui")).unwrap();
+        assert_eq!(paths(&roots), [("/shared/ui", "/ui")]);
+    }
+}
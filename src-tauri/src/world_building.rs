@@ -0,0 +1,163 @@
+// Open-world content of a level: landscape actors and components, and foliage painted with
+// the foliage tool (instanced mesh components under InstancedFoliageActors, with their
+// instance counts and meshes). World Partition maps keep their actors in external packages,
+// so those are read along with the .umap.
+
+use crate::error::{CodexError, CodexResult};
+use crate::index::{AssetIndex, IndexedAsset};
+use crate::manager::ScanManager;
+use crate::package::Package;
+use crate::properties::{self, Property, PropertyValue};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tauri::State;
+
+const LANDSCAPE_ACTORS: &[&str] = &["Landscape", "LandscapeProxy", "LandscapeStreamingProxy"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FoliageMesh {
+    pub mesh: String,
+    pub components: usize,
+    pub instances: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FoliageTypeInfo {
+    pub path: String,
+    pub class: String,
+    /// Mesh of a static mesh foliage type, or actor class of an actor foliage type.
+    pub mesh: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorldBuildingInfo {
+    pub level: String,
+    /// The level package plus its external actor packages.
+    pub packages_read: usize,
+    /// Landscape actors and streaming proxies.
+    pub landscape_actors: usize,
+    pub landscape_components: usize,
+    /// Quads along a component's side, e.g. 63 or 127.
+    pub component_size_quads: Option<i64>,
+    pub landscape_quads: i64,
+    pub landscape_materials: Vec<String>,
+    pub foliage_actors: usize,
+    pub foliage_components: usize,
+    pub foliage_instances: i64,
+    /// Most instances first.
+    pub foliage_meshes: Vec<FoliageMesh>,
+    /// FoliageType assets the level depends on.
+    pub foliage_types: Vec<FoliageTypeInfo>,
+}
+
+/// Instances in a (hierarchical) instanced mesh component. The instance transforms are native
+/// data; the sort table built from them is a property and has one entry per instance.
+fn instance_count(props: &[Property]) -> i64 {
+    match properties::find(props, "SortedInstances") {
+        Some(PropertyValue::Array(items)) => items.len() as i64,
+        _ => properties::find(props, "NumBuiltInstances").and_then(|v| v.as_i64()).unwrap_or(0),
+    }
+}
+
+fn object_package(props: &[Property], name: &str) -> Option<String> {
+    let path = properties::find(props, name)?.as_str()?;
+    Some(crate::package::package_of_object_path(path).to_string())
+}
+
+fn read_package(
+    file_path: &Path,
+    info: &mut WorldBuildingInfo,
+    meshes: &mut BTreeMap<String, FoliageMesh>,
+    materials: &mut BTreeSet<String>,
+) {
+    let Ok(package) = Package::read_file(file_path) else {
+        return;
+    };
+    info.packages_read += 1;
+    for export in &package.exports {
+        let class = package.export_class(export);
+        let outer_class = usize::try_from(export.outer_index - 1)
+            .ok()
+            .and_then(|i| package.exports.get(i))
+            .map(|outer| package.export_class(outer))
+            .unwrap_or_default();
+        let is_foliage_component =
+            class.ends_with("InstancedStaticMeshComponent") && outer_class == "InstancedFoliageActor";
+        let is_landscape_actor = LANDSCAPE_ACTORS.contains(&class.as_str());
+        if class == "InstancedFoliageActor" {
+            info.foliage_actors += 1;
+            continue;
+        }
+        if !is_foliage_component && !is_landscape_actor && class != "LandscapeComponent" {
+            continue;
+        }
+        let props = properties::read_export_properties(file_path, &package, export).unwrap_or_default();
+        if is_landscape_actor {
+            info.landscape_actors += 1;
+            materials.extend(object_package(&props, "LandscapeMaterial"));
+        } else if class == "LandscapeComponent" {
+            info.landscape_components += 1;
+            if let Some(size) = properties::find(&props, "ComponentSizeQuads").and_then(|v| v.as_i64()) {
+                info.component_size_quads.get_or_insert(size);
+                info.landscape_quads += size * size;
+            }
+        } else {
+            let instances = instance_count(&props);
+            let mesh = object_package(&props, "StaticMesh").unwrap_or_else(|| "None".to_string());
+            let entry = meshes.entry(mesh.clone()).or_insert_with(|| FoliageMesh { mesh, components: 0, instances: 0 });
+            entry.components += 1;
+            entry.instances += instances;
+            info.foliage_components += 1;
+            info.foliage_instances += instances;
+        }
+    }
+}
+
+fn foliage_type(asset: &IndexedAsset) -> FoliageTypeInfo {
+    let file_path = Path::new(&asset.asset.file_path);
+    let mesh = Package::read_file(file_path).ok().and_then(|package| {
+        let props = properties::read_export_properties(file_path, &package, package.main_export()?).ok()?;
+        object_package(&props, "Mesh").or_else(|| object_package(&props, "ActorClass"))
+    });
+    FoliageTypeInfo { path: asset.asset.path.clone(), class: asset.class.clone().unwrap_or_default(), mesh }
+}
+
+pub fn inspect(index: &AssetIndex, level: &IndexedAsset) -> WorldBuildingInfo {
+    let mut info = WorldBuildingInfo { level: level.asset.path.clone(), ..Default::default() };
+    let external_folder = crate::build_state::external_actors_folder(level);
+    let packages: Vec<&IndexedAsset> = std::iter::once(level)
+        .chain(index.assets.iter().filter(|a| crate::index::is_in_folder(&a.asset.path, &external_folder)))
+        .collect();
+
+    let mut meshes = BTreeMap::new();
+    let mut materials = BTreeSet::new();
+    let mut foliage_types = BTreeSet::new();
+    for asset in &packages {
+        read_package(Path::new(&asset.asset.file_path), &mut info, &mut meshes, &mut materials);
+        for dep in asset.dependencies.iter().filter_map(|d| index.get(d)) {
+            if dep.class.as_deref().is_some_and(|c| c.starts_with("FoliageType")) {
+                foliage_types.insert(dep.asset.path.as_str());
+            }
+        }
+    }
+
+    info.landscape_materials = materials.into_iter().collect();
+    info.foliage_meshes = meshes.into_values().collect();
+    info.foliage_meshes.sort_by(|a, b| b.instances.cmp(&a.instances).then_with(|| a.mesh.cmp(&b.mesh)));
+    info.foliage_types = foliage_types.into_iter().filter_map(|path| index.get(path)).map(foliage_type).collect();
+    info
+}
+
+/// `level` is a package path (`/Game/Maps/Arena`) or the .umap file path.
+#[tauri::command(async)]
+pub fn get_world_building_info(
+    manager: State<ScanManager>,
+    project: String,
+    level: String,
+) -> CodexResult<WorldBuildingInfo> {
+    let index = manager.index(&project)?;
+    let level = crate::level_budget::find_level(&index, &level)
+        .ok_or_else(|| CodexError::message("level_not_found", &[("level", &level)]))?;
+    Ok(inspect(&index, level))
+}
@@ -0,0 +1,87 @@
+// Message catalog for backend errors. Commands reject with `{ code, params, message }`; the
+// UI renders `code` from the active locale and falls back to the backend's English
+// `message` for codes a catalog doesn't have yet. Keys mirror MESSAGES in error.rs.
+
+export type MessageParams = Record<string, string>;
+
+type Catalog = Record<string, string>;
+
+const en: Catalog = {
+  not_a_project: '{path} is not an Unreal project. Pick the folder that contains the .uproject file.',
+  content_missing: 'No Content folder found in {path}.',
+  io: '{path}: {message}',
+  io_locked: '{path} is locked by another program (is the editor saving it?).',
+  io_permission_denied: 'No permission to read {path}.',
+  io_not_found: '{path} does not exist.',
+  parse_failed: '{path} could not be read: {message}',
+  engine_not_found: 'Engine {association} is not installed or registered on this machine.',
+  failed: '{message}',
+  already_mounted: '{mount} is already mounted.',
+  asset_claimed: '{path} is claimed by {user}; ask them to release it first.',
+  asset_not_found: '{path} not found.',
+  blueprint_export_out_of_range: 'Blueprint export out of range.',
+  claim_not_held: '{path} is not claimed by {user}.',
+  empty_mount_point: "Mount point can't be empty.",
+  empty_platform: "Platform can't be empty.",
+  empty_search: "Search text can't be empty.",
+  http_bad_response: 'Unexpected response from {url}: {message}',
+  http_failed: 'Request to {url} failed: {message}',
+  http_status: '{url} returned HTTP {status}.',
+  http_unavailable: 'curl is not available on this machine; it is needed for integrations.',
+  invalid_asset_path: 'Invalid asset path.',
+  invalid_import_data: '{path} has import data that could not be read: {message}',
+  invalid_issue_key: "'{key}' is not an issue key (expected something like GAME-123).",
+  invalid_owners_rule: 'Line {line} of .codexowners is not a valid rule: {message}',
+  invalid_report_time: "Invalid report time '{time}'; use 24-hour HH:MM.",
+  invalid_review_transition: "Can't move {path} from {from} to {to}.",
+  invalid_url: "'{url}' is not an http(s) URL.",
+  level_not_found: 'Level {level} is not in the project.',
+  lfs_not_fetched: '{path} at {rev} is an LFS object that has not been fetched.',
+  missing_at_revision: '{path} does not exist at {rev}.',
+  no_curves: 'No curves in package ({class}).',
+  no_issue_tracker: 'No issue tracker is configured for this project.',
+  no_level: 'No level in package.',
+  no_main_export: 'Package has no main export.',
+  no_notifier: 'No webhook is configured for this project.',
+  no_report_schedule: 'No report schedule is configured for this project.',
+  no_source_art_folder: 'No source art folder found.',
+  no_team_sync: 'No team sync location is configured for this project.',
+  no_work_tree: 'Repository has no work tree.',
+  not_a_behavior_tree: 'Not a Behavior Tree or Blackboard ({class}).',
+  not_a_folder: '{path} is not a folder.',
+  not_a_level_sequence: 'Not a Level Sequence ({class}).',
+  not_a_metasound: 'Not a MetaSound ({class}).',
+  not_a_niagara_asset: 'Not a Niagara system or emitter ({class}).',
+  not_an_interface: '{path} is not a Blueprint interface.',
+  not_scan_output: '{path} is not a scan output file.',
+  outside_repository: '{path} is not inside the repository.',
+  project_not_watched: '{project} is not being watched for changes.',
+  reviewer_required: 'Reviewer is required.',
+  source_art_folder_missing: 'Source art folder not found: {path}',
+  team_sync_read_only: "Team sync is read-only on this machine, so claims and releases can't reach the team.",
+  unknown_output_format: "Unknown scan output format '{format}'.",
+  unknown_review_state: "Unknown review state '{state}'.",
+  unsupported_vcs: 'Unsupported version control system: {vcs}',
+  user_required: 'User name is required.',
+};
+
+const catalogs: Record<string, Catalog> = { en };
+
+let locale = localStorage.getItem('codex_locale') || 'en';
+
+export function setMessageLocale(next: string) {
+  locale = next;
+  localStorage.setItem('codex_locale', next);
+}
+
+/** Registers (or extends) the catalog for a locale, e.g. from a translation file. */
+export function registerMessages(forLocale: string, messages: Catalog) {
+  catalogs[forLocale] = { ...catalogs[forLocale], ...messages };
+}
+
+/** The message for `code` in the active locale, or undefined if no catalog has it. */
+export function formatMessage(code: string, params: MessageParams = {}): string | undefined {
+  const template = catalogs[locale]?.[code] ?? en[code];
+  if (template === undefined) return undefined;
+  return template.replace(/\{(\w+)\}/g, (match, name) => params[name] ?? match);
+}
@@ -1,12 +1,50 @@
 import { open } from '@tauri-apps/api/dialog';
+import { listen } from '@tauri-apps/api/event';
 import { invoke } from '@tauri-apps/api/tauri';
 import { ref } from 'vue';
+import { formatMessage, type MessageParams } from './messages';
 
 export interface UnrealAsset {
   name: string;
   path: string; // Relative to Content folder e.g. /Game/Characters/MyChar
   asset_type: string; // e.g. 'Blueprint', 'Texture', 'Material'
   file_path: string;
+  size?: number; // Bytes on disk
+  is_large?: boolean; // Above the scan's large-asset threshold
+  mount?: string; // Content root mount point, e.g. /Game
+  owner?: string; // Owning team(s) from .codexowners
+  locked?: boolean; // Held open exclusively by another process when scanned
+  is_starter_content?: boolean; // Epic Starter Content or project template boilerplate
+}
+
+// Payload of `project-loaded`, sent after a dropped project has been scanned.
+export interface ProjectLoaded {
+  project: string;
+  name: string;
+  assets: UnrealAsset[];
+}
+
+// Payload of `deep-link`, from links such as `codex://asset//Game/Blueprints/BP_Player`.
+export interface DeepLink {
+  kind: 'asset';
+  path: string; // Package path, e.g. /Game/Blueprints/BP_Player
+  project?: string | null; // Project folder to open first, from `?project=`
+}
+
+// Errors returned by the backend commands: a catalog `code` with its `params`, plus the
+// backend's English `message` as a fallback.
+export interface CodexError {
+  code: string;
+  params: MessageParams;
+  message: string;
+}
+
+export function describeCodexError(e: unknown): string {
+  const err = e as CodexError;
+  if (!err || typeof err.code !== 'string') return String(e);
+  // I/O errors read better per kind (locked, permission_denied, not_found).
+  const byKind = err.code === 'io' ? formatMessage(`io_${err.params?.kind}`, err.params) : undefined;
+  return byKind ?? formatMessage(err.code, err.params) ?? err.message;
 }
 
 class UnrealService {
@@ -14,11 +52,53 @@ class UnrealService {
   private isScanning = ref(false);
   private projectPath = ref<string | null>(localStorage.getItem('unreal_project_path'));
   private graphRootPath = ref<string | null>(null); // For scoping the graph view
+  private focusedAssetPath = ref<string | null>(null); // Asset a codex:// link asked for
 
   constructor() {
     if (this.projectPath.value) {
       this.scanProject(this.projectPath.value);
     }
+    // @ts-ignore
+    if (window.__TAURI__) {
+      this.listenForDroppedProjects();
+      this.listenForDeepLinks();
+    }
+  }
+
+  // codex:// links bring the window forward and name an asset to focus. A link that
+  // launched the app arrives before we listen, so also ask for the pending one.
+  private listenForDeepLinks() {
+    listen<DeepLink>('deep-link', () => this.takePendingDeepLink());
+    this.takePendingDeepLink();
+  }
+
+  private async takePendingDeepLink() {
+    const link = await invoke<DeepLink | null>('take_pending_deep_link');
+    if (!link) return;
+    if (link.project && link.project !== this.projectPath.value) {
+      this.projectPath.value = link.project;
+      localStorage.setItem('unreal_project_path', link.project);
+      await this.scanProject(link.project);
+    }
+    this.focusedAssetPath.value = link.path;
+  }
+
+  // A .uproject or project folder dropped on the window is validated and scanned by the
+  // backend, which then hands us the results.
+  private listenForDroppedProjects() {
+    listen<string>('project-drop-started', () => {
+      this.isScanning.value = true;
+    });
+    listen<ProjectLoaded>('project-loaded', (event) => {
+      this.projectPath.value = event.payload.project;
+      localStorage.setItem('unreal_project_path', event.payload.project);
+      this.assets.value = event.payload.assets;
+      this.isScanning.value = false;
+    });
+    listen<CodexError>('project-drop-failed', (event) => {
+      this.isScanning.value = false;
+      alert('Could not open the dropped project: ' + describeCodexError(event.payload));
+    });
   }
 
   // Tree structure for the Content Browser
@@ -74,6 +154,15 @@ class UnrealService {
     this.graphRootPath.value = path;
   }
 
+  getFocusedAssetPath() {
+    return this.focusedAssetPath;
+  }
+
+  // The codex:// link for an asset, for pasting into chat, tickets and docs.
+  linkTo(asset: UnrealAsset): string {
+    return `codex://asset/${asset.path.split('/').map(encodeURIComponent).join('/')}`;
+  }
+
   async selectProject(): Promise<string | null> {
     try {
       // @ts-ignore
@@ -112,7 +201,7 @@ class UnrealService {
       this.assets.value = assets;
     } catch (e) {
       console.error('Failed to scan project', e);
-      alert('Failed to scan project: ' + e);
+      alert('Failed to scan project: ' + describeCodexError(e));
     } finally {
       this.isScanning.value = false;
     }